@@ -2,17 +2,110 @@
 
 use crate::{
     Error, Result,
-    types::{NicheOpportunity, IntegrationScore, DataSource, DataSourceType, AnalysisConfig, AnalysisResult, AnalysisMetadata},
-    scoring::{OpportunityScorer, DefaultScorer, ScoringData},
-    transform::{NormalizedIntegration, load_hacs_data, load_github_data, load_youtube_data, normalize_integrations},
+    types::{
+        NicheOpportunity, OpportunityKind, IntegrationScore, DataSource, DataSourceType, AnalysisConfig, AnalysisResult,
+        AnalysisMetadata, Evidence, SourceProvenance,
+    },
+    ecosystems::foreign_popularity,
+    device_catalogs::protocol_device_count,
+    scoring::{OpportunityScorer, DefaultScorer, Ensemble, ScoringData},
+    forecast::{ForecastSignal, SignalHistory},
+    custom_sources::{FileSourceConfig, custom_demand},
+    transform::{NormalizedIntegration, addressable_users},
+    pipeline::{DemandBasis, Enricher, FinishedRun, InputNormalizer, Normalizer, PipelineContext, PostFilter, standard_enrichers, standard_filters},
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use std::collections::{BTreeMap, HashMap};
 use std::time::Instant;
 
+/// Raw data files consumed by an analysis run
+///
+/// HACS, GitHub and YouTube are required; the remaining sources are optional
+/// and only contribute signals when a path is provided and the source is
+/// enabled in `AnalysisConfig` (see `AnalysisConfig::source_enabled`).
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisInputs {
+    /// HACS integration data (`fetch_hacs_integrations` result)
+    pub hacs_path: String,
+
+    /// GitHub repository search results
+    pub github_path: String,
+
+    /// YouTube video search results
+    pub youtube_path: String,
+
+    /// Hacker News Algolia search results
+    pub hackernews_path: Option<String>,
+
+    /// Reddit search listing
+    pub reddit_path: Option<String>,
+
+    /// Home Assistant Community feature-request topics
+    pub forum_path: Option<String>,
+
+    /// Home Assistant core integration manifests
+    pub core_inventory_path: Option<String>,
+
+    /// Home Assistant analytics install counts
+    pub analytics_path: Option<String>,
+
+    /// Local PyPI metadata/download dump for manifest requirements
+    pub pypi_path: Option<String>,
+
+    /// Activity of existing integrations' repositories (issues, releases,
+    /// contributors, commits)
+    pub maintenance_path: Option<String>,
+
+    /// Homebridge plugin catalog dump (npm search response)
+    pub homebridge_path: Option<String>,
+
+    /// openHAB add-on catalog dump
+    pub openhab_path: Option<String>,
+
+    /// zigbee2mqtt supported-devices list
+    pub zigbee_path: Option<String>,
+
+    /// Z-Wave JS device configs (directory checkout or JSON array)
+    pub zwave_path: Option<String>,
+
+    /// Matter certified-product list
+    pub matter_path: Option<String>,
+
+    /// Internal CSV/JSON Lines sources counted as weighted demand
+    pub custom_sources: Vec<FileSourceConfig>,
+
+    /// Known provenance per source key (collection time, artifact id, query);
+    /// missing fields fall back to the input file's path and modification time
+    pub provenance: HashMap<String, SourceProvenance>,
+
+    /// Evaluate the inputs as of this time instead of now (time window and
+    /// recency), for analyses of stored historical snapshots
+    pub as_of: Option<DateTime<Utc>>,
+
+    /// Signals of earlier snapshots; when given, stars, mentions and installs
+    /// are forecast and the star trend becomes the growth rate
+    pub history: Option<SignalHistory>,
+}
+
+impl AnalysisInputs {
+    /// Create inputs for the three required sources
+    pub fn new(hacs_path: &str, github_path: &str, youtube_path: &str) -> Self {
+        Self {
+            hacs_path: hacs_path.to_string(),
+            github_path: github_path.to_string(),
+            youtube_path: youtube_path.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Evidence items kept per opportunity
+const MAX_EVIDENCE: usize = 20;
+
 /// Analyzer for identifying integration opportunities
 pub struct IntegrationAnalyzer {
-    scorer: Box<dyn OpportunityScorer>,
+    ensemble: Ensemble,
     config: AnalysisConfig,
 }
 
@@ -20,15 +113,34 @@ impl IntegrationAnalyzer {
     /// Create a new analyzer with default configuration
     pub fn new() -> Self {
         Self {
-            scorer: Box::new(DefaultScorer::new()),
+            ensemble: Ensemble::single(Box::new(DefaultScorer::new())),
             config: AnalysisConfig::default(),
         }
     }
     
     /// Create a new analyzer with custom configuration
     pub fn with_config(config: AnalysisConfig) -> Self {
-        let scorer = Box::new(DefaultScorer::with_weights(config.weights.clone()));
-        Self { scorer, config }
+        let ensemble = Ensemble::single(Box::new(DefaultScorer::with_weights(config.weights.clone())));
+        Self { ensemble, config }
+    }
+    
+    /// Create a new analyzer using the scorer (or ensemble of scorers)
+    /// named in the configuration
+    ///
+    /// Fails when a named scoring script is missing or does not compile.
+    pub fn from_config(config: AnalysisConfig) -> Result<Self> {
+        let ensemble = Ensemble::from_config(&config)?;
+        Ok(Self { ensemble, config })
+    }
+    
+    /// Create a new analyzer with custom configuration and scorer
+    pub fn with_scorer(config: AnalysisConfig, scorer: Box<dyn OpportunityScorer>) -> Self {
+        Self { ensemble: Ensemble::single(scorer), config }
+    }
+    
+    /// Create a new analyzer that runs several scorers side by side
+    pub fn with_ensemble(config: AnalysisConfig, ensemble: Ensemble) -> Self {
+        Self { ensemble, config }
     }
     
     /// Analyze integration opportunities from raw data files
@@ -37,40 +149,66 @@ impl IntegrationAnalyzer {
         hacs_path: &str,
         github_path: &str,
         youtube_path: &str,
+    ) -> Result<AnalysisResult> {
+        self.analyze_from_inputs(&AnalysisInputs::new(hacs_path, github_path, youtube_path))
+    }
+    
+    /// Analyze integration opportunities from required and optional raw data files
+    ///
+    /// Only sources enabled in the config (`AnalysisConfig::source_enabled`) are loaded;
+    /// a disabled required source counts as empty. Timestamped signals (videos,
+    /// posts, custom rows) outside `time_range_days` are dropped before matching.
+    pub fn analyze_from_inputs(&self, inputs: &AnalysisInputs) -> Result<AnalysisResult> {
+        self.analyze_with_stages(inputs, &InputNormalizer, &standard_enrichers(), &standard_filters(&self.config))
+    }
+    
+    /// Run the normalizer and enrichers, then score, filter and rank
+    pub(crate) fn analyze_with_stages(
+        &self,
+        inputs: &AnalysisInputs,
+        normalizer: &dyn Normalizer,
+        enrichers: &[Box<dyn Enricher>],
+        filters: &[Box<dyn PostFilter>],
     ) -> Result<AnalysisResult> {
         let start = Instant::now();
+        let mut ctx = PipelineContext::new(&self.config, inputs);
         
-        // Load raw data
-        let hacs_data = load_hacs_data(hacs_path)?;
-        let github_data = load_github_data(github_path)?;
-        let youtube_data = load_youtube_data(youtube_path)?;
-        
-        // Normalize data
-        let normalized = normalize_integrations(hacs_data, github_data, youtube_data)?;
+        let mut normalized = normalizer.normalize(&mut ctx)?;
+        for enricher in enrichers {
+            enricher.enrich(&mut normalized, &mut ctx)?;
+        }
         
         // Analyze opportunities
-        self.analyze_normalized(normalized, start)
+        let mut result = self.analyze_normalized(normalized, ctx.finish(), filters)?;
+        result.metadata.duration_secs = start.elapsed().as_secs_f64();
+        Ok(result)
     }
     
-    /// Analyze normalized integration data
+    /// Analyze normalized integration data (the caller records the duration)
     fn analyze_normalized(
         &self,
         integrations: Vec<NormalizedIntegration>,
-        start: Instant,
+        run: FinishedRun,
+        filters: &[Box<dyn PostFilter>],
     ) -> Result<AnalysisResult> {
         let total_candidates = integrations.len();
         let mut opportunities = Vec::new();
         
-        for integration in integrations {
-            // Calculate scoring data
-            let scoring_data = self.calculate_scoring_data(&integration);
-            
-            // Score the opportunity
-            let score = self.scorer.score(&scoring_data)?;
-            
+        // Calculate scoring data
+        let scoring_data: Vec<ScoringData> = integrations
+            .iter()
+            .map(|integration| self.calculate_scoring_data(integration, run.now))
+            .collect();
+        
+        // Score all candidates at once; ensemble ranks are across the whole set
+        let scores = self.ensemble.score_all(&scoring_data.iter().collect::<Vec<_>>())?;
+        
+        let candidates = integrations.into_iter().zip(scoring_data).zip(scores);
+        for ((integration, features), scored) in candidates {
             // Filter by minimum score threshold
-            if score.composite >= self.config.min_score {
-                let opportunity = self.create_opportunity(integration, score);
+            if scored.score.composite >= self.config.min_score {
+                let mut opportunity = self.create_opportunity(integration, scored.score, features, &run.provenance);
+                opportunity.scorer_results = scored.results;
                 opportunities.push(opportunity);
             }
         }
@@ -82,11 +220,16 @@ impl IntegrationAnalyzer {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
         
+        // Post-filters see the full ranked list, before truncation
+        for opportunity in opportunities.iter_mut() {
+            filters.iter().for_each(|f| f.annotate(opportunity));
+        }
+        opportunities.retain(|o| filters.iter().all(|f| f.keep(o)));
+        
         // Limit to max_results
         opportunities.truncate(self.config.max_results);
         
         let qualified_candidates = opportunities.len();
-        let duration_secs = start.elapsed().as_secs_f64();
         
         Ok(AnalysisResult {
             opportunities,
@@ -95,43 +238,99 @@ impl IntegrationAnalyzer {
             metadata: AnalysisMetadata {
                 total_candidates,
                 qualified_candidates,
-                duration_secs,
-                sources_used: self.config.enabled_sources.clone(),
+                duration_secs: 0.0,
+                sources_used: run.sources_used,
+                source_status: Vec::new(),
             },
+            breakouts: run.breakouts,
+            provenance: run.provenance,
         })
     }
     
     /// Calculate scoring data from normalized integration
-    fn calculate_scoring_data(&self, integration: &NormalizedIntegration) -> ScoringData {
-        // Demand: based on GitHub stars and YouTube mentions
-        let request_count = (integration.stars as usize) + (integration.youtube_mentions as usize * 10);
+    fn calculate_scoring_data(
+        &self,
+        integration: &NormalizedIntegration,
+        now: DateTime<Utc>,
+    ) -> ScoringData {
+        // Demand: explicit asks when the integration has forum requests, then its
+        // installs, then GitHub stars, plus YouTube mentions and Hacker News discussion
+        let base_demand = match DemandBasis::for_integration(integration) {
+            // Foreign popularity: a hundred downloads or installs count as one request
+            _ if integration.kind == OpportunityKind::PortedElsewhere => {
+                (foreign_popularity(integration) / 100) as usize
+            }
+            // Each catalogued device counts as ten requests
+            _ if integration.kind == OpportunityKind::ProtocolOnly => protocol_device_count(integration) * 10,
+            DemandBasis::Requests => {
+                integration.forum_votes as usize + (integration.forum_topics as usize * 5)
+            }
+            // Ten installs count as one request
+            DemandBasis::Installs => addressable_users(integration).unwrap_or(0) as usize / 10,
+            DemandBasis::Stars => integration.stars as usize,
+        };
+        // Vendor library downloads: ten thousand monthly downloads count as one request
+        let library_demand = integration.library_feasibility
+            .as_ref()
+            .map(|l| (l.monthly_downloads / 10_000) as usize)
+            .unwrap_or(0);
+        let request_count = base_demand
+            + (integration.youtube_mentions as usize * 10)
+            + (integration.hackernews_mentions as usize * 5)
+            + (integration.reddit_mentions as usize * 5)
+            + library_demand;
         
-        // Growth rate: estimate based on stars and recency
-        let growth_rate = if integration.stars > 0 {
-            integration.stars as f64 / 365.0 // Rough estimate: stars per day
-        } else {
-            0.0
+        // Growth rate: the forecast star trend when snapshot history is
+        // given, else an estimate based on stars
+        let growth_rate = match integration.forecasts.iter().find(|f| f.signal == ForecastSignal::Stars) {
+            Some(stars) => stars.trend_per_day.max(0.0),
+            None if integration.stars > 0 => integration.stars as f64 / 365.0, // Rough estimate: stars per day
+            None => 0.0,
+        };
+        
+        // Feasibility: vendor library signals from PyPI when resolved, otherwise
+        // assume an API exists if the integration is in HACS
+        let (has_api, api_quality) = match &integration.library_feasibility {
+            Some(libraries) => (integration.in_hacs || libraries.has_library, libraries.api_quality),
+            // A working port in another ecosystem proves the device can be reached
+            None if integration.kind == OpportunityKind::PortedElsewhere => (true, 0.7),
+            // Devices are reachable locally; vendor cloud/feature APIs are unknown
+            None if integration.kind == OpportunityKind::ProtocolOnly => (true, 0.6),
+            None => (integration.in_hacs, if integration.in_hacs { 0.8 } else { 0.5 }),
         };
         
-        // Feasibility: assume API exists if integration is in HACS
-        let has_api = integration.in_hacs;
-        let api_quality = if integration.in_hacs { 0.8 } else { 0.5 };
+        // Competition: the HACS integration itself plus any official core coverage
+        // (a neglected HACS integration is discounted via `maintenance_health`)
+        let official_integrations = integration.core_coverage.len();
+        // Generic protocol integrations (ZHA, Z-Wave JS, Matter) already give basic control
+        let existing_integrations = (if integration.in_hacs { 1 } else { 0 })
+            + official_integrations
+            + integration.protocol_devices.len();
         
-        // Competition: count as 1 if in HACS (existing integration)
-        let existing_integrations = if integration.in_hacs { 1 } else { 0 };
+        // Recency: days since last update or most recent custom signal
+        let last_activity = integration.custom_signals
+            .values()
+            .filter_map(|t| t.last_seen)
+            .chain(integration.last_updated)
+            .max();
+        let days_since_activity = last_activity.map(|dt| (now - dt).num_days().max(0) as u32);
+        let days_since_last_request = days_since_activity.unwrap_or(365);
         
-        // Recency: days since last update
-        let days_since_last_request = integration.last_updated
-            .map(|dt| (Utc::now() - dt).num_days() as u32)
-            .unwrap_or(365);
+        let mut signals = scoring_signals(integration, now);
+        if let Some(days) = days_since_activity {
+            signals.insert("days_since_activity".to_string(), f64::from(days));
+        }
         
         ScoringData {
             request_count,
+            custom_signals: custom_demand(integration),
             growth_rate,
             has_api,
             api_quality,
             existing_integrations,
+            official_integrations,
             days_since_last_request,
+            signals,
         }
     }
     
@@ -140,99 +339,584 @@ impl IntegrationAnalyzer {
         &self,
         integration: NormalizedIntegration,
         score: IntegrationScore,
+        features: ScoringData,
+        provenance: &BTreeMap<String, SourceProvenance>,
     ) -> NicheOpportunity {
-        let data_sources = self.create_data_sources(&integration);
+        let data_sources = self.create_data_sources(&integration, provenance);
+        let addressable_users = addressable_users(&integration);
 
         NicheOpportunity {
             id: Uuid::new_v4(),
             name: integration.name.clone(),
             category: integration.domain.unwrap_or_else(|| "unknown".to_string()),
+            kind: integration.kind,
             score: score.composite,
             scoring_details: score,
             data_sources,
+            evidence: rank_evidence(integration.evidence),
+            features: Some(features),
+            scorer_results: Vec::new(),
+            forecasts: integration.forecasts,
+            lifecycle: None,
+            differentiation: integration.feature_gaps,
             discovered_at: Utc::now(),
             metadata: serde_json::json!({
                 "github_url": integration.github_url,
+                "created_at": integration.created_at,
                 "stars": integration.stars,
                 "forks": integration.forks,
                 "open_issues": integration.open_issues,
                 "topics": integration.topics,
                 "in_hacs": integration.in_hacs,
+                "foreign_ports": integration.foreign_ports,
+                "protocol_devices": integration.protocol_devices,
+                "custom_signals": integration.custom_signals,
+                "active_installs": integration.active_installs,
+                "addressable_users": addressable_users,
+                "core_domains": integration.core_coverage.iter().map(|c| c.domain.as_str()).collect::<Vec<_>>(),
                 "youtube_mentions": integration.youtube_mentions,
+                "hackernews_mentions": integration.hackernews_mentions,
+                "hackernews_points": integration.hackernews_points,
+                "reddit_mentions": integration.reddit_mentions,
+                "reddit_score": integration.reddit_score,
+                "forum_topics": integration.forum_topics,
+                "forum_votes": integration.forum_votes,
+                "maintenance": integration.maintenance,
             }),
         }
     }
 
-    /// Create data sources from normalized integration
-    fn create_data_sources(&self, integration: &NormalizedIntegration) -> Vec<DataSource> {
+    /// Create data sources from normalized integration, with the provenance
+    /// of the inputs they came from
+    fn create_data_sources(
+        &self,
+        integration: &NormalizedIntegration,
+        provenance: &BTreeMap<String, SourceProvenance>,
+    ) -> Vec<DataSource> {
         let mut sources = Vec::new();
+        let source_for = |source: &str, name: &str, source_type, data_points, metadata| {
+            data_source(provenance.get(source), source, name, source_type, data_points, metadata)
+        };
 
         // HACS source
         if let Some(hacs_id) = &integration.sources.hacs_id {
-            sources.push(DataSource {
-                name: "HACS".to_string(),
-                source_type: DataSourceType::Hacs,
-                collected_at: Utc::now(),
-                data_points: 1,
-                metadata: serde_json::json!({
+            sources.push(source_for(
+                "hacs",
+                "HACS",
+                DataSourceType::Hacs,
+                1,
+                serde_json::json!({
                     "hacs_id": hacs_id,
                     "domain": integration.domain,
                 }),
-            });
+            ));
+        }
+
+        // HA core source
+        if !integration.core_coverage.is_empty() {
+            sources.push(source_for(
+                "ha_core",
+                "HA Core",
+                DataSourceType::HaCore,
+                integration.core_coverage.len(),
+                serde_json::json!({
+                    "integrations": integration.core_coverage,
+                }),
+            ));
+        }
+
+        // HA analytics source
+        if integration.active_installs.is_some() || integration.core_coverage.iter().any(|c| c.active_installs.is_some()) {
+            sources.push(source_for(
+                "ha_analytics",
+                "HA Analytics",
+                DataSourceType::HaAnalytics,
+                1 + integration.core_coverage.len(),
+                serde_json::json!({
+                    "active_installs": integration.active_installs,
+                    "addressable_users": addressable_users(integration),
+                }),
+            ));
+        }
+
+        // PyPI source
+        if let Some(libraries) = &integration.library_feasibility {
+            sources.push(source_for(
+                "pypi",
+                "PyPI",
+                DataSourceType::Other("PyPI".to_string()),
+                libraries.libraries.len(),
+                serde_json::json!({
+                    "libraries": libraries.libraries,
+                    "api_quality": libraries.api_quality,
+                    "monthly_downloads": libraries.monthly_downloads,
+                }),
+            ));
+        }
+
+        // Repository maintenance activity
+        if let Some(health) = &integration.maintenance {
+            sources.push(source_for(
+                "github_maintenance",
+                "GitHub Maintenance",
+                DataSourceType::GitHub,
+                1,
+                serde_json::json!({
+                    "repository": integration.sources.github_full_name,
+                    "health": health,
+                }),
+            ));
+        }
+
+        // Other ecosystems
+        for (ecosystem, name, source_type) in [
+            ("homebridge", "Homebridge", DataSourceType::Homebridge),
+            ("openhab", "openHAB", DataSourceType::OpenHab),
+        ] {
+            let ports: Vec<_> = integration.foreign_ports
+                .iter()
+                .filter(|p| p.ecosystem == ecosystem)
+                .collect();
+            if !ports.is_empty() {
+                sources.push(source_for(
+                    ecosystem,
+                    name,
+                    source_type,
+                    ports.len(),
+                    serde_json::json!({
+                        "packages": ports.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+                        "popularity": ports.iter().map(|p| p.popularity).sum::<u64>(),
+                    }),
+                ));
+            }
+        }
+
+        // Protocol device catalogs
+        for (protocol, count) in &integration.protocol_devices {
+            sources.push(source_for(
+                protocol.source_key(),
+                protocol.catalog_name(),
+                DataSourceType::DeviceCatalog,
+                *count,
+                serde_json::json!({
+                    "protocol": protocol,
+                    "devices": count,
+                }),
+            ));
+        }
+
+        // Custom file sources
+        for (source, total) in &integration.custom_signals {
+            sources.push(source_for(
+                source,
+                source,
+                DataSourceType::Custom(source.clone()),
+                total.records as usize,
+                serde_json::json!({
+                    "value": total.value,
+                    "weight": total.weight,
+                    "weighted": total.weighted(),
+                    "last_seen": total.last_seen,
+                }),
+            ));
         }
 
         // GitHub source
         if let Some(github_name) = &integration.sources.github_full_name {
-            sources.push(DataSource {
-                name: "GitHub".to_string(),
-                source_type: DataSourceType::GitHub,
-                collected_at: Utc::now(),
-                data_points: 1,
-                metadata: serde_json::json!({
+            sources.push(source_for(
+                "github",
+                "GitHub",
+                DataSourceType::GitHub,
+                1,
+                serde_json::json!({
                     "full_name": github_name,
                     "stars": integration.stars,
                     "forks": integration.forks,
                     "open_issues": integration.open_issues,
                 }),
-            });
+            ));
+        }
+
+        // Hacker News source
+        if !integration.sources.hackernews_ids.is_empty() {
+            sources.push(source_for(
+                "hackernews",
+                "Hacker News",
+                DataSourceType::HackerNews,
+                integration.sources.hackernews_ids.len(),
+                serde_json::json!({
+                    "item_ids": integration.sources.hackernews_ids,
+                    "mention_count": integration.hackernews_mentions,
+                    "points": integration.hackernews_points,
+                }),
+            ));
+        }
+
+        // Reddit source
+        if !integration.sources.reddit_ids.is_empty() {
+            sources.push(source_for(
+                "reddit",
+                "Reddit",
+                DataSourceType::Reddit,
+                integration.sources.reddit_ids.len(),
+                serde_json::json!({
+                    "post_ids": integration.sources.reddit_ids,
+                    "mention_count": integration.reddit_mentions,
+                    "score": integration.reddit_score,
+                }),
+            ));
+        }
+
+        // Forum source
+        if !integration.sources.forum_topic_ids.is_empty() {
+            sources.push(source_for(
+                "ha_community",
+                "HA Community",
+                DataSourceType::Forum,
+                integration.sources.forum_topic_ids.len(),
+                serde_json::json!({
+                    "topic_ids": integration.sources.forum_topic_ids,
+                    "votes": integration.forum_votes,
+                    "views": integration.forum_views,
+                    "replies": integration.forum_replies,
+                }),
+            ));
         }
 
         // YouTube source - always include to show data was collected
         // Even if no exact match, shows general market intelligence
         if !integration.sources.youtube_video_ids.is_empty() {
             // Exact match found
-            sources.push(DataSource {
-                name: "YouTube".to_string(),
-                source_type: DataSourceType::Other("YouTube".to_string()),
-                collected_at: Utc::now(),
-                data_points: integration.sources.youtube_video_ids.len(),
-                metadata: serde_json::json!({
+            sources.push(source_for(
+                "youtube",
+                "YouTube",
+                DataSourceType::Other("YouTube".to_string()),
+                integration.sources.youtube_video_ids.len(),
+                serde_json::json!({
                     "video_ids": integration.sources.youtube_video_ids,
                     "mention_count": integration.youtube_mentions,
                     "match_type": "exact",
                 }),
-            });
+            ));
         } else {
             // No exact match, but show general market data was collected
-            sources.push(DataSource {
-                name: "YouTube (general)".to_string(),
-                source_type: DataSourceType::Other("YouTube".to_string()),
-                collected_at: Utc::now(),
-                data_points: 0,
-                metadata: serde_json::json!({
+            sources.push(source_for(
+                "youtube",
+                "YouTube (general)",
+                DataSourceType::Other("YouTube".to_string()),
+                0,
+                serde_json::json!({
                     "match_type": "general",
                     "note": "General Home Assistant market data collected, no integration-specific match",
                 }),
-            });
+            ));
         }
 
         sources
     }
 }
 
+/// Normalized numeric fields exposed to scorers as `ScoringData.signals`
+pub(crate) fn scoring_signals(integration: &NormalizedIntegration, now: DateTime<Utc>) -> BTreeMap<String, f64> {
+    let mut signals = BTreeMap::from([
+        ("stars".to_string(), f64::from(integration.stars)),
+        ("forks".to_string(), f64::from(integration.forks)),
+        ("open_issues".to_string(), f64::from(integration.open_issues)),
+        ("in_hacs".to_string(), if integration.in_hacs { 1.0 } else { 0.0 }),
+        ("youtube_mentions".to_string(), f64::from(integration.youtube_mentions)),
+        ("hackernews_mentions".to_string(), f64::from(integration.hackernews_mentions)),
+        ("hackernews_points".to_string(), integration.hackernews_points as f64),
+        ("reddit_mentions".to_string(), f64::from(integration.reddit_mentions)),
+        ("reddit_score".to_string(), integration.reddit_score as f64),
+        ("forum_topics".to_string(), f64::from(integration.forum_topics)),
+        ("forum_votes".to_string(), integration.forum_votes as f64),
+        ("foreign_popularity".to_string(), foreign_popularity(integration) as f64),
+        ("protocol_devices".to_string(), protocol_device_count(integration) as f64),
+    ]);
+    if let Some(installs) = integration.active_installs {
+        signals.insert("active_installs".to_string(), installs as f64);
+    }
+    if let Some(users) = addressable_users(integration) {
+        signals.insert("addressable_users".to_string(), users as f64);
+    }
+    if let Some(health) = &integration.maintenance {
+        signals.insert("maintenance_health".to_string(), health.score);
+    }
+    if let Some(created) = integration.created_at {
+        signals.insert("age_days".to_string(), (now - created).num_days().max(0) as f64);
+    }
+    signals
+}
+
+/// Best matches first, then most engaged; capped at `MAX_EVIDENCE`
+///
+/// Metrics aren't comparable (a view is not a vote), so engagement is the
+/// mean of an item's metrics, each scaled by its largest value in the list.
+fn rank_evidence(mut evidence: Vec<Evidence>) -> Vec<Evidence> {
+    let mut peaks: HashMap<String, u64> = HashMap::new();
+    for (metric, &value) in evidence.iter().flat_map(|e| &e.engagement) {
+        let peak = peaks.entry(metric.clone()).or_default();
+        *peak = (*peak).max(value);
+    }
+    let engagement = |e: &Evidence| {
+        let scaled = e.engagement
+            .iter()
+            .map(|(metric, &value)| match peaks[metric] {
+                0 => 0.0,
+                peak => value as f64 / peak as f64,
+            })
+            .sum::<f64>();
+        scaled / e.engagement.len().max(1) as f64
+    };
+    evidence.sort_by(|a, b| {
+        b.match_quality
+            .total_cmp(&a.match_quality)
+            .then_with(|| engagement(b).total_cmp(&engagement(a)))
+    });
+    evidence.truncate(MAX_EVIDENCE);
+    evidence
+}
+
+/// Data source entry referring to its input by source key, with that
+/// input's provenance
+///
+/// `collected_at` falls back to the analysis time only when the input's
+/// collection time is unknown.
+fn data_source(
+    provenance: Option<&SourceProvenance>,
+    source: &str,
+    name: &str,
+    source_type: DataSourceType,
+    data_points: usize,
+    metadata: serde_json::Value,
+) -> DataSource {
+    let provenance = provenance.cloned().unwrap_or_default();
+    DataSource {
+        source: source.to_string(),
+        name: name.to_string(),
+        source_type,
+        collected_at: provenance.collected_at.unwrap_or_else(Utc::now),
+        data_points,
+        artifact: provenance.artifact,
+        query: provenance.query,
+        source_records: provenance.records,
+        metadata,
+    }
+}
+
 impl Default for IntegrationAnalyzer {
     fn default() -> Self {
         Self::new()
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Fixtures;
+
+    #[test]
+    fn test_source_selection_time_window_and_provenance() {
+        let fixtures = Fixtures::new();
+        let write = |name: &str, value: serde_json::Value| fixtures.write(name, value);
+
+        let recent = (Utc::now() - chrono::Duration::days(5)).to_rfc3339();
+        let stale = (Utc::now() - chrono::Duration::days(400)).to_rfc3339();
+        let video = |id: &str, published_at: &str| serde_json::json!({
+            "id": { "videoId": id },
+            "snippet": {
+                "title": "Tado thermostat setup",
+                "channelTitle": "Smart Home",
+                "publishedAt": published_at,
+                "description": "",
+            },
+        });
+
+        let mut inputs = AnalysisInputs::new(
+            &write("hacs.json", serde_json::json!({
+                "1": { "domain": "tado", "full_name": "user/tado", "stargazers_count": 40 },
+            })),
+            &write("github.json", serde_json::json!({ "total_count": 0, "incomplete_results": false, "items": [] })),
+            &write("youtube.json", serde_json::json!({ "items": [video("a", &recent), video("b", &stale)] })),
+        );
+        inputs.hackernews_path = Some(write("hackernews.json", serde_json::json!({ "hits": [] })));
+
+        let config = AnalysisConfig {
+            min_score: 0.0,
+            enabled_sources: vec!["HACS".to_string(), "youtube".to_string()],
+            time_range_days: 30,
+            ..Default::default()
+        };
+        let result = IntegrationAnalyzer::with_config(config.clone()).analyze_from_inputs(&inputs).unwrap();
+        assert_eq!(result.metadata.sources_used, vec!["hacs", "youtube"]);
+        assert_eq!(result.opportunities[0].metadata["youtube_mentions"], 1);
+
+        let config = AnalysisConfig { time_range_days: 0, ..config };
+        let result = IntegrationAnalyzer::with_config(config.clone()).analyze_from_inputs(&inputs).unwrap();
+        assert_eq!(result.opportunities[0].metadata["youtube_mentions"], 2);
+
+        // Provenance: caller-supplied for HACS, file path and mtime for YouTube
+        let collected_at = Utc::now() - chrono::Duration::days(3);
+        inputs.provenance.insert("hacs".to_string(), SourceProvenance {
+            collected_at: Some(collected_at),
+            artifact: Some("artifact-42".to_string()),
+            query: Some("hacs/integration".to_string()),
+            records: 0,
+        });
+        let result = IntegrationAnalyzer::with_config(config).analyze_from_inputs(&inputs).unwrap();
+        let sources = &result.opportunities[0].data_sources;
+        let hacs = sources.iter().find(|s| s.name == "HACS").unwrap();
+        assert_eq!(hacs.collected_at, collected_at);
+        assert_eq!(result.provenance[&hacs.source].artifact.as_deref(), Some("artifact-42"));
+        assert_eq!(hacs.artifact.as_deref(), Some("artifact-42"));
+        assert_eq!(hacs.query.as_deref(), Some("hacs/integration"));
+        assert_eq!(hacs.source_records, 1);
+        assert_eq!(hacs.age_days(result.analyzed_at), 3);
+        let youtube = sources.iter().find(|s| s.name == "YouTube").unwrap();
+        assert_eq!(youtube.artifact.as_deref(), Some(inputs.youtube_path.as_str()));
+        assert_eq!(youtube.source_records, 2);
+    }
+
+    #[test]
+    fn test_demand_basis_per_integration() {
+        let fixtures = Fixtures::new();
+        let write = |name: &str, value: serde_json::Value| fixtures.write(name, value);
+
+        let mut inputs = AnalysisInputs::new(
+            &write("hacs.json", serde_json::json!({
+                "1": { "domain": "tado", "full_name": "user/tado", "stargazers_count": 40 },
+                "2": { "domain": "roborock", "full_name": "user/roborock", "stargazers_count": 70 },
+            })),
+            &write("github.json", serde_json::json!({ "total_count": 0, "incomplete_results": false, "items": [] })),
+            &write("youtube.json", serde_json::json!({ "items": [] })),
+        );
+        inputs.forum_path = Some(write("forum.json", serde_json::json!([
+            { "id": 1, "title": "Tado open window detection", "slug": "tado-open-window", "vote_count": 12 },
+        ])));
+
+        let config = AnalysisConfig { min_score: 0.0, ..Default::default() };
+        let result = IntegrationAnalyzer::with_config(config).analyze_from_inputs(&inputs).unwrap();
+        let demand = |name: &str| {
+            let opportunity = result.opportunities.iter().find(|o| o.name == name).unwrap();
+            opportunity.features.as_ref().unwrap().request_count
+        };
+        // Forum requests for one integration leave the others on their own signals
+        assert_eq!(demand("tado"), 12 + 5);
+        assert_eq!(demand("roborock"), 70);
+
+        // Install counts apply where analytics has the domain; the rest keep their stars
+        inputs.forum_path = None;
+        inputs.analytics_path = Some(write("analytics.json", serde_json::json!({
+            "integrations": {},
+            "custom_integrations": { "tado": { "total": 900 } },
+        })));
+        let config = AnalysisConfig { min_score: 0.0, ..Default::default() };
+        let result = IntegrationAnalyzer::with_config(config).analyze_from_inputs(&inputs).unwrap();
+        let demand = |name: &str| {
+            let opportunity = result.opportunities.iter().find(|o| o.name == name).unwrap();
+            opportunity.features.as_ref().unwrap().request_count
+        };
+        assert_eq!(demand("tado"), 90);
+        assert_eq!(demand("roborock"), 70);
+    }
+
+    #[test]
+    fn test_source_lists() {
+        let config = AnalysisConfig::default();
+        assert!(crate::types::BUILTIN_SOURCES.iter().all(|s| config.source_enabled(s)));
+
+        // A missing list enables every source; a stored list is honoured as written
+        let stored = |enabled_sources: Option<serde_json::Value>| {
+            let mut value = serde_json::json!({
+                "min_score": 50.0, "max_results": 20, "weights": crate::types::ScoreWeights::default(), "time_range_days": 90,
+            });
+            if let Some(enabled_sources) = enabled_sources {
+                value["enabled_sources"] = enabled_sources;
+            }
+            serde_json::from_value::<AnalysisConfig>(value).unwrap()
+        };
+        let missing = stored(None);
+        assert!(missing.source_enabled("youtube") && missing.source_enabled("github_maintenance"));
+        let listed = stored(Some(serde_json::json!(["github", "reddit", "hacs"])));
+        assert_eq!(listed.enabled_sources, vec!["github", "reddit", "hacs"]);
+        assert!(listed.source_enabled("reddit") && !listed.source_enabled("youtube"));
+
+        let explicit = AnalysisConfig {
+            enabled_sources: vec!["hacs".to_string(), "custom".to_string()],
+            disabled_sources: vec!["Sales".to_string()],
+            ..Default::default()
+        };
+        let explicit: AnalysisConfig = serde_json::from_value(serde_json::to_value(&explicit).unwrap()).unwrap();
+        assert!(explicit.source_enabled("HACS") && !explicit.source_enabled("youtube"));
+        assert!(explicit.source_enabled("custom") && explicit.source_disabled("sales"));
+    }
+
+    #[test]
+    fn test_issue_and_reddit_evidence() {
+        let fixtures = Fixtures::new();
+        let write = |name: &str, value: serde_json::Value| fixtures.write(name, value);
+
+        let mut inputs = AnalysisInputs::new(
+            &write("hacs.json", serde_json::json!({
+                "1": { "domain": "tado", "full_name": "user/tado", "stargazers_count": 40 },
+            })),
+            &write("github.json", serde_json::json!({ "total_count": 0, "incomplete_results": false, "items": [] })),
+            &write("youtube.json", serde_json::json!({ "items": [] })),
+        );
+        inputs.maintenance_path = Some(write("maintenance.json", serde_json::json!([{
+            "full_name": "user/tado",
+            "issues": [{
+                "id": 7, "number": 7, "title": "Add open window detection", "state": "open", "comments": 3,
+                "created_at": "2025-05-01T00:00:00Z", "updated_at": "2025-05-01T00:00:00Z", "closed_at": null,
+                "labels": [{"name": "enhancement", "color": "ffffff"}],
+                "reactions": {"total_count": 4, "+1": 4},
+            }],
+        }])));
+        inputs.reddit_path = Some(write("reddit.json", serde_json::json!({
+            "kind": "Listing",
+            "data": { "children": [{ "kind": "t3", "data": {
+                "id": "abc", "title": "Tado thermostats keep going offline", "author": "someone",
+                "subreddit": "homeassistant", "score": 52, "num_comments": 14,
+                "created_utc": Utc::now().timestamp() as f64, "url": "https://i.redd.it/x.png",
+                "selftext": "", "permalink": "/r/homeassistant/comments/abc/tado/",
+            }}]},
+        })));
+
+        let config = AnalysisConfig { min_score: 0.0, ..Default::default() };
+        let result = IntegrationAnalyzer::with_config(config).analyze_from_inputs(&inputs).unwrap();
+        let opportunity = &result.opportunities[0];
+
+        let issue = opportunity.evidence.iter().find(|e| e.kind == crate::types::EvidenceKind::Issue).unwrap();
+        assert_eq!(issue.url, "https://github.com/user/tado/issues/7");
+        assert_eq!(issue.engagement["upvotes"], 4);
+        let post = opportunity.evidence.iter().find(|e| e.source == "reddit").unwrap();
+        assert_eq!(post.url, "https://www.reddit.com/r/homeassistant/comments/abc/tado/");
+        assert_eq!(post.engagement["score"], 52);
+        assert_eq!(opportunity.metadata["reddit_mentions"], 1);
+        assert!(opportunity.data_sources.iter().any(|s| s.name == "Reddit"));
+    }
+
+    #[test]
+    fn test_rank_evidence_scales_each_metric() {
+        let item = |title: &str, engagement: &[(&str, u64)]| Evidence {
+            source: "test".to_string(),
+            kind: crate::types::EvidenceKind::Post,
+            url: format!("https://example.com/{}", title),
+            title: title.to_string(),
+            timestamp: None,
+            engagement: engagement.iter().map(|(m, v)| (m.to_string(), *v)).collect(),
+            match_quality: 1.0,
+        };
+        let evidence = vec![
+            item("quiet_thread", &[("views", 900), ("votes", 5), ("replies", 1), ("likes", 0)]),
+            item("busy_thread", &[("views", 1000), ("votes", 40), ("replies", 10), ("likes", 10)]),
+            item("story", &[("points", 300), ("comments", 40)]),
+            item("video", &[]),
+        ];
+
+        // Raw sums would rank 900 views above 300 points
+        let titles: Vec<String> = rank_evidence(evidence).into_iter().map(|e| e.title).collect();
+        assert_eq!(titles, vec!["busy_thread", "story", "quiet_thread", "video"]);
+    }
+}
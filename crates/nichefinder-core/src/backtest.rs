@@ -0,0 +1,466 @@
+//! Backtesting the scoring model against stored snapshots
+//!
+//! A backtest analyzes the data lake as of date T, scores every candidate
+//! with each scorer (or weights profile) from its feature vector, then
+//! analyzes the lake as of T+N and measures what happened to each
+//! candidate: star growth, new competing integrations and install growth.
+//! Growth in any of them counts as the opportunity having been real.
+//!
+//! Per scorer and outcome it reports the Spearman rank correlation between
+//! score and outcome, precision@k (share of the top k scored candidates
+//! whose outcome is also in the top k) and calibration (mean score against
+//! mean outcome percentile per score bucket).
+
+use crate::{
+    analysis::IntegrationAnalyzer,
+    data_lake::{DataLake, LakeQuery},
+    forecast::history_key,
+    scoring::{average_ranks, OpportunityScorer, ScoringData},
+    types::{AnalysisConfig, AnalysisResult, NicheOpportunity},
+    Error, Result,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Default number of top candidates for precision@k
+pub const DEFAULT_K: usize = 10;
+
+/// Width of a calibration bucket, in score points
+const CALIBRATION_BUCKET: f64 = 10.0;
+
+/// Outcome measured between T and T+N
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    /// GitHub stars gained
+    StarGrowth,
+
+    /// Integrations covering the same niche that appeared
+    NewCompetitors,
+
+    /// Active installs gained (only where analytics exist at both dates)
+    InstallGrowth,
+}
+
+impl Outcome {
+    /// All outcomes, in report order
+    pub const ALL: [Outcome; 3] = [Outcome::StarGrowth, Outcome::NewCompetitors, Outcome::InstallGrowth];
+
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::StarGrowth => "star_growth",
+            Outcome::NewCompetitors => "new_competitors",
+            Outcome::InstallGrowth => "install_growth",
+        }
+    }
+}
+
+/// A candidate present at both dates, with its features at T and outcomes at T+N
+#[derive(Debug, Clone)]
+pub struct CandidateOutcome {
+    pub name: String,
+
+    /// Feature vector at T
+    pub features: ScoringData,
+
+    /// Outcome values; missing when not measurable for this candidate
+    pub outcomes: HashMap<Outcome, f64>,
+}
+
+/// Candidates in a calibration bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationBucket {
+    /// Score range [lower, upper)
+    pub lower: f64,
+    pub upper: f64,
+    pub count: usize,
+    pub mean_score: f64,
+
+    /// Mean outcome percentile (0-100) of the bucket's candidates
+    pub mean_outcome_percentile: f64,
+}
+
+/// Metrics of one scorer against one outcome
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutcomeMetrics {
+    pub outcome: Outcome,
+
+    /// Candidates with a measurable outcome
+    pub samples: usize,
+
+    /// Spearman rank correlation of score and outcome; `None` when either is constant
+    pub spearman: Option<f64>,
+
+    /// Share of the top k scored candidates whose outcome is in the top k (and positive)
+    pub precision_at_k: f64,
+
+    pub calibration: Vec<CalibrationBucket>,
+
+    /// Count-weighted mean gap between bucket score and outcome percentile
+    pub calibration_error: f64,
+}
+
+/// Backtest results of one scorer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScorerBacktest {
+    pub scorer: String,
+    pub metrics: Vec<OutcomeMetrics>,
+}
+
+/// Backtest results of all scorers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestReport {
+    /// Date the candidates were scored at (T)
+    pub as_of: Option<NaiveDate>,
+
+    /// Date the outcomes were measured at (T+N)
+    pub outcome_date: Option<NaiveDate>,
+
+    /// Candidates present at both dates
+    pub candidates: usize,
+
+    /// Cut-off for precision@k
+    pub k: usize,
+
+    pub scorers: Vec<ScorerBacktest>,
+}
+
+/// Backtest of one or more scorers
+pub struct Backtest {
+    scorers: Vec<Box<dyn OpportunityScorer>>,
+    config: AnalysisConfig,
+    k: usize,
+}
+
+impl Backtest {
+    /// Backtest scorers, analyzing snapshots with the given configuration
+    ///
+    /// The score threshold and result limit are lifted so every candidate
+    /// is evaluated.
+    pub fn new(config: AnalysisConfig, scorers: Vec<Box<dyn OpportunityScorer>>) -> Self {
+        let config = AnalysisConfig {
+            min_score: 0.0,
+            max_results: usize::MAX,
+            scorers: Vec::new(),
+            ..config
+        };
+        Self { scorers, config, k: DEFAULT_K }
+    }
+
+    /// Set k for precision@k
+    pub fn with_k(mut self, k: usize) -> Self {
+        self.k = k.max(1);
+        self
+    }
+
+    /// Score the lake as of `as_of` and measure outcomes `horizon_days` later
+    pub fn run_lake(&self, lake: &DataLake, as_of: NaiveDate, horizon_days: u32) -> Result<BacktestReport> {
+        let outcome_date = as_of + Duration::days(i64::from(horizon_days));
+        let (_, before) = analyze_snapshot(lake, &self.config, as_of)?;
+        let (_, after) = analyze_snapshot(lake, &self.config, outcome_date)?;
+
+        let mut report = self.evaluate(&match_outcomes(&before, &after))?;
+        report.as_of = Some(as_of);
+        report.outcome_date = Some(outcome_date);
+        Ok(report)
+    }
+
+    /// Evaluate every scorer against matched candidates
+    pub fn evaluate(&self, candidates: &[CandidateOutcome]) -> Result<BacktestReport> {
+        let mut scorers = Vec::new();
+        for scorer in &self.scorers {
+            let scores = candidates
+                .iter()
+                .map(|c| scorer.score(&c.features).map(|s| s.composite))
+                .collect::<Result<Vec<f64>>>()?;
+
+            let metrics = Outcome::ALL
+                .iter()
+                .map(|&outcome| {
+                    let (scores, values): (Vec<f64>, Vec<f64>) = candidates
+                        .iter()
+                        .zip(&scores)
+                        .filter_map(|(c, &score)| c.outcomes.get(&outcome).map(|&value| (score, value)))
+                        .unzip();
+                    outcome_metrics(outcome, &scores, &values, self.k)
+                })
+                .collect();
+            scorers.push(ScorerBacktest { scorer: scorer.name().to_string(), metrics });
+        }
+
+        Ok(BacktestReport {
+            as_of: None,
+            outcome_date: None,
+            candidates: candidates.len(),
+            k: self.k,
+            scorers,
+        })
+    }
+}
+
+/// Analyze the most recent stored data on or before a date
+///
+/// Returns the time the data was evaluated at (`CollectionRun::observed_at`)
+/// with the result.
+pub(crate) fn analyze_snapshot(
+    lake: &DataLake,
+    config: &AnalysisConfig,
+    date: NaiveDate,
+) -> Result<(DateTime<Utc>, AnalysisResult)> {
+    let run = lake.load(LakeQuery::AsOf(date))?;
+    let as_of = run.observed_at().unwrap_or_else(Utc::now);
+    let dir = std::env::temp_dir().join(format!("nichefinder-backtest-{}-{}", date, uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| Error::DataSource(format!("Failed to create {}: {}", dir.display(), e)))?;
+
+    let result = run.write_inputs(&dir).and_then(|mut inputs| {
+        inputs.as_of = Some(as_of);
+        IntegrationAnalyzer::with_config(config.clone()).analyze_from_inputs(&inputs)
+    });
+    remove_dir(&dir);
+    Ok((as_of, result?))
+}
+
+fn remove_dir(dir: &Path) {
+    if let Err(e) = std::fs::remove_dir_all(dir) {
+        tracing::warn!("Failed to remove {}: {}", dir.display(), e);
+    }
+}
+
+/// Pair candidates scored at T with their state at T+N
+///
+/// Candidates are matched by kind and name; those that disappeared by T+N
+/// (or have no feature vector) are left out.
+pub fn match_outcomes(before: &AnalysisResult, after: &AnalysisResult) -> Vec<CandidateOutcome> {
+    let key = |o: &NicheOpportunity| history_key(o.kind, &o.name);
+    let later: HashMap<String, &ScoringData> = after.opportunities
+        .iter()
+        .filter_map(|o| o.features.as_ref().map(|f| (key(o), f)))
+        .collect();
+
+    before.opportunities
+        .iter()
+        .filter_map(|o| {
+            let features = o.features.as_ref()?;
+            let then = later.get(&key(o))?;
+            let signal = |data: &ScoringData, name: &str| data.signals.get(name).copied();
+
+            let mut outcomes = HashMap::new();
+            if let (Some(start), Some(end)) = (signal(features, "stars"), signal(then, "stars")) {
+                outcomes.insert(Outcome::StarGrowth, end - start);
+            }
+            outcomes.insert(
+                Outcome::NewCompetitors,
+                then.existing_integrations as f64 - features.existing_integrations as f64,
+            );
+            if let (Some(start), Some(end)) = (signal(features, "active_installs"), signal(then, "active_installs")) {
+                outcomes.insert(Outcome::InstallGrowth, end - start);
+            }
+
+            Some(CandidateOutcome { name: o.name.clone(), features: features.clone(), outcomes })
+        })
+        .collect()
+}
+
+fn outcome_metrics(outcome: Outcome, scores: &[f64], values: &[f64], k: usize) -> OutcomeMetrics {
+    let percentiles = percentiles(values);
+    let calibration = calibration(scores, &percentiles);
+    let samples = scores.len();
+    let calibration_error = if samples == 0 {
+        0.0
+    } else {
+        calibration
+            .iter()
+            .map(|b| b.count as f64 * (b.mean_score - b.mean_outcome_percentile).abs())
+            .sum::<f64>()
+            / samples as f64
+    };
+
+    OutcomeMetrics {
+        outcome,
+        samples,
+        spearman: spearman(scores, values),
+        precision_at_k: precision_at_k(scores, values, k),
+        calibration,
+        calibration_error,
+    }
+}
+
+/// Spearman rank correlation (Pearson correlation of average ranks)
+pub fn spearman(a: &[f64], b: &[f64]) -> Option<f64> {
+    if a.len() != b.len() || a.len() < 2 {
+        return None;
+    }
+    let (ra, rb) = (average_ranks(a), average_ranks(b));
+    let mean = (a.len() as f64 + 1.0) / 2.0;
+
+    let covariance: f64 = ra.iter().zip(&rb).map(|(x, y)| (x - mean) * (y - mean)).sum();
+    let spread = |r: &[f64]| r.iter().map(|x| (x - mean).powi(2)).sum::<f64>().sqrt();
+    let denominator = spread(&ra) * spread(&rb);
+    (denominator > 0.0).then(|| covariance / denominator)
+}
+
+/// Share of the top k by score whose outcome is positive and in the top k
+pub fn precision_at_k(scores: &[f64], values: &[f64], k: usize) -> f64 {
+    let k = k.min(scores.len());
+    if k == 0 {
+        return 0.0;
+    }
+
+    let mut by_value = values.to_vec();
+    by_value.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    let threshold = by_value[k - 1];
+
+    let mut by_score: Vec<usize> = (0..scores.len()).collect();
+    by_score.sort_by(|&a, &b| scores[b].partial_cmp(&scores[a]).unwrap_or(std::cmp::Ordering::Equal));
+    let hits = by_score[..k]
+        .iter()
+        .filter(|&&i| values[i] > 0.0 && values[i] >= threshold)
+        .count();
+    hits as f64 / k as f64
+}
+
+/// Outcome percentile (0-100) of each value, ties averaged
+fn percentiles(values: &[f64]) -> Vec<f64> {
+    if values.len() < 2 {
+        return vec![50.0; values.len()];
+    }
+    let top = values.len() as f64 - 1.0;
+    average_ranks(values).into_iter().map(|r| 100.0 * (r - 1.0) / top).collect()
+}
+
+fn calibration(scores: &[f64], percentiles: &[f64]) -> Vec<CalibrationBucket> {
+    let buckets = (100.0 / CALIBRATION_BUCKET) as usize;
+    let mut sums = vec![(0usize, 0.0, 0.0); buckets];
+    for (&score, &percentile) in scores.iter().zip(percentiles) {
+        let bucket = ((score / CALIBRATION_BUCKET) as usize).min(buckets - 1);
+        sums[bucket].0 += 1;
+        sums[bucket].1 += score;
+        sums[bucket].2 += percentile;
+    }
+
+    sums.into_iter()
+        .enumerate()
+        .filter(|(_, (count, _, _))| *count > 0)
+        .map(|(bucket, (count, score, percentile))| CalibrationBucket {
+            lower: bucket as f64 * CALIBRATION_BUCKET,
+            upper: (bucket + 1) as f64 * CALIBRATION_BUCKET,
+            count,
+            mean_score: score / count as f64,
+            mean_outcome_percentile: percentile / count as f64,
+        })
+        .collect()
+}
+
+impl BacktestReport {
+    /// Render as Markdown: a metrics table per outcome, then calibration
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# NicheFinder Backtest\n\n");
+        if let (Some(as_of), Some(outcome_date)) = (self.as_of, self.outcome_date) {
+            md.push_str(&format!("**Scored as of:** {}\n", as_of));
+            md.push_str(&format!("**Outcomes measured:** {}\n", outcome_date));
+        }
+        md.push_str(&format!("**Candidates at both dates:** {}\n\n", self.candidates));
+
+        for (index, outcome) in Outcome::ALL.iter().enumerate() {
+            md.push_str(&format!("## {}\n\n", outcome.as_str()));
+            md.push_str(&format!("| Scorer | Samples | Spearman | Precision@{} | Calibration error |\n", self.k));
+            md.push_str("|---|---|---|---|---|\n");
+            for scorer in &self.scorers {
+                let m = &scorer.metrics[index];
+                let spearman = m.spearman.map_or_else(|| "n/a".to_string(), |r| format!("{:+.3}", r));
+                md.push_str(&format!(
+                    "| {} | {} | {} | {:.2} | {:.1} |\n",
+                    scorer.scorer, m.samples, spearman, m.precision_at_k, m.calibration_error
+                ));
+            }
+            md.push('\n');
+        }
+
+        md.push_str("## Calibration\n\n");
+        md.push_str("Mean score against mean outcome percentile per score bucket.\n\n");
+        for scorer in &self.scorers {
+            for m in scorer.metrics.iter().filter(|m| !m.calibration.is_empty()) {
+                let buckets: Vec<String> = m.calibration
+                    .iter()
+                    .map(|b| format!("{:.0}-{:.0}: {:.1} vs {:.1} (n={})", b.lower, b.upper, b.mean_score, b.mean_outcome_percentile, b.count))
+                    .collect();
+                md.push_str(&format!("- **{} / {}**: {}\n", scorer.scorer, m.outcome.as_str(), buckets.join("; ")));
+            }
+        }
+        md
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::DefaultScorer;
+    use crate::types::ScoreWeights;
+    use std::collections::BTreeMap;
+
+    fn candidate(name: &str, request_count: usize, api_quality: f64, star_growth: f64) -> CandidateOutcome {
+        CandidateOutcome {
+            name: name.to_string(),
+            features: ScoringData {
+                request_count,
+                custom_signals: 0.0,
+                growth_rate: 0.0,
+                has_api: true,
+                api_quality,
+                existing_integrations: 0,
+                official_integrations: 0,
+                days_since_last_request: 0,
+                signals: BTreeMap::new(),
+            },
+            outcomes: HashMap::from([(Outcome::StarGrowth, star_growth)]),
+        }
+    }
+
+    #[test]
+    fn test_metrics() {
+        assert!((spearman(&[1.0, 2.0, 3.0], &[10.0, 20.0, 30.0]).unwrap() - 1.0).abs() < 1e-9);
+        assert!((spearman(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]).unwrap() + 1.0).abs() < 1e-9);
+        assert_eq!(spearman(&[1.0, 2.0, 3.0], &[5.0, 5.0, 5.0]), None);
+        assert_eq!(average_ranks(&[3.0, 1.0, 3.0]), vec![2.5, 1.0, 2.5]);
+
+        assert_eq!(precision_at_k(&[90.0, 80.0, 10.0, 5.0], &[50.0, 0.0, 40.0, 1.0], 2), 0.5);
+        // No growth anywhere is no hit, not a tie at the top
+        assert_eq!(precision_at_k(&[90.0, 80.0], &[0.0, 0.0], 1), 0.0);
+    }
+
+    #[test]
+    fn test_evaluate_scorers() {
+        // Demand predicted star growth; API quality did not
+        let candidates = vec![
+            candidate("a", 100, 0.1, 300.0),
+            candidate("b", 60, 0.5, 120.0),
+            candidate("c", 20, 0.7, 10.0),
+            candidate("d", 5, 0.9, 0.0),
+        ];
+        let profile = |name, demand, feasibility| -> Box<dyn OpportunityScorer> {
+            let weights = ScoreWeights { demand, feasibility, competition: 0.0, trend: 0.0 };
+            Box::new(DefaultScorer::with_weights(weights).named(name))
+        };
+        let backtest = Backtest::new(AnalysisConfig::default(), vec![profile("demand", 1.0, 0.0), profile("feasibility", 0.0, 1.0)])
+            .with_k(2);
+
+        let report = backtest.evaluate(&candidates).unwrap();
+        let star_growth = |scorer: usize| &report.scorers[scorer].metrics[0];
+        assert_eq!(report.scorers[0].scorer, "demand");
+        assert_eq!(star_growth(0).samples, 4);
+        assert!(star_growth(0).spearman.unwrap() > 0.99);
+        assert!(star_growth(1).spearman.unwrap() < -0.99);
+        assert_eq!(star_growth(0).precision_at_k, 1.0);
+        assert_eq!(star_growth(1).precision_at_k, 0.0);
+        assert!(star_growth(0).calibration_error < star_growth(1).calibration_error);
+
+        // Outcomes missing for every candidate leave no samples
+        let installs = &report.scorers[0].metrics[2];
+        assert_eq!((installs.samples, installs.spearman), (0, None));
+        assert!(report.to_markdown().contains("| demand | 4 | +1.000 | 1.00 |"));
+    }
+}
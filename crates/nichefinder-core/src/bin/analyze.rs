@@ -1,6 +1,13 @@
 //! CLI tool for analyzing integration opportunities
 
-use nichefinder_core::{IntegrationAnalyzer, AnalysisConfig};
+use nichefinder_core::{IntegrationAnalyzer, AnalysisConfig, AnalysisInputs};
+use nichefinder_core::custom_sources::FileSourceConfig;
+use nichefinder_core::data_lake::{DataLake, LakeQuery};
+use nichefinder_core::backtest::Backtest;
+use nichefinder_core::forecast::SignalHistory;
+use nichefinder_core::scoring::{scorers_from_config, DefaultScorer, OpportunityScorer};
+use nichefinder_core::types::ScoreWeights;
+use chrono::NaiveDate;
 use std::path::PathBuf;
 use clap::Parser;
 
@@ -20,6 +27,66 @@ struct Args {
     #[arg(long, default_value = "data/raw/search_youtube_videos-result.json")]
     youtube_data: PathBuf,
     
+    /// Read collected data from this data lake instead of the data files above (optional)
+    #[arg(long)]
+    lake: Option<PathBuf>,
+    
+    /// Lake runs to analyze: "latest", "YYYY-MM-DD", "..YYYY-MM-DD" or "YYYY-MM-DD..YYYY-MM-DD"
+    #[arg(long, default_value = "latest")]
+    lake_query: String,
+    
+    /// Path to HA core integration inventory file (optional)
+    #[arg(long)]
+    core_inventory: Option<PathBuf>,
+    
+    /// Path to HA analytics install-count file (optional)
+    #[arg(long)]
+    analytics_data: Option<PathBuf>,
+    
+    /// Path to local PyPI metadata dump for requirement enrichment (optional)
+    #[arg(long)]
+    pypi_data: Option<PathBuf>,
+    
+    /// Path to repository activity of existing integrations for maintenance health (optional)
+    #[arg(long)]
+    maintenance_data: Option<PathBuf>,
+    
+    /// Path to Homebridge plugin catalog dump (optional)
+    #[arg(long)]
+    homebridge_data: Option<PathBuf>,
+    
+    /// Path to openHAB add-on catalog dump (optional)
+    #[arg(long)]
+    openhab_data: Option<PathBuf>,
+    
+    /// Path to zigbee2mqtt supported-devices list (optional)
+    #[arg(long)]
+    zigbee_devices: Option<PathBuf>,
+    
+    /// Path to Z-Wave JS device config directory or JSON list (optional)
+    #[arg(long)]
+    zwave_devices: Option<PathBuf>,
+    
+    /// Path to Matter certified-product list (optional)
+    #[arg(long)]
+    matter_products: Option<PathBuf>,
+    
+    /// Custom CSV/JSON Lines source config (YAML/TOML); may be repeated
+    #[arg(long = "custom-source")]
+    custom_sources: Vec<PathBuf>,
+    
+    /// Path to Hacker News data file (optional)
+    #[arg(long)]
+    hackernews_data: Option<PathBuf>,
+    
+    /// Path to Reddit search listing file (optional)
+    #[arg(long)]
+    reddit_data: Option<PathBuf>,
+    
+    /// Path to HA Community feature-request data file (optional)
+    #[arg(long)]
+    forum_data: Option<PathBuf>,
+    
     /// Minimum score threshold (0-100)
     #[arg(long, default_value = "50.0")]
     min_score: f64,
@@ -28,6 +95,65 @@ struct Args {
     #[arg(long, default_value = "20")]
     max_results: usize,
     
+    /// Comma-separated sources to use (default: all built-in and custom sources)
+    #[arg(long, value_delimiter = ',')]
+    sources: Vec<String>,
+    
+    /// Comma-separated sources to skip
+    #[arg(long, value_delimiter = ',')]
+    exclude_sources: Vec<String>,
+    
+    /// Ignore videos, posts and other dated signals older than this many days (0 = no limit)
+    #[arg(long, default_value = "90")]
+    time_range_days: u32,
+    
+    /// Comma-separated lifecycle stages to report (emerging, growing, mature,
+    /// saturated, declining); all when omitted
+    #[arg(long, value_delimiter = ',')]
+    stages: Vec<String>,
+    
+    /// Scoring script to use instead of the weighted scorer, by name (optional)
+    #[arg(long)]
+    scorer: Option<String>,
+    
+    /// Comma-separated scorers to run side by side ("weighted" or script names)
+    #[arg(long, value_delimiter = ',')]
+    scorers: Vec<String>,
+    
+    /// How ensemble scores are combined: mean, median or rank-average
+    #[arg(long, default_value = "mean")]
+    ensemble_method: String,
+    
+    /// Directory holding scoring scripts (<name>.rhai)
+    #[arg(long, default_value = "scorers")]
+    scorer_dir: String,
+    
+    /// Forecast stars, mentions and installs and detect breakouts from the
+    /// lake's earlier snapshots (requires --lake)
+    #[arg(long)]
+    forecast: bool,
+    
+    /// Days ahead that signals are forecast
+    #[arg(long, default_value = "30")]
+    forecast_horizon_days: u32,
+    
+    /// Backtest instead of analyzing: score the lake as of this date (YYYY-MM-DD)
+    /// and measure outcomes --backtest-horizon-days later (requires --lake)
+    #[arg(long)]
+    backtest_as_of: Option<NaiveDate>,
+    
+    /// Days between scoring and measuring backtest outcomes
+    #[arg(long, default_value = "90")]
+    backtest_horizon_days: u32,
+    
+    /// Top candidates for backtest precision@k
+    #[arg(long, default_value = "10")]
+    backtest_k: usize,
+    
+    /// Extra weights profile to backtest, "name=demand,feasibility,competition,trend"; may be repeated
+    #[arg(long = "weights-profile")]
+    weights_profiles: Vec<String>,
+    
     /// Output format (json or markdown)
     #[arg(long, default_value = "markdown")]
     format: String,
@@ -48,28 +174,133 @@ fn main() -> anyhow::Result<()> {
     let config = AnalysisConfig {
         min_score: args.min_score,
         max_results: args.max_results,
+        enabled_sources: args.sources.clone(),
+        disabled_sources: args.exclude_sources.clone(),
+        time_range_days: args.time_range_days,
+        scorer: args.scorer.clone(),
+        scorer_dir: args.scorer_dir.clone(),
+        scorers: args.scorers.clone(),
+        ensemble_method: args.ensemble_method.parse()?,
+        forecast_horizon_days: args.forecast_horizon_days,
+        lifecycle_stages: args.stages.iter().map(|s| s.parse()).collect::<Result<_, _>>()?,
         ..Default::default()
     };
     
+    if let Some(as_of) = args.backtest_as_of {
+        return run_backtest(&args, config, as_of);
+    }
+    
+    // Signal history for forecasting, up to the analyzed date
+    let history = match (&args.lake, args.forecast) {
+        (Some(root), true) => {
+            let until = match args.lake_query.parse()? {
+                LakeQuery::Latest => None,
+                LakeQuery::Date(day) | LakeQuery::AsOf(day) | LakeQuery::Range { to: day, .. } => Some(day),
+            };
+            let history = SignalHistory::from_lake(&DataLake::new(root), &config, until)?;
+            tracing::info!("  Signal history: {} opportunities", history.len());
+            Some(history)
+        }
+        (None, true) => anyhow::bail!("--forecast requires --lake"),
+        (_, false) => None,
+    };
+    
     // Create analyzer
-    let analyzer = IntegrationAnalyzer::with_config(config);
+    let analyzer = IntegrationAnalyzer::from_config(config)?;
     
     // Run analysis
     tracing::info!("Starting analysis...");
-    tracing::info!("  HACS data: {}", args.hacs_data.display());
-    tracing::info!("  GitHub data: {}", args.github_data.display());
-    tracing::info!("  YouTube data: {}", args.youtube_data.display());
+    let lake_dir = std::env::temp_dir().join(format!("nichefinder-lake-inputs-{}", std::process::id()));
+    let mut lake_statuses = Vec::new();
+    let mut inputs = match &args.lake {
+        Some(root) => {
+            tracing::info!("  Data lake: {} ({})", root.display(), args.lake_query);
+            let query: LakeQuery = args.lake_query.parse()?;
+            let run = DataLake::new(root).load(query)?;
+            std::fs::create_dir_all(&lake_dir)?;
+            let mut inputs = run.write_inputs(&lake_dir)?;
+            // Evaluate at fetch time; the signal history holds this run at the same time
+            inputs.as_of = run.observed_at();
+            lake_statuses = run.statuses;
+            inputs
+        }
+        None => {
+            tracing::info!("  HACS data: {}", args.hacs_data.display());
+            tracing::info!("  GitHub data: {}", args.github_data.display());
+            tracing::info!("  YouTube data: {}", args.youtube_data.display());
+            
+            AnalysisInputs::new(
+                args.hacs_data.to_str().unwrap(),
+                args.github_data.to_str().unwrap(),
+                args.youtube_data.to_str().unwrap(),
+            )
+        }
+    };
+    if let Some(path) = &args.core_inventory {
+        tracing::info!("  HA core inventory: {}", path.display());
+        inputs.core_inventory_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.analytics_data {
+        tracing::info!("  HA analytics data: {}", path.display());
+        inputs.analytics_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.pypi_data {
+        tracing::info!("  PyPI dump: {}", path.display());
+        inputs.pypi_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.maintenance_data {
+        tracing::info!("  Maintenance data: {}", path.display());
+        inputs.maintenance_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.homebridge_data {
+        tracing::info!("  Homebridge catalog: {}", path.display());
+        inputs.homebridge_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.openhab_data {
+        tracing::info!("  openHAB catalog: {}", path.display());
+        inputs.openhab_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.zigbee_devices {
+        tracing::info!("  zigbee2mqtt devices: {}", path.display());
+        inputs.zigbee_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.zwave_devices {
+        tracing::info!("  Z-Wave JS devices: {}", path.display());
+        inputs.zwave_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.matter_products {
+        tracing::info!("  Matter products: {}", path.display());
+        inputs.matter_path = Some(path.to_str().unwrap().to_string());
+    }
+    for path in &args.custom_sources {
+        tracing::info!("  Custom source: {}", path.display());
+        inputs.custom_sources.push(FileSourceConfig::from_file(path)?);
+    }
+    if let Some(path) = &args.hackernews_data {
+        tracing::info!("  Hacker News data: {}", path.display());
+        inputs.hackernews_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.reddit_data {
+        tracing::info!("  Reddit data: {}", path.display());
+        inputs.reddit_path = Some(path.to_str().unwrap().to_string());
+    }
+    if let Some(path) = &args.forum_data {
+        tracing::info!("  Forum data: {}", path.display());
+        inputs.forum_path = Some(path.to_str().unwrap().to_string());
+    }
+    inputs.history = history;
     
-    let result = analyzer.analyze_from_files(
-        args.hacs_data.to_str().unwrap(),
-        args.github_data.to_str().unwrap(),
-        args.youtube_data.to_str().unwrap(),
-    )?;
+    let mut result = analyzer.analyze_from_inputs(&inputs)?;
+    if args.lake.is_some() {
+        result.metadata.source_status = lake_statuses;
+        let _ = std::fs::remove_dir_all(&lake_dir);
+    }
     
     tracing::info!("Analysis complete!");
     tracing::info!("  Total candidates: {}", result.metadata.total_candidates);
     tracing::info!("  Qualified candidates: {}", result.metadata.qualified_candidates);
     tracing::info!("  Duration: {:.2}s", result.metadata.duration_secs);
+    tracing::info!("  Sources used: {}", result.metadata.sources_used.join(", "));
     
     // Output results
     match args.format.as_str() {
@@ -89,6 +320,43 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+fn run_backtest(args: &Args, config: AnalysisConfig, as_of: NaiveDate) -> anyhow::Result<()> {
+    let root = args.lake.as_ref().ok_or_else(|| anyhow::anyhow!("--backtest-as-of requires --lake"))?;
+    
+    let mut scorers = scorers_from_config(&config)?;
+    for profile in &args.weights_profiles {
+        scorers.push(parse_weights_profile(profile)?);
+    }
+    
+    tracing::info!("Backtesting {} scorer(s) as of {} (+{} days)", scorers.len(), as_of, args.backtest_horizon_days);
+    let report = Backtest::new(config, scorers)
+        .with_k(args.backtest_k)
+        .run_lake(&DataLake::new(root), as_of, args.backtest_horizon_days)?;
+    
+    match args.format.as_str() {
+        "json" => println!("{}", serde_json::to_string_pretty(&report)?),
+        _ => print!("{}", report.to_markdown()),
+    }
+    Ok(())
+}
+
+/// Parse "name=demand,feasibility,competition,trend"
+fn parse_weights_profile(profile: &str) -> anyhow::Result<Box<dyn OpportunityScorer>> {
+    let (name, weights) = profile
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Invalid weights profile '{}': expected name=d,f,c,t", profile))?;
+    let weights = weights
+        .split(',')
+        .map(|w| w.trim().parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let [demand, feasibility, competition, trend] = weights[..] else {
+        anyhow::bail!("Invalid weights profile '{}': expected four weights", profile);
+    };
+    
+    let weights = ScoreWeights { demand, feasibility, competition, trend };
+    Ok(Box::new(DefaultScorer::with_weights(weights).named(name.trim())))
+}
+
 fn print_markdown_report(result: &nichefinder_core::AnalysisResult) {
     println!("# Home Assistant Integration Opportunities");
     println!();
@@ -96,6 +364,10 @@ fn print_markdown_report(result: &nichefinder_core::AnalysisResult) {
     println!("**Total Candidates:** {}", result.metadata.total_candidates);
     println!("**Qualified Opportunities:** {}", result.metadata.qualified_candidates);
     println!("**Analysis Duration:** {:.2}s", result.metadata.duration_secs);
+    println!("**Sources Used:** {}", result.metadata.sources_used.join(", "));
+    if result.config.time_range_days > 0 {
+        println!("**Time Window:** last {} days", result.config.time_range_days);
+    }
     println!();
     println!("---");
     println!();
@@ -104,6 +376,10 @@ fn print_markdown_report(result: &nichefinder_core::AnalysisResult) {
         println!("## {}. {} (Score: {:.1}/100)", i + 1, opportunity.name, opportunity.score);
         println!();
         println!("**Category:** {}", opportunity.category);
+        println!("**Kind:** {}", opportunity.kind.as_str());
+        if let Some(stage) = opportunity.lifecycle {
+            println!("**Lifecycle:** {}", stage.as_str());
+        }
         println!();
         
         // Scoring breakdown
@@ -114,6 +390,40 @@ fn print_markdown_report(result: &nichefinder_core::AnalysisResult) {
         println!("- Trend: {:.1}/100", opportunity.scoring_details.trend);
         println!();
         
+        if !opportunity.scorer_results.is_empty() {
+            println!("**Scorers ({}):**", result.config.ensemble_method.as_str());
+            for scorer in &opportunity.scorer_results {
+                println!("- {}: {:.1}/100 (rank #{})", scorer.scorer, scorer.score.composite, scorer.rank);
+            }
+            println!();
+        }
+        
+        if !opportunity.forecasts.is_empty() {
+            println!("**Forecast ({} days):**", result.config.forecast_horizon_days);
+            for forecast in &opportunity.forecasts {
+                println!(
+                    "- {}: {:.0} -> {:.0} ({:.0}% interval {:.0}-{:.0}, {:+.1}/day over {} snapshots)",
+                    forecast.signal.as_str(),
+                    forecast.current,
+                    forecast.projected,
+                    forecast.confidence * 100.0,
+                    forecast.lower,
+                    forecast.upper,
+                    forecast.trend_per_day,
+                    forecast.observations
+                );
+            }
+            println!();
+        }
+        
+        if !opportunity.differentiation.is_empty() {
+            println!("**Differentiation Opportunities:**");
+            for gap in opportunity.differentiation.iter().take(5) {
+                println!("- {}", gap.markdown_citation());
+            }
+            println!();
+        }
+        
         // Metadata
         if let Some(github_url) = opportunity.metadata.get("github_url").and_then(|v| v.as_str()) {
             println!("**GitHub:** {}", github_url);
@@ -130,19 +440,119 @@ fn print_markdown_report(result: &nichefinder_core::AnalysisResult) {
         if let Some(in_hacs) = opportunity.metadata.get("in_hacs").and_then(|v| v.as_bool()) {
             println!("**In HACS:** {}", if in_hacs { "Yes" } else { "No" });
         }
+        if let Some(ports) = opportunity.metadata.get("foreign_ports").and_then(|v| v.as_array()) {
+            for port in ports {
+                println!("**Supported in {}:** {} ({})",
+                    port.get("ecosystem").and_then(|v| v.as_str()).unwrap_or("unknown"),
+                    port.get("name").and_then(|v| v.as_str()).unwrap_or("unknown"),
+                    port.get("url").and_then(|v| v.as_str()).unwrap_or(""),
+                );
+            }
+        }
+        if let Some(devices) = opportunity.metadata.get("protocol_devices").and_then(|v| v.as_object()) {
+            if !devices.is_empty() {
+                let counts: Vec<String> = devices
+                    .iter()
+                    .map(|(protocol, count)| format!("{} {}", count, protocol))
+                    .collect();
+                println!("**Protocol-only devices:** {}", counts.join(", "));
+            }
+        }
+        if let Some(users) = opportunity.metadata.get("addressable_users").and_then(|v| v.as_u64()) {
+            println!("**Addressable Users:** {}", users);
+        }
+        if let Some(core) = opportunity.metadata.get("core_domains").and_then(|v| v.as_array()) {
+            if !core.is_empty() {
+                let domains: Vec<_> = core.iter().filter_map(|d| d.as_str()).collect();
+                println!("**Official Core Integrations:** {}", domains.join(", "));
+            }
+        }
         if let Some(youtube) = opportunity.metadata.get("youtube_mentions").and_then(|v| v.as_u64()) {
             println!("**YouTube Mentions:** {}", youtube);
         }
+        if let Some(hn) = opportunity.metadata.get("hackernews_mentions").and_then(|v| v.as_u64()) {
+            if hn > 0 {
+                println!("**Hacker News Mentions:** {}", hn);
+            }
+        }
+        if let Some(reddit) = opportunity.metadata.get("reddit_mentions").and_then(|v| v.as_u64()) {
+            if reddit > 0 {
+                println!("**Reddit Mentions:** {}", reddit);
+            }
+        }
+        if let Some(votes) = opportunity.metadata.get("forum_votes").and_then(|v| v.as_u64()) {
+            if votes > 0 {
+                println!("**Feature Request Votes:** {}", votes);
+            }
+        }
         
         println!();
         println!("**Data Sources:** {} sources", opportunity.data_sources.len());
         for source in &opportunity.data_sources {
-            println!("- {} ({} data points)", source.name, source.data_points);
+            print!(
+                "- {} ({} data points, collected {}, {} days old",
+                source.name,
+                source.data_points,
+                source.collected_at.format("%Y-%m-%d %H:%M UTC"),
+                source.age_days(result.analyzed_at),
+            );
+            if let Some(artifact) = &source.artifact {
+                print!(", from {}", artifact);
+            }
+            if let Some(query) = &source.query {
+                print!(", query \"{}\"", query);
+            }
+            println!(")");
+        }
+        
+        if !opportunity.evidence.is_empty() {
+            println!();
+            println!("**Evidence:**");
+            for (n, evidence) in opportunity.evidence.iter().enumerate() {
+                println!("{}. {}", n + 1, evidence.markdown_citation());
+            }
         }
         
         println!();
         println!("---");
         println!();
     }
+    
+    let disputed = result.scorer_disagreements(10);
+    if !disputed.is_empty() {
+        println!("## Scorer Disagreement");
+        println!();
+        for opportunity in disputed {
+            let ranks: Vec<String> = opportunity.scorer_results
+                .iter()
+                .map(|r| format!("{} #{}", r.scorer, r.rank))
+                .collect();
+            println!("- **{}**: rank spread {} ({})", opportunity.name, opportunity.rank_spread().unwrap_or(0.0), ranks.join(", "));
+        }
+        println!();
+    }
+    
+    if !result.breakouts.is_empty() {
+        println!("## Breakouts");
+        println!();
+        for breakout in &result.breakouts {
+            print!(
+                "- **{}** ({}): {} {:.0} vs {:.1} expected ({:.1}x, p={:.1e}, {} earlier snapshots)",
+                breakout.subject,
+                breakout.scope.as_str(),
+                breakout.signal,
+                breakout.observed,
+                breakout.expected,
+                breakout.ratio,
+                breakout.p_value,
+                breakout.baseline_snapshots
+            );
+            if !breakout.members.is_empty() {
+                print!(" across {}", breakout.members.join(", "));
+            }
+            println!();
+        }
+        println!();
+    }
 }
 
@@ -0,0 +1,418 @@
+//! Breakout detection on signal history
+//!
+//! Sudden spikes often mark a device launch or a vendor breaking its cloud
+//! API: a jump in YouTube mentions, a burst of forum requests or a surge of
+//! open issues on an existing integration. For each signal the latest
+//! snapshot is compared against the earlier ones, treating the signal as a
+//! Poisson count with the baseline as its mean. A breakout is flagged when
+//! the observed count is improbable under the baseline (one-sided p-value
+//! below the significance level) and also a large jump in absolute and
+//! relative terms, so small counts on a quiet baseline are not flagged.
+//!
+//! Window counts (mentions, topics, open issues) are compared as levels
+//! against their mean. Cumulative counts (stars) are compared as the gain
+//! since the previous snapshot against the baseline gain per day.
+//!
+//! Breakouts are detected per opportunity and per vendor. The vendor comes
+//! from the device catalogs where known, else from the first word of the
+//! name (e.g. "Tuya Local" and "Tuya Cloud"), skipping generic words such as
+//! "Home" or "Smart"; vendor series are the sums over their opportunities at
+//! the snapshots where all of them were observed.
+
+use crate::{
+    device_catalogs::vendor_display_name,
+    forecast::{history_key, signal_series, Observation, SignalHistory, SignalSnapshot},
+    types::OpportunityKind,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use statrs::distribution::{DiscreteCDF, Poisson};
+use std::collections::BTreeMap;
+
+/// Leading name words that describe the integration rather than its vendor
+const GENERIC_NAME_WORDS: &[&str] = &[
+    "home", "smart", "simple", "easy", "my", "the", "local", "cloud", "custom", "generic",
+    "better", "advanced", "extended", "universal", "unofficial", "open", "auto", "super",
+    "ha", "hass", "homeassistant", "mqtt", "integration",
+];
+
+/// Signals checked for breakouts, and whether each is cumulative
+pub const BREAKOUT_SIGNALS: [(&str, bool); 6] = [
+    ("youtube_mentions", false),
+    ("hackernews_mentions", false),
+    ("forum_topics", false),
+    ("forum_votes", false),
+    ("open_issues", false),
+    ("stars", true),
+];
+
+/// Lowest baseline mean used in the test, so a zero baseline is not
+/// broken by a single count
+const MIN_BASELINE_MEAN: f64 = 0.5;
+
+/// What a breakout was detected on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakoutScope {
+    /// A single opportunity
+    Integration,
+
+    /// All opportunities of a vendor
+    Vendor,
+}
+
+impl BreakoutScope {
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BreakoutScope::Integration => "integration",
+            BreakoutScope::Vendor => "vendor",
+        }
+    }
+}
+
+/// A statistically significant spike in a signal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Breakout {
+    pub scope: BreakoutScope,
+
+    /// Opportunity or vendor name
+    pub subject: String,
+
+    /// Opportunities of a vendor breakout
+    #[serde(default)]
+    pub members: Vec<String>,
+
+    /// Signal name (see `BREAKOUT_SIGNALS`)
+    pub signal: String,
+
+    /// Observed count (gain since the previous snapshot for cumulative signals)
+    pub observed: f64,
+
+    /// Count expected from the baseline
+    pub expected: f64,
+
+    /// Observed over expected
+    pub ratio: f64,
+
+    /// Probability of at least the observed count under the baseline
+    pub p_value: f64,
+
+    /// Earlier snapshots in the baseline
+    pub baseline_snapshots: usize,
+
+    /// Time of the latest snapshot
+    pub detected_at: DateTime<Utc>,
+}
+
+/// A candidate's identity and current signals
+pub struct BreakoutCandidate<'a> {
+    pub kind: OpportunityKind,
+    pub name: &'a str,
+    /// Vendor from a device catalog, when known; otherwise taken from the name
+    pub vendor: Option<&'a str>,
+    pub signals: &'a BTreeMap<String, f64>,
+}
+
+/// Flags breakouts in signal history
+#[derive(Debug, Clone)]
+pub struct BreakoutDetector {
+    significance: f64,
+    min_ratio: f64,
+    min_excess: f64,
+    min_baseline: usize,
+}
+
+impl BreakoutDetector {
+    /// Detector flagging at least doubled signals with p < 0.001 and at
+    /// least three earlier snapshots
+    pub fn new() -> Self {
+        Self {
+            significance: 0.001,
+            min_ratio: 2.0,
+            min_excess: 3.0,
+            min_baseline: 3,
+        }
+    }
+
+    /// Set the p-value below which a spike is significant
+    pub fn with_significance(mut self, significance: f64) -> Self {
+        self.significance = significance.clamp(f64::MIN_POSITIVE, 1.0);
+        self
+    }
+
+    /// Set how many times the expected count a breakout must reach
+    pub fn with_min_ratio(mut self, ratio: f64) -> Self {
+        self.min_ratio = ratio.max(1.0);
+        self
+    }
+
+    /// Set how many earlier snapshots a baseline needs
+    pub fn with_min_baseline(mut self, snapshots: usize) -> Self {
+        self.min_baseline = snapshots.max(2);
+        self
+    }
+
+    /// Breakouts of candidates observed now against their history, most
+    /// significant first
+    ///
+    /// For an analysis of a stored run, `now` must be the run's
+    /// `CollectionRun::observed_at` so the run is the latest snapshot rather
+    /// than repeated after it.
+    pub fn detect(
+        &self,
+        history: &SignalHistory,
+        candidates: &[BreakoutCandidate],
+        now: DateTime<Utc>,
+    ) -> Vec<Breakout> {
+        let mut breakouts = Vec::new();
+        let mut vendors: BTreeMap<String, Vec<(&str, Vec<SignalSnapshot>)>> = BTreeMap::new();
+
+        for candidate in candidates {
+            let key = history_key(candidate.kind, candidate.name);
+            let snapshots = history.with_current(&key, now, candidate.signals);
+            for (signal, cumulative) in BREAKOUT_SIGNALS {
+                let series = signal_series(&snapshots, |s| s.get(signal).copied());
+                if let Some(mut breakout) = self.detect_series(signal, &series, cumulative) {
+                    breakout.subject = candidate.name.to_string();
+                    breakouts.push(breakout);
+                }
+            }
+            let vendor = candidate.vendor.map(vendor_display_name).or_else(|| vendor_name(candidate.name));
+            if let Some(vendor) = vendor {
+                vendors.entry(vendor).or_default().push((candidate.name, snapshots));
+            }
+        }
+
+        for (vendor, members) in vendors.into_iter().filter(|(_, members)| members.len() > 1) {
+            for (signal, cumulative) in BREAKOUT_SIGNALS {
+                let series = vendor_series(&members, signal);
+                if let Some(mut breakout) = self.detect_series(signal, &series, cumulative) {
+                    breakout.scope = BreakoutScope::Vendor;
+                    breakout.subject = vendor.clone();
+                    breakout.members = members.iter().map(|(name, _)| name.to_string()).collect();
+                    breakouts.push(breakout);
+                }
+            }
+        }
+
+        breakouts.sort_by(|a, b| a.p_value.total_cmp(&b.p_value).then_with(|| b.ratio.total_cmp(&a.ratio)));
+        breakouts
+    }
+
+    /// Test the latest observation of a series against the earlier ones
+    ///
+    /// The returned breakout has an empty subject.
+    pub fn detect_series(&self, signal: &str, series: &[Observation], cumulative: bool) -> Option<Breakout> {
+        let (latest, baseline) = series.split_last()?;
+        if baseline.len() < self.min_baseline {
+            return None;
+        }
+
+        let (observed, expected) = if cumulative {
+            let (first, previous) = (baseline.first()?, baseline.last()?);
+            let baseline_days = days_between(first.at, previous.at);
+            let latest_days = days_between(previous.at, latest.at);
+            if baseline_days <= 0.0 || latest_days <= 0.0 {
+                return None;
+            }
+            let rate = (previous.value - first.value).max(0.0) / baseline_days;
+            (latest.value - previous.value, rate * latest_days)
+        } else {
+            let mean = baseline.iter().map(|o| o.value).sum::<f64>() / baseline.len() as f64;
+            (latest.value, mean)
+        };
+
+        let mean = expected.max(MIN_BASELINE_MEAN);
+        if observed < mean * self.min_ratio || observed - expected < self.min_excess {
+            return None;
+        }
+        let poisson = Poisson::new(mean).ok()?;
+        // P(X >= observed) = 1 - P(X <= observed - 1)
+        let p_value = 1.0 - poisson.cdf(observed.floor() as u64 - 1);
+        if p_value >= self.significance {
+            return None;
+        }
+
+        Some(Breakout {
+            scope: BreakoutScope::Integration,
+            subject: String::new(),
+            members: Vec::new(),
+            signal: signal.to_string(),
+            observed,
+            expected,
+            ratio: observed / mean,
+            p_value,
+            baseline_snapshots: baseline.len(),
+            detected_at: latest.at,
+        })
+    }
+}
+
+impl Default for BreakoutDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Vendor an opportunity name belongs to: its first word, without legal
+/// suffixes; `None` for words under three characters or generic words that
+/// don't name a vendor
+pub fn vendor_name(name: &str) -> Option<String> {
+    let display = vendor_display_name(name);
+    let first = display.split_whitespace().next()?;
+    let generic = GENERIC_NAME_WORDS.contains(&first.to_lowercase().as_str());
+    (first.chars().count() >= 3 && !generic).then(|| first.to_string())
+}
+
+fn days_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    (to - from).num_seconds() as f64 / 86_400.0
+}
+
+/// Sum of a signal over a vendor's opportunities, at the snapshots where
+/// every opportunity has it
+fn vendor_series(members: &[(&str, Vec<SignalSnapshot>)], signal: &str) -> Vec<Observation> {
+    let mut totals: BTreeMap<DateTime<Utc>, (f64, usize)> = BTreeMap::new();
+    for (_, snapshots) in members {
+        for observation in signal_series(snapshots, |s| s.get(signal).copied()) {
+            let (sum, count) = totals.entry(observation.at).or_default();
+            *sum += observation.value;
+            *count += 1;
+        }
+    }
+
+    totals
+        .into_iter()
+        .filter(|(_, (_, count))| *count == members.len())
+        .map(|(at, (value, _))| Observation { at, value })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn start() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc)
+    }
+
+    fn observe(history: &mut SignalHistory, name: &str, week: i64, signals: &[(&str, f64)]) {
+        let signals = signals.iter().map(|(k, v)| (k.to_string(), *v)).collect();
+        let key = history_key(OpportunityKind::CommunityIntegration, name);
+        history.observe(&key, start() + Duration::days(7 * week), &signals);
+    }
+
+    #[test]
+    fn test_detect_series() {
+        let detector = BreakoutDetector::new();
+        let series = |values: &[f64]| -> Vec<Observation> {
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| Observation { at: start() + Duration::days(7 * i as i64), value })
+                .collect()
+        };
+
+        // 5x jump in mentions
+        let breakout = detector.detect_series("youtube_mentions", &series(&[4.0, 3.0, 5.0, 20.0]), false).unwrap();
+        assert_eq!(breakout.expected, 4.0);
+        assert_eq!(breakout.ratio, 5.0);
+        assert!(breakout.p_value < 1e-6);
+
+        // Ordinary noise, too little history, and a tiny count on a quiet baseline
+        assert!(detector.detect_series("youtube_mentions", &series(&[4.0, 3.0, 5.0, 6.0]), false).is_none());
+        assert!(detector.detect_series("youtube_mentions", &series(&[4.0, 20.0]), false).is_none());
+        assert!(detector.detect_series("forum_topics", &series(&[0.0, 0.0, 0.0, 2.0]), false).is_none());
+
+        // Stars gained in the last week against 10 a week
+        let stars = series(&[100.0, 110.0, 120.0, 130.0, 200.0]);
+        let breakout = detector.detect_series("stars", &stars, true).unwrap();
+        assert_eq!(breakout.observed, 70.0);
+        assert!((breakout.expected - 10.0).abs() < 1e-9);
+        assert!(detector.detect_series("stars", &series(&[100.0, 110.0, 120.0, 130.0, 142.0]), true).is_none());
+    }
+
+    #[test]
+    fn test_detect_integrations_and_vendors() {
+        let mut history = SignalHistory::new();
+        for week in 0..4 {
+            observe(&mut history, "Tuya Local", week, &[("open_issues", 2.0)]);
+            observe(&mut history, "Tuya Cloud", week, &[("open_issues", 2.0)]);
+            observe(&mut history, "Acme Hub", week, &[("open_issues", 10.0)]);
+        }
+
+        // Both Tuya integrations see more issues, neither alone significantly
+        let now = start() + Duration::days(28);
+        let signals = [
+            ("Tuya Local", BTreeMap::from([("open_issues".to_string(), 7.0)])),
+            ("Tuya Cloud", BTreeMap::from([("open_issues".to_string(), 7.0)])),
+            ("Acme Hub", BTreeMap::from([("open_issues".to_string(), 40.0)])),
+        ];
+        let candidates: Vec<BreakoutCandidate> = signals
+            .iter()
+            .map(|(name, signals)| BreakoutCandidate { kind: OpportunityKind::CommunityIntegration, name, vendor: None, signals })
+            .collect();
+        let breakouts = BreakoutDetector::new().detect(&history, &candidates, now);
+
+        assert_eq!(breakouts.len(), 2);
+        assert_eq!(breakouts[0].subject, "Acme Hub");
+        assert_eq!(breakouts[0].scope, BreakoutScope::Integration);
+        assert_eq!(breakouts[1].subject, "Tuya");
+        assert_eq!(breakouts[1].scope, BreakoutScope::Vendor);
+        assert_eq!(breakouts[1].observed, 14.0);
+        assert_eq!(breakouts[1].members.len(), 2);
+    }
+
+    #[test]
+    fn test_vendor_groups_skip_generic_words() {
+        assert_eq!(vendor_name("Tuya Local").as_deref(), Some("Tuya"));
+        assert_eq!(vendor_name("Smart Irrigation"), None);
+        assert_eq!(vendor_name("Easy Time"), None);
+
+        let mut history = SignalHistory::new();
+        for week in 0..4 {
+            for name in ["Home Connect", "Home Alarm", "Aqara Sensors", "Lumi Hub"] {
+                observe(&mut history, name, week, &[("open_issues", 2.0)]);
+            }
+        }
+
+        // "Home" names share no vendor; the catalog vendor groups Aqara and Lumi
+        let now = start() + Duration::days(28);
+        let signals = BTreeMap::from([("open_issues".to_string(), 7.0)]);
+        let candidates = [
+            BreakoutCandidate { kind: OpportunityKind::CommunityIntegration, name: "Home Connect", vendor: None, signals: &signals },
+            BreakoutCandidate { kind: OpportunityKind::CommunityIntegration, name: "Home Alarm", vendor: None, signals: &signals },
+            BreakoutCandidate { kind: OpportunityKind::CommunityIntegration, name: "Aqara Sensors", vendor: Some("Aqara Ltd."), signals: &signals },
+            BreakoutCandidate { kind: OpportunityKind::CommunityIntegration, name: "Lumi Hub", vendor: Some("Aqara"), signals: &signals },
+        ];
+        let breakouts = BreakoutDetector::new().detect(&history, &candidates, now);
+
+        assert_eq!(breakouts.len(), 1);
+        assert_eq!(breakouts[0].subject, "Aqara");
+        assert_eq!(breakouts[0].members, vec!["Aqara Sensors", "Lumi Hub"]);
+    }
+
+    #[test]
+    fn test_detect_on_lake_history() {
+        // History from the lake already holds the analyzed run (week 4) with the spike
+        let mut history = SignalHistory::new();
+        for (week, stars) in [100.0, 110.0, 120.0, 130.0, 200.0].into_iter().enumerate() {
+            observe(&mut history, "Acme Hub", week as i64, &[("stars", stars)]);
+        }
+        let signals = BTreeMap::from([("stars".to_string(), 200.0)]);
+        let candidates = [BreakoutCandidate { kind: OpportunityKind::CommunityIntegration, name: "Acme Hub", vendor: None, signals: &signals }];
+
+        // Evaluated at the run's own time, the current signals replace the stored snapshot
+        let run_time = start() + Duration::days(28);
+        let breakouts = BreakoutDetector::new().detect(&history, &candidates, run_time);
+        assert_eq!(breakouts.len(), 1);
+        assert_eq!(breakouts[0].signal, "stars");
+        assert_eq!(breakouts[0].observed, 70.0);
+        assert_eq!(breakouts[0].baseline_snapshots, 4);
+
+        // A later evaluation time repeats the run as a flat point and hides the spike
+        let later = BreakoutDetector::new().detect(&history, &candidates, run_time + Duration::days(1));
+        assert!(later.is_empty());
+    }
+}
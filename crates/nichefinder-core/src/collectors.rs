@@ -1,4 +1,5 @@
-//! Data collectors for various sources (HACS, GitHub, Reddit)
+//! Data collectors for various sources (HACS, HA core, HA analytics, GitHub, Reddit, Hacker News,
+//! HA Community, Homebridge, openHAB)
 
 use crate::error::{Error, Result};
 use async_trait::async_trait;
@@ -14,6 +15,32 @@ pub trait DataCollector: Send + Sync {
     
     /// Get the source name
     fn source_name(&self) -> &str;
+
+    /// Search query or request the collector sends, for provenance
+    fn query(&self) -> Option<String> {
+        None
+    }
+
+    /// Collect data, keeping records gathered before a failure
+    ///
+    /// The default treats any error as a total failure; collectors that page
+    /// through several requests override this to return what they have.
+    async fn collect_partial(&self) -> PartialCollection {
+        match self.collect().await {
+            Ok(data) => PartialCollection { data, error: None },
+            Err(e) => PartialCollection { data: Vec::new(), error: Some(e) },
+        }
+    }
+}
+
+/// Result of a collection that may have failed part-way
+#[derive(Debug)]
+pub struct PartialCollection {
+    /// Records collected (all of them if `error` is `None`)
+    pub data: Vec<CollectedData>,
+
+    /// Error that stopped collection, if any
+    pub error: Option<Error>,
 }
 
 /// Generic collected data from any source
@@ -108,11 +135,19 @@ impl DataCollector for HacsCollector {
         
         let collected_data = integrations
             .into_iter()
-            .map(|(id, integration)| CollectedData {
-                source: "hacs".to_string(),
-                data_type: "integration".to_string(),
-                raw_data: serde_json::to_value(&integration).unwrap_or_default(),
-                collected_at: chrono::Utc::now(),
+            .map(|(id, integration)| {
+                // Keep the HACS repository id so the data.json map can be rebuilt
+                let mut raw_data = serde_json::to_value(&integration).unwrap_or_default();
+                if let Some(object) = raw_data.as_object_mut() {
+                    object.insert("id".to_string(), serde_json::Value::String(id));
+                }
+
+                CollectedData {
+                    source: "hacs".to_string(),
+                    data_type: "integration".to_string(),
+                    raw_data,
+                    collected_at: chrono::Utc::now(),
+                }
             })
             .collect();
         
@@ -120,7 +155,7 @@ impl DataCollector for HacsCollector {
     }
     
     fn source_name(&self) -> &str {
-        "HACS"
+        "hacs"
     }
 }
 
@@ -169,15 +204,80 @@ pub struct GitHubIssue {
     pub comments: u64,
     #[serde(default)]
     pub labels: Vec<GitHubLabel>,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub reactions: Option<GitHubReactions>,
     pub pull_request: Option<serde_json::Value>, // Present if this is a PR
 }
 
+/// Reaction counts on an issue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitHubReactions {
+    #[serde(default)]
+    pub total_count: u64,
+    #[serde(rename = "+1", default)]
+    pub plus_one: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitHubLabel {
     pub name: String,
     pub color: String,
 }
 
+/// GitHub API response for release data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubRelease {
+    pub tag_name: String,
+    pub created_at: String,
+    pub published_at: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+}
+
+/// GitHub API response for contributor data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubContributor {
+    pub login: String,
+    pub contributions: u64,
+}
+
+/// GitHub API response for commit data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubCommit {
+    pub sha: String,
+    pub commit: GitHubCommitDetail,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubCommitDetail {
+    pub committer: Option<GitHubCommitSignature>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubCommitSignature {
+    pub date: String,
+}
+
+/// Maintenance activity of a repository: issues (all states), releases,
+/// contributors and the latest commits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoActivity {
+    /// "owner/repo"
+    pub full_name: String,
+    #[serde(default)]
+    pub issues: Vec<GitHubIssue>,
+    #[serde(default)]
+    pub releases: Vec<GitHubRelease>,
+    #[serde(default)]
+    pub contributors: Vec<GitHubContributor>,
+    #[serde(default)]
+    pub commits: Vec<GitHubCommit>,
+}
+
 /// GitHub search API response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitHubSearchResponse {
@@ -239,17 +339,95 @@ impl GitHubCollector {
         Ok(repository)
     }
 
-    /// Fetch issues for a repository (includes PRs by default)
-    pub async fn fetch_issues(&self, owner: &str, repo: &str, state: &str) -> Result<Vec<GitHubIssue>> {
-        let path = format!("/repos/{}/{}/issues?state={}&per_page=100", owner, repo, state);
+    /// Fetch issues for a repository (includes PRs by default), newest
+    /// first, up to `max_pages` pages of 100
+    pub async fn fetch_issues(&self, owner: &str, repo: &str, state: &str, max_pages: u32) -> Result<Vec<GitHubIssue>> {
+        let mut issues = Vec::new();
+        for page in 1..=max_pages {
+            let path = format!("/repos/{}/{}/issues?state={}&per_page=100&page={}", owner, repo, state, page);
+            let response = self.connector.get(&path, None)
+                .await
+                .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+            let page_issues: Vec<GitHubIssue> = serde_json::from_value(response)
+                .map_err(|e| Error::Serialization(e))?;
+            let last_page = page_issues.len() < 100;
+            issues.extend(page_issues);
+            if last_page {
+                break;
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Fetch the latest releases of a repository
+    pub async fn fetch_releases(&self, owner: &str, repo: &str) -> Result<Vec<GitHubRelease>> {
+        let path = format!("/repos/{}/{}/releases?per_page=100", owner, repo);
         let response = self.connector.get(&path, None)
             .await
             .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
 
-        let issues: Vec<GitHubIssue> = serde_json::from_value(response)
+        let releases: Vec<GitHubRelease> = serde_json::from_value(response)
             .map_err(|e| Error::Serialization(e))?;
 
-        Ok(issues)
+        Ok(releases)
+    }
+
+    /// Fetch the top contributors of a repository
+    pub async fn fetch_contributors(&self, owner: &str, repo: &str) -> Result<Vec<GitHubContributor>> {
+        let path = format!("/repos/{}/{}/contributors?per_page=100", owner, repo);
+        let response = self.connector.get(&path, None)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        // Empty repositories answer 204 without a body
+        if response.is_null() {
+            return Ok(Vec::new());
+        }
+        let contributors: Vec<GitHubContributor> = serde_json::from_value(response)
+            .map_err(|e| Error::Serialization(e))?;
+
+        Ok(contributors)
+    }
+
+    /// Fetch the latest commits on the default branch
+    pub async fn fetch_commits(&self, owner: &str, repo: &str) -> Result<Vec<GitHubCommit>> {
+        let path = format!("/repos/{}/{}/commits?per_page=100", owner, repo);
+        let response = self.connector.get(&path, None)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        let commits: Vec<GitHubCommit> = serde_json::from_value(response)
+            .map_err(|e| Error::Serialization(e))?;
+
+        Ok(commits)
+    }
+
+    /// Fetch everything the maintenance health model needs for a repository
+    ///
+    /// Each part is optional: an empty repository answers 409 for its
+    /// commits and one with issues disabled 410 for its issues, so a failed
+    /// call is logged and left empty. Fails only when every call fails.
+    pub async fn fetch_activity(&self, owner: &str, repo: &str) -> Result<RepoActivity> {
+        let full_name = format!("{}/{}", owner, repo);
+        let (issues, releases, contributors, commits) = futures::join!(
+            self.fetch_issues(owner, repo, "all", ACTIVITY_ISSUE_PAGES),
+            self.fetch_releases(owner, repo),
+            self.fetch_contributors(owner, repo),
+            self.fetch_commits(owner, repo),
+        );
+        if let (Err(e), Err(_), Err(_), Err(_)) = (&issues, &releases, &contributors, &commits) {
+            return Err(Error::DataSource(format!("No activity of {} could be fetched: {}", full_name, e)));
+        }
+
+        Ok(RepoActivity {
+            issues: activity_part(&full_name, "issues", issues),
+            releases: activity_part(&full_name, "releases", releases),
+            contributors: activity_part(&full_name, "contributors", contributors),
+            commits: activity_part(&full_name, "commits", commits),
+            full_name,
+        })
     }
 
     /// Search for repositories matching a query
@@ -267,12 +445,25 @@ impl GitHubCollector {
     }
 }
 
+/// Pages of 100 issues read per repository for maintenance activity
+const ACTIVITY_ISSUE_PAGES: u32 = 10;
+
+/// One optional part of a repository's activity; a failed call is logged and left empty
+fn activity_part<T>(full_name: &str, part: &str, result: Result<Vec<T>>) -> Vec<T> {
+    result.unwrap_or_else(|e| {
+        tracing::warn!("Failed to fetch {} of {}: {}", part, full_name, e);
+        Vec::new()
+    })
+}
+
+/// Repository search used by `GitHubCollector::collect`
+pub const GITHUB_INTEGRATION_QUERY: &str = "home-assistant topic:home-assistant language:python";
+
 #[async_trait]
 impl DataCollector for GitHubCollector {
     async fn collect(&self) -> Result<Vec<CollectedData>> {
         // Search for Home Assistant integration repositories
-        let query = "home-assistant topic:home-assistant language:python";
-        let search_result = self.search_repositories(query).await?;
+        let search_result = self.search_repositories(GITHUB_INTEGRATION_QUERY).await?;
 
         let collected_data = search_result.items
             .into_iter()
@@ -290,6 +481,60 @@ impl DataCollector for GitHubCollector {
     fn source_name(&self) -> &str {
         "github"
     }
+
+    fn query(&self) -> Option<String> {
+        Some(GITHUB_INTEGRATION_QUERY.to_string())
+    }
+}
+
+/// Collector for the maintenance activity of existing integrations' repositories
+///
+/// At least four requests per repository (issues are paged), so callers
+/// should pass the repositories that matter (e.g. the most-starred HACS
+/// integrations) rather than every HACS integration.
+pub struct GitHubMaintenanceCollector {
+    github: GitHubCollector,
+    repos: Vec<String>,
+}
+
+impl GitHubMaintenanceCollector {
+    /// Create a collector for "owner/repo" names
+    pub async fn new(token: Option<String>, repos: Vec<String>) -> Result<Self> {
+        Ok(Self {
+            github: GitHubCollector::new(token).await?,
+            repos,
+        })
+    }
+}
+
+#[async_trait]
+impl DataCollector for GitHubMaintenanceCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let mut collected_data = Vec::new();
+
+        for full_name in &self.repos {
+            let Some((owner, repo)) = full_name.split_once('/') else {
+                tracing::warn!("Skipping malformed repository name '{}'", full_name);
+                continue;
+            };
+            // One unreachable repository (renamed, deleted) should not fail the rest
+            match self.github.fetch_activity(owner, repo).await {
+                Ok(activity) => collected_data.push(CollectedData {
+                    source: "github_maintenance".to_string(),
+                    data_type: "repo_activity".to_string(),
+                    raw_data: serde_json::to_value(&activity).unwrap_or_default(),
+                    collected_at: chrono::Utc::now(),
+                }),
+                Err(e) => tracing::warn!("Failed to fetch activity of {}: {}", full_name, e),
+            }
+        }
+
+        Ok(collected_data)
+    }
+
+    fn source_name(&self) -> &str {
+        "github_maintenance"
+    }
 }
 
 /// Reddit API response for OAuth token
@@ -313,12 +558,31 @@ pub struct RedditPost {
     pub created_utc: f64,
     pub url: String,
     pub selftext: String,
+    /// Path of the comment thread on reddit.com
+    #[serde(default)]
+    pub permalink: String,
     #[serde(default)]
     pub link_flair_text: Option<String>,
     #[serde(default)]
     pub upvote_ratio: Option<f64>,
 }
 
+impl RedditPost {
+    /// Text matched against integration names (title and self text)
+    pub fn searchable_text(&self) -> String {
+        format!("{} {}", self.title, self.selftext)
+    }
+
+    /// Comment thread URL, falling back to the post's link
+    pub fn discussion_url(&self) -> String {
+        if self.permalink.is_empty() {
+            self.url.clone()
+        } else {
+            format!("https://www.reddit.com{}", self.permalink)
+        }
+    }
+}
+
 /// Reddit listing response (contains posts)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RedditListing {
@@ -478,3 +742,1013 @@ impl DataCollector for RedditCollector {
     }
 }
 
+
+/// Hacker News search hit from the Algolia API (story or comment)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HackerNewsHit {
+    #[serde(rename = "objectID")]
+    pub object_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub points: Option<i64>,
+    #[serde(default)]
+    pub num_comments: Option<u64>,
+    pub created_at: String,
+    #[serde(default)]
+    pub created_at_i: Option<i64>,
+    #[serde(default)]
+    pub story_text: Option<String>,
+    #[serde(default)]
+    pub comment_text: Option<String>,
+    #[serde(default)]
+    pub story_id: Option<u64>,
+    #[serde(default)]
+    pub story_title: Option<String>,
+    #[serde(default, rename = "_tags")]
+    pub tags: Vec<String>,
+}
+
+impl HackerNewsHit {
+    /// Whether this hit is a comment rather than a story
+    pub fn is_comment(&self) -> bool {
+        self.tags.iter().any(|t| t == "comment")
+    }
+
+    /// Text used for matching: title plus story or comment body
+    pub fn searchable_text(&self) -> String {
+        [
+            self.title.as_deref(),
+            self.story_title.as_deref(),
+            self.story_text.as_deref(),
+            self.comment_text.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// Link to the discussion on news.ycombinator.com
+    pub fn discussion_url(&self) -> String {
+        format!("https://news.ycombinator.com/item?id={}", self.object_id)
+    }
+}
+
+/// Hacker News Algolia search response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HackerNewsSearchResponse {
+    pub hits: Vec<HackerNewsHit>,
+    #[serde(default, rename = "nbHits")]
+    pub nb_hits: u64,
+    #[serde(default)]
+    pub page: u32,
+    #[serde(default, rename = "nbPages")]
+    pub nb_pages: u32,
+}
+
+/// Hacker News collector backed by the Algolia search API
+pub struct HackerNewsCollector {
+    connector: RestApiConnector,
+    queries: Vec<String>,
+}
+
+impl HackerNewsCollector {
+    /// Create a new Hacker News collector
+    /// Always searches for "home assistant"; each integration name adds a
+    /// "home assistant <name>" query. No authentication is required.
+    pub async fn new(integration_names: Vec<String>) -> Result<Self> {
+        let mut config = RestApiConfig::default();
+        config.base_url = "https://hn.algolia.com/api/v1".to_string();
+        config.timeout_seconds = 30;
+
+        let connector = RestApiConnector::with_config(config)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        let mut queries = vec!["home assistant".to_string()];
+        queries.extend(
+            integration_names
+                .iter()
+                .map(|name| format!("home assistant {}", name)),
+        );
+
+        Ok(Self { connector, queries })
+    }
+
+    /// Queries this collector runs
+    pub fn queries(&self) -> &[String] {
+        &self.queries
+    }
+
+    /// Search Hacker News, restricted to the given tags (e.g. "story", "comment")
+    pub async fn search(&self, query: &str, tags: &str, hits_per_page: u32) -> Result<HackerNewsSearchResponse> {
+        self.search_page(query, tags, hits_per_page, 0).await
+    }
+
+    /// One page (from 0) of a Hacker News search
+    pub async fn search_page(&self, query: &str, tags: &str, hits_per_page: u32, page: u32) -> Result<HackerNewsSearchResponse> {
+        let encoded_query = urlencoding::encode(query);
+        let path = format!(
+            "/search?query={}&tags={}&hitsPerPage={}&page={}",
+            encoded_query, tags, hits_per_page, page
+        );
+
+        let response = self.connector.get(&path, None)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        let search_result: HackerNewsSearchResponse = serde_json::from_value(response)
+            .map_err(|e| Error::Serialization(e))?;
+
+        Ok(search_result)
+    }
+}
+
+#[async_trait]
+impl DataCollector for HackerNewsCollector {
+    /// Pages through every query for stories and comments; a failed search
+    /// is logged and skipped, and only fails the collection when all do
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut collected_data = Vec::new();
+        let mut searches = 0;
+        let mut failed = Vec::new();
+
+        for query in &self.queries {
+            for tags in ["story", "comment"] {
+                searches += 1;
+                let mut page = 0;
+                loop {
+                    let search_result = match self.search_page(query, tags, 100, page).await {
+                        Ok(result) => result,
+                        Err(e) => {
+                            tracing::warn!("Hacker News search '{}' ({}, page {}) failed: {}", query, tags, page, e);
+                            if page == 0 {
+                                failed.push(e);
+                            }
+                            break;
+                        }
+                    };
+
+                    for hit in search_result.hits {
+                        // The same story often shows up for several queries
+                        if !seen.insert(hit.object_id.clone()) {
+                            continue;
+                        }
+
+                        collected_data.push(CollectedData {
+                            source: "hackernews".to_string(),
+                            data_type: if hit.is_comment() { "comment" } else { "story" }.to_string(),
+                            raw_data: serde_json::to_value(&hit).unwrap_or_default(),
+                            collected_at: chrono::Utc::now(),
+                        });
+                    }
+
+                    page += 1;
+                    if page >= search_result.nb_pages.min(HACKERNEWS_MAX_PAGES) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if failed.len() == searches {
+            if let Some(e) = failed.pop() {
+                return Err(e);
+            }
+        }
+        Ok(collected_data)
+    }
+
+    fn source_name(&self) -> &str {
+        "hackernews"
+    }
+
+    fn query(&self) -> Option<String> {
+        Some(self.queries.join(" | "))
+    }
+}
+
+/// Pages of 100 hits read per Hacker News search (Algolia serves at most 1000)
+const HACKERNEWS_MAX_PAGES: u32 = 10;
+
+/// Discourse topic tag - plain names on older servers, objects on newer ones
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DiscourseTag {
+    Name(String),
+    Detailed { name: String },
+}
+
+impl DiscourseTag {
+    /// Tag name regardless of representation
+    pub fn name(&self) -> &str {
+        match self {
+            DiscourseTag::Name(name) => name,
+            DiscourseTag::Detailed { name } => name,
+        }
+    }
+}
+
+/// Discourse topic from a category listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscourseTopic {
+    pub id: u64,
+    pub title: String,
+    pub slug: String,
+    #[serde(default)]
+    pub category_id: Option<u64>,
+    #[serde(default)]
+    pub posts_count: u64,
+    #[serde(default)]
+    pub reply_count: u64,
+    #[serde(default)]
+    pub views: u64,
+    #[serde(default)]
+    pub like_count: u64,
+    /// Votes from the Discourse topic voting plugin (feature request categories)
+    #[serde(default)]
+    pub vote_count: u64,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub last_posted_at: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<DiscourseTag>,
+    /// Forum the topic was collected from (set by `DiscourseCollector`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forum_url: Option<String>,
+}
+
+impl DiscourseTopic {
+    /// Text used for matching: title plus tag names
+    pub fn searchable_text(&self) -> String {
+        let mut text = self.title.clone();
+        for tag in &self.tags {
+            text.push(' ');
+            text.push_str(tag.name());
+        }
+        text
+    }
+
+    /// Link to the topic on the given forum
+    pub fn url(&self, base_url: &str) -> String {
+        format!("{}/t/{}/{}", base_url.trim_end_matches('/'), self.slug, self.id)
+    }
+
+    /// Link to the topic on the forum it was collected from (the Home
+    /// Assistant Community when unknown)
+    pub fn link(&self) -> String {
+        self.url(self.forum_url.as_deref().unwrap_or(HA_COMMUNITY_URL))
+    }
+}
+
+/// Discourse category listing response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscourseTopicListResponse {
+    pub topic_list: DiscourseTopicList,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscourseTopicList {
+    pub topics: Vec<DiscourseTopic>,
+    #[serde(default)]
+    pub more_topics_url: Option<String>,
+}
+
+/// Discourse category to collect topics from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscourseCategory {
+    /// Category slug (e.g. "feature-requests")
+    pub slug: String,
+
+    /// Numeric category id, if known
+    pub id: Option<u64>,
+}
+
+impl DiscourseCategory {
+    /// Create a category reference
+    pub fn new(slug: &str, id: Option<u64>) -> Self {
+        Self {
+            slug: slug.to_string(),
+            id,
+        }
+    }
+
+    fn listing_path(&self, page: u32) -> String {
+        match self.id {
+            Some(id) => format!("/c/{}/{}.json?page={}", self.slug, id, page),
+            None => format!("/c/{}.json?page={}", self.slug, page),
+        }
+    }
+}
+
+/// Home Assistant Community forum base URL
+pub const HA_COMMUNITY_URL: &str = "https://community.home-assistant.io";
+
+/// Discourse forum collector for feature-request style categories
+pub struct DiscourseCollector {
+    connector: RestApiConnector,
+    base_url: String,
+    categories: Vec<DiscourseCategory>,
+    max_pages: u32,
+}
+
+impl DiscourseCollector {
+    /// Create a new Discourse collector for the given forum and categories
+    /// Each category is paginated until it runs out of topics or `max_pages` is reached
+    pub async fn new(base_url: &str, categories: Vec<DiscourseCategory>, max_pages: u32) -> Result<Self> {
+        let mut config = RestApiConfig::default();
+        config.base_url = base_url.to_string();
+        config.timeout_seconds = 30;
+
+        config.default_headers.insert(
+            "User-Agent".to_string(),
+            "NicheFinder/0.1.0".to_string(),
+        );
+
+        let connector = RestApiConnector::with_config(config)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        Ok(Self {
+            connector,
+            base_url: base_url.to_string(),
+            categories,
+            max_pages,
+        })
+    }
+
+    /// Create a collector for the Home Assistant Community "Feature Requests" category
+    pub async fn home_assistant(max_pages: u32) -> Result<Self> {
+        Self::new(
+            HA_COMMUNITY_URL,
+            vec![DiscourseCategory::new("feature-requests", None)],
+            max_pages,
+        )
+        .await
+    }
+
+    /// Forum base URL, used to build topic links
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Fetch a single page of topics from a category
+    pub async fn fetch_category_page(&self, category: &DiscourseCategory, page: u32) -> Result<DiscourseTopicList> {
+        let response = self.connector.get(&category.listing_path(page), None)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        let listing: DiscourseTopicListResponse = serde_json::from_value(response)
+            .map_err(|e| Error::Serialization(e))?;
+
+        Ok(listing.topic_list)
+    }
+
+    /// Fetch all topics from a category, following pagination
+    pub async fn fetch_category_topics(&self, category: &DiscourseCategory) -> Result<Vec<DiscourseTopic>> {
+        let mut topics = Vec::new();
+        self.fetch_category_into(category, &mut topics).await?;
+        Ok(topics)
+    }
+
+    /// Page through a category, keeping the topics of pages fetched before a failure
+    async fn fetch_category_into(&self, category: &DiscourseCategory, topics: &mut Vec<DiscourseTopic>) -> Result<()> {
+        for page in 0..self.max_pages {
+            let listing = self.fetch_category_page(category, page).await?;
+            let has_more = listing.more_topics_url.is_some();
+
+            if listing.topics.is_empty() {
+                break;
+            }
+            topics.extend(listing.topics.into_iter().map(|topic| DiscourseTopic {
+                forum_url: Some(self.base_url.clone()),
+                ..topic
+            }));
+
+            if !has_more {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DataCollector for DiscourseCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let partial = self.collect_partial().await;
+        match partial.error {
+            Some(e) => Err(e),
+            None => Ok(partial.data),
+        }
+    }
+
+    /// Categories are collected independently; a failing category or page
+    /// keeps the topics fetched before it
+    async fn collect_partial(&self) -> PartialCollection {
+        let mut topics = Vec::new();
+        let mut error = None;
+
+        for category in &self.categories {
+            if let Err(e) = self.fetch_category_into(category, &mut topics).await {
+                tracing::warn!("Discourse category '{}' failed: {}", category.slug, e);
+                error.get_or_insert(e);
+            }
+        }
+
+        let data = topics
+            .into_iter()
+            .map(|topic| CollectedData {
+                source: "ha_community".to_string(),
+                data_type: "topic".to_string(),
+                raw_data: serde_json::to_value(&topic).unwrap_or_default(),
+                collected_at: chrono::Utc::now(),
+            })
+            .collect();
+        PartialCollection { data, error }
+    }
+
+    fn source_name(&self) -> &str {
+        "ha_community"
+    }
+
+    fn query(&self) -> Option<String> {
+        let base_url = self.base_url.trim_end_matches('/');
+        let categories: Vec<String> = self.categories.iter().map(|c| format!("{}/c/{}", base_url, c.slug)).collect();
+        Some(categories.join(" | "))
+    }
+}
+
+/// Home Assistant core integration manifest (`homeassistant/components/<domain>/manifest.json`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoreIntegrationManifest {
+    pub domain: String,
+    pub name: String,
+    #[serde(default)]
+    pub integration_type: Option<String>,
+    #[serde(default)]
+    pub iot_class: Option<String>,
+    #[serde(default)]
+    pub quality_scale: Option<String>,
+    #[serde(default)]
+    pub config_flow: bool,
+    #[serde(default)]
+    pub requirements: Vec<String>,
+    #[serde(default)]
+    pub codeowners: Vec<String>,
+    #[serde(default)]
+    pub documentation: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Set on virtual integrations that point at another domain
+    #[serde(default)]
+    pub supported_by: Option<String>,
+}
+
+impl CoreIntegrationManifest {
+    /// Whether this manifest describes a device/service integration rather than
+    /// an entity platform or system component (e.g. "light", "recorder")
+    pub fn is_device_or_service(&self) -> bool {
+        !matches!(self.integration_type.as_deref(), Some("entity") | Some("system"))
+    }
+}
+
+/// GitHub git trees API response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GitHubTree {
+    tree: Vec<GitHubTreeEntry>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+/// GitHub git trees API entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GitHubTreeEntry {
+    path: String,
+    #[serde(rename = "type")]
+    entry_type: String,
+}
+
+/// Manifests fetched from raw.githubusercontent.com at once
+const MANIFEST_CONCURRENCY: usize = 16;
+
+/// Integration domain of a `homeassistant/components/<domain>/manifest.json` tree path
+fn component_manifest_domain(path: &str) -> Option<&str> {
+    let domain = path
+        .strip_prefix("homeassistant/components/")?
+        .strip_suffix("/manifest.json")?;
+    (!domain.is_empty() && !domain.contains('/')).then_some(domain)
+}
+
+/// Where to read core integration manifests from
+#[derive(Debug, Clone)]
+pub enum CoreInventorySource {
+    /// Local checkout of home-assistant/core
+    LocalCheckout(std::path::PathBuf),
+
+    /// home-assistant/core on GitHub at the given branch or tag
+    GitHub { git_ref: String, token: Option<String> },
+}
+
+/// Home Assistant core integration inventory collector
+pub struct HaCoreCollector {
+    source: CoreInventorySource,
+    contents: Option<RestApiConnector>,
+    raw: Option<RestApiConnector>,
+}
+
+impl HaCoreCollector {
+    /// Create a new core inventory collector
+    /// GitHub mode lists the manifests through the git trees API and reads
+    /// them from raw.githubusercontent.com
+    pub async fn new(source: CoreInventorySource) -> Result<Self> {
+        let (contents, raw) = match &source {
+            CoreInventorySource::LocalCheckout(_) => (None, None),
+            CoreInventorySource::GitHub { token, .. } => {
+                let mut config = RestApiConfig::default();
+                config.base_url = "https://api.github.com".to_string();
+                config.timeout_seconds = 30;
+                if let Some(token) = token {
+                    config.default_headers.insert(
+                        "Authorization".to_string(),
+                        format!("Bearer {}", token),
+                    );
+                }
+                config.default_headers.insert(
+                    "User-Agent".to_string(),
+                    "NicheFinder/0.1.0".to_string(),
+                );
+
+                let mut raw_config = RestApiConfig::default();
+                raw_config.base_url = "https://raw.githubusercontent.com".to_string();
+                raw_config.timeout_seconds = 30;
+
+                let contents = RestApiConnector::with_config(config)
+                    .await
+                    .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+                let raw = RestApiConnector::with_config(raw_config)
+                    .await
+                    .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+                (Some(contents), Some(raw))
+            }
+        };
+
+        Ok(Self { source, contents, raw })
+    }
+
+    /// Fetch all core integration manifests
+    pub async fn fetch_manifests(&self) -> Result<Vec<CoreIntegrationManifest>> {
+        match &self.source {
+            CoreInventorySource::LocalCheckout(root) => Self::read_local_manifests(root),
+            CoreInventorySource::GitHub { git_ref, .. } => self.fetch_github_manifests(git_ref).await,
+        }
+    }
+
+    /// Read manifests from a local checkout of home-assistant/core
+    fn read_local_manifests(root: &std::path::Path) -> Result<Vec<CoreIntegrationManifest>> {
+        let components = root.join("homeassistant").join("components");
+        let entries = std::fs::read_dir(&components)
+            .map_err(|e| Error::DataSource(format!("Failed to read {}: {}", components.display(), e)))?;
+
+        let mut manifests = Vec::new();
+        for entry in entries.flatten() {
+            let manifest_path = entry.path().join("manifest.json");
+            if !manifest_path.is_file() {
+                continue;
+            }
+
+            let content = match std::fs::read_to_string(&manifest_path) {
+                Ok(content) => content,
+                Err(e) => {
+                    tracing::warn!("Skipping core manifest {}: {}", manifest_path.display(), e);
+                    continue;
+                }
+            };
+            match serde_json::from_str::<CoreIntegrationManifest>(&content) {
+                Ok(manifest) => manifests.push(manifest),
+                Err(e) => tracing::warn!("Skipping core manifest {}: {}", manifest_path.display(), e),
+            }
+        }
+
+        manifests.sort_by(|a, b| a.domain.cmp(&b.domain));
+        Ok(manifests)
+    }
+
+    /// Fetch manifests from home-assistant/core on GitHub
+    ///
+    /// The contents API stops listing a directory at 1000 entries, so the
+    /// manifests are found in the recursive git tree instead. Manifests that
+    /// fail to fetch are skipped with a warning.
+    async fn fetch_github_manifests(&self, git_ref: &str) -> Result<Vec<CoreIntegrationManifest>> {
+        use futures::stream::{self, StreamExt};
+
+        let (Some(contents), Some(raw)) = (&self.contents, &self.raw) else {
+            return Err(Error::Config("GitHub connectors not initialized".to_string()));
+        };
+
+        let path = format!("/repos/home-assistant/core/git/trees/{}?recursive=1", git_ref);
+        let response = contents.get(&path, None)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+        let tree: GitHubTree = serde_json::from_value(response)
+            .map_err(|e| Error::Serialization(e))?;
+        if tree.truncated {
+            tracing::warn!("Git tree of home-assistant/core@{} is truncated; core inventory is incomplete", git_ref);
+        }
+
+        let manifest_paths: Vec<(String, String)> = tree
+            .tree
+            .iter()
+            .filter(|entry| entry.entry_type == "blob")
+            .filter_map(|entry| {
+                let domain = component_manifest_domain(&entry.path)?;
+                Some((domain.to_string(), format!("/home-assistant/core/{}/{}", git_ref, entry.path)))
+            })
+            .collect();
+
+        let results: Vec<_> = stream::iter(manifest_paths)
+            .map(|(domain, manifest_path)| async move {
+                let response = raw.get(&manifest_path, None).await;
+                (domain, response)
+            })
+            .buffer_unordered(MANIFEST_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut manifests = Vec::new();
+        let mut failed = 0;
+        for (domain, response) in results {
+            let response = match response {
+                Ok(response) => response,
+                Err(e) => {
+                    tracing::warn!("Failed to fetch core manifest for {}: {}", domain, e);
+                    failed += 1;
+                    continue;
+                }
+            };
+            match serde_json::from_value::<CoreIntegrationManifest>(response) {
+                Ok(manifest) => manifests.push(manifest),
+                Err(e) => tracing::warn!("Skipping core manifest for {}: {}", domain, e),
+            }
+        }
+
+        // Only fail when no manifest could be fetched at all
+        if failed > 0 {
+            if manifests.is_empty() {
+                return Err(Error::DataSource(format!(
+                    "No core manifests fetched from home-assistant/core@{} ({} failed)",
+                    git_ref, failed
+                )));
+            }
+            tracing::warn!("Skipped {} of {} core manifests that failed to fetch", failed, failed + manifests.len());
+        }
+
+        manifests.sort_by(|a, b| a.domain.cmp(&b.domain));
+        Ok(manifests)
+    }
+}
+
+#[async_trait]
+impl DataCollector for HaCoreCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let manifests = self.fetch_manifests().await?;
+
+        let collected_data = manifests
+            .into_iter()
+            .map(|manifest| CollectedData {
+                source: "ha_core".to_string(),
+                data_type: "manifest".to_string(),
+                raw_data: serde_json::to_value(&manifest).unwrap_or_default(),
+                collected_at: chrono::Utc::now(),
+            })
+            .collect();
+
+        Ok(collected_data)
+    }
+
+    fn source_name(&self) -> &str {
+        "ha_core"
+    }
+}
+
+/// Install statistics for a custom integration from HA analytics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomIntegrationStats {
+    #[serde(default)]
+    pub total: u64,
+    #[serde(default)]
+    pub versions: HashMap<String, u64>,
+}
+
+/// Snapshot of analytics.home-assistant.io install counts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsSnapshot {
+    /// Unix timestamp (milliseconds) of the snapshot
+    #[serde(default)]
+    pub last_updated: Option<i64>,
+    /// Installations reporting analytics
+    #[serde(default)]
+    pub active_installations: u64,
+    /// Core integration domain -> installations using it
+    pub integrations: HashMap<String, u64>,
+    /// Custom integration domain -> install statistics
+    #[serde(default)]
+    pub custom_integrations: HashMap<String, CustomIntegrationStats>,
+}
+
+impl AnalyticsSnapshot {
+    /// Installs for a core integration domain
+    pub fn core_installs(&self, domain: &str) -> Option<u64> {
+        self.integrations.get(domain).copied()
+    }
+
+    /// Installs for a custom integration domain
+    pub fn custom_installs(&self, domain: &str) -> Option<u64> {
+        self.custom_integrations.get(domain).map(|stats| stats.total)
+    }
+}
+
+/// Home Assistant analytics collector (per-integration install counts)
+pub struct HaAnalyticsCollector {
+    connector: RestApiConnector,
+}
+
+impl HaAnalyticsCollector {
+    /// Create a new analytics collector
+    pub async fn new() -> Result<Self> {
+        let mut config = RestApiConfig::default();
+        config.base_url = "https://analytics.home-assistant.io".to_string();
+        config.timeout_seconds = 30;
+
+        let connector = RestApiConnector::with_config(config)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        Ok(Self { connector })
+    }
+
+    /// Fetch the current install-count snapshot
+    pub async fn fetch_current(&self) -> Result<AnalyticsSnapshot> {
+        let response = self.connector.get("/current_data.json", None)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        let snapshot: AnalyticsSnapshot = serde_json::from_value(response)
+            .map_err(|e| Error::Serialization(e))?;
+
+        Ok(snapshot)
+    }
+}
+
+#[async_trait]
+impl DataCollector for HaAnalyticsCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let snapshot = self.fetch_current().await?;
+
+        let core = snapshot.integrations.iter().map(|(domain, installs)| CollectedData {
+            source: "ha_analytics".to_string(),
+            data_type: "core_installs".to_string(),
+            raw_data: serde_json::json!({ "domain": domain, "installs": installs }),
+            collected_at: chrono::Utc::now(),
+        });
+        let custom = snapshot.custom_integrations.iter().map(|(domain, stats)| CollectedData {
+            source: "ha_analytics".to_string(),
+            data_type: "custom_installs".to_string(),
+            raw_data: serde_json::json!({ "domain": domain, "installs": stats.total }),
+            collected_at: chrono::Utc::now(),
+        });
+
+        Ok(core.chain(custom).collect())
+    }
+
+    fn source_name(&self) -> &str {
+        "ha_analytics"
+    }
+}
+
+/// Read a local JSON dump for dump-backed collectors
+fn read_dump<T: serde::de::DeserializeOwned>(path: &std::path::Path, what: &str) -> Result<T> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| Error::DataSource(format!("Failed to read {} dump {}: {}", what, path.display(), e)))?;
+
+    serde_json::from_str(&content).map_err(|e| Error::Serialization(e))
+}
+
+/// npm package metadata from a registry search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpmPackage {
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub links: HashMap<String, String>,
+}
+
+/// npm download counts (not part of the search API; merged in when dumping)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NpmDownloads {
+    #[serde(default)]
+    pub weekly: u64,
+    #[serde(default)]
+    pub monthly: u64,
+}
+
+/// Homebridge plugin from an npm registry search (`keywords:homebridge-plugin`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HomebridgePlugin {
+    pub package: NpmPackage,
+    #[serde(default)]
+    pub downloads: Option<NpmDownloads>,
+    #[serde(default)]
+    pub flags: Option<serde_json::Value>,
+}
+
+/// npm keyword every published Homebridge plugin carries
+const HOMEBRIDGE_PLUGIN_KEYWORD: &str = "homebridge-plugin";
+
+/// Keywords of Homebridge plugins that don't integrate a device or vendor
+/// (virtual and placeholder accessories); protocol keywords such as `mqtt`
+/// or `http` are common on device plugins too, so they don't exclude one
+const NON_DEVICE_KEYWORDS: &[&str] = &["dummy", "virtual", "fake", "example", "template"];
+
+impl HomebridgePlugin {
+    /// Whether the package is a Homebridge plugin for a device or vendor:
+    /// tagged `homebridge-plugin` and without a non-device keyword
+    pub fn is_device_plugin(&self) -> bool {
+        let has = |keyword: &str| self.package.keywords.iter().any(|k| k.eq_ignore_ascii_case(keyword));
+        has(HOMEBRIDGE_PLUGIN_KEYWORD) && !NON_DEVICE_KEYWORDS.iter().any(|k| has(k))
+    }
+
+    /// Device/vendor name derived from the package name
+    /// ("@acme/homebridge-roborock-vacuum" -> "roborock vacuum")
+    pub fn target_name(&self) -> String {
+        let name = self.package.name.rsplit('/').next().unwrap_or(&self.package.name);
+        name.trim_start_matches("homebridge-")
+            .trim_end_matches("-platform")
+            .trim_end_matches("-plugin")
+            .replace(['-', '_'], " ")
+    }
+
+    /// Monthly downloads, the popularity signal for Homebridge plugins
+    pub fn monthly_downloads(&self) -> u64 {
+        self.downloads.as_ref().map(|d| d.monthly).unwrap_or(0)
+    }
+
+    /// Link to the package on npm
+    pub fn url(&self) -> String {
+        self.package.links.get("npm")
+            .cloned()
+            .unwrap_or_else(|| format!("https://www.npmjs.com/package/{}", self.package.name))
+    }
+}
+
+/// npm registry search response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpmSearchResponse {
+    pub objects: Vec<HomebridgePlugin>,
+    #[serde(default)]
+    pub total: u64,
+}
+
+/// Homebridge plugin catalog collector backed by a local npm search dump
+pub struct HomebridgeCollector {
+    dump_path: std::path::PathBuf,
+}
+
+impl HomebridgeCollector {
+    /// Create a collector reading an npm search response dump
+    pub fn from_dump(dump_path: impl Into<std::path::PathBuf>) -> Self {
+        Self { dump_path: dump_path.into() }
+    }
+
+    /// Read all plugins from the dump
+    pub fn fetch_plugins(&self) -> Result<Vec<HomebridgePlugin>> {
+        let response: NpmSearchResponse = read_dump(&self.dump_path, "Homebridge")?;
+        Ok(response.objects)
+    }
+}
+
+#[async_trait]
+impl DataCollector for HomebridgeCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let collected_data = self.fetch_plugins()?
+            .into_iter()
+            .map(|plugin| CollectedData {
+                source: "homebridge".to_string(),
+                data_type: "plugin".to_string(),
+                raw_data: serde_json::to_value(&plugin).unwrap_or_default(),
+                collected_at: chrono::Utc::now(),
+            })
+            .collect();
+
+        Ok(collected_data)
+    }
+
+    fn source_name(&self) -> &str {
+        "homebridge"
+    }
+}
+
+/// openHAB add-on (REST `/rest/addons` format, with optional community popularity)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenHabAddon {
+    pub uid: String,
+    pub id: String,
+    pub label: String,
+    #[serde(rename = "type")]
+    pub addon_type: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub keywords: Option<String>,
+    #[serde(default)]
+    pub connection: Option<String>,
+    #[serde(default)]
+    pub link: Option<String>,
+    /// Likes on the community marketplace topic, when the add-on has one
+    #[serde(default)]
+    pub like_count: Option<u64>,
+    /// Install count, when known
+    #[serde(default)]
+    pub installs: Option<u64>,
+}
+
+impl OpenHabAddon {
+    /// Device/vendor name derived from the label ("Hue Binding" -> "Hue")
+    pub fn target_name(&self) -> String {
+        self.label
+            .trim_end_matches(" Binding")
+            .trim_end_matches(" Integration")
+            .trim()
+            .to_string()
+    }
+
+    /// Popularity: installs when known, otherwise ten installs per like
+    pub fn popularity(&self) -> u64 {
+        self.installs
+            .or(self.like_count.map(|likes| likes * 10))
+            .unwrap_or(0)
+    }
+
+    /// Link to the add-on documentation
+    pub fn url(&self) -> String {
+        self.link.clone().unwrap_or_else(|| {
+            format!("https://www.openhab.org/addons/{}s/{}/", self.addon_type, self.id)
+        })
+    }
+}
+
+/// openHAB add-on catalog collector backed by a local dump
+pub struct OpenHabCollector {
+    dump_path: std::path::PathBuf,
+}
+
+impl OpenHabCollector {
+    /// Create a collector reading an add-on catalog dump
+    pub fn from_dump(dump_path: impl Into<std::path::PathBuf>) -> Self {
+        Self { dump_path: dump_path.into() }
+    }
+
+    /// Read all bindings from the dump (other add-on types don't target devices)
+    pub fn fetch_bindings(&self) -> Result<Vec<OpenHabAddon>> {
+        let addons: Vec<OpenHabAddon> = read_dump(&self.dump_path, "openHAB")?;
+        Ok(addons.into_iter().filter(|a| a.addon_type == "binding").collect())
+    }
+}
+
+#[async_trait]
+impl DataCollector for OpenHabCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let collected_data = self.fetch_bindings()?
+            .into_iter()
+            .map(|addon| CollectedData {
+                source: "openhab".to_string(),
+                data_type: "binding".to_string(),
+                raw_data: serde_json::to_value(&addon).unwrap_or_default(),
+                collected_at: chrono::Utc::now(),
+            })
+            .collect();
+
+        Ok(collected_data)
+    }
+
+    fn source_name(&self) -> &str {
+        "openhab"
+    }
+}
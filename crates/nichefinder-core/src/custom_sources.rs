@@ -0,0 +1,418 @@
+//! File-based custom demand sources (CSV / JSON Lines)
+//!
+//! Internal spreadsheets — sales requests, support-ticket tallies, surveys —
+//! are imported through a column mapping and matched against integrations
+//! like any other mention source, then scored as weighted custom demand.
+//!
+//! ```yaml
+//! name: support_tickets
+//! path: data/support_tickets.csv
+//! weight: 2.0
+//! columns:
+//!   name: product
+//!   alias: other_names
+//!   value: ticket_count
+//!   timestamp: week
+//! ```
+
+use crate::{
+    collectors::{CollectedData, DataCollector},
+    matching::MatchingEngine,
+    transform::NormalizedIntegration,
+    Error, Result,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+fn default_alias_separator() -> String {
+    ";".to_string()
+}
+
+/// File format of a custom source
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileFormat {
+    Csv,
+    #[serde(alias = "ndjson")]
+    Jsonl,
+}
+
+/// Which columns (CSV) or keys (JSON Lines) hold each signal field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnMapping {
+    /// Device, vendor or integration name
+    pub name: String,
+
+    /// Additional names, split on `alias_separator`
+    #[serde(default)]
+    pub alias: Option<String>,
+
+    #[serde(default = "default_alias_separator")]
+    pub alias_separator: String,
+
+    /// Signal value (count, votes, revenue...); each row counts as 1 without it
+    #[serde(default)]
+    pub value: Option<String>,
+
+    /// Timestamp (RFC 3339 or YYYY-MM-DD)
+    #[serde(default)]
+    pub timestamp: Option<String>,
+}
+
+/// Configuration for a file-based custom source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileSourceConfig {
+    /// Source name, used as `CollectedData.source` and in opportunity metadata
+    pub name: String,
+
+    /// Path to the CSV or JSON Lines file
+    pub path: PathBuf,
+
+    /// File format; inferred from the extension when omitted
+    #[serde(default)]
+    pub format: Option<FileFormat>,
+
+    /// Column mapping
+    pub columns: ColumnMapping,
+
+    /// Multiplier applied to signal values before they count as demand
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+}
+
+impl FileSourceConfig {
+    /// Load a config from a `.yaml`/`.yml` or `.toml` file
+    ///
+    /// A relative data `path` is resolved against the config file's directory.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read custom source config {}: {}", path.display(), e)))?;
+
+        let mut config: Self = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&content)
+                .map_err(|e| Error::Config(format!("Invalid custom source config: {}", e)))?,
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+                .map_err(|e| Error::Config(format!("Invalid custom source config: {}", e)))?,
+            _ => {
+                return Err(Error::Config(format!(
+                    "Unsupported custom source config format: {}",
+                    path.display()
+                )))
+            }
+        };
+
+        if config.path.is_relative() {
+            if let Some(dir) = path.parent() {
+                config.path = dir.join(&config.path);
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Configured or inferred file format
+    pub fn file_format(&self) -> Result<FileFormat> {
+        if let Some(format) = self.format {
+            return Ok(format);
+        }
+
+        match self.path.extension().and_then(|ext| ext.to_str()) {
+            Some("csv") => Ok(FileFormat::Csv),
+            Some("jsonl") | Some("ndjson") => Ok(FileFormat::Jsonl),
+            _ => Err(Error::Config(format!(
+                "Cannot infer format of {}; set `format` to csv or jsonl",
+                self.path.display()
+            ))),
+        }
+    }
+}
+
+/// A single imported signal row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomSignal {
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub value: f64,
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// Custom source totals attached to a matched integration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomSignalTotal {
+    /// Source weight
+    pub weight: f64,
+
+    /// Sum of matched signal values (unweighted)
+    pub value: f64,
+
+    /// Number of matched rows
+    pub records: u32,
+
+    /// Most recent matched row
+    pub last_seen: Option<DateTime<Utc>>,
+}
+
+impl CustomSignalTotal {
+    /// Value with the source weight applied
+    pub fn weighted(&self) -> f64 {
+        self.value * self.weight
+    }
+}
+
+/// File-based data collector for CSV / JSON Lines exports
+pub struct FileCollector {
+    config: FileSourceConfig,
+}
+
+impl FileCollector {
+    /// Create a file collector from a source config
+    pub fn new(config: FileSourceConfig) -> Self {
+        Self { config }
+    }
+
+    /// Create a file collector from a config file
+    pub fn from_config_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(FileSourceConfig::from_file(path)?))
+    }
+
+    /// Source config
+    pub fn config(&self) -> &FileSourceConfig {
+        &self.config
+    }
+
+    /// Read all signal rows; rows without a name are skipped
+    pub fn read_signals(&self) -> Result<Vec<CustomSignal>> {
+        let rows = match self.config.file_format()? {
+            FileFormat::Csv => self.read_csv_rows()?,
+            FileFormat::Jsonl => self.read_jsonl_rows()?,
+        };
+
+        let columns = &self.config.columns;
+        let mut signals = Vec::new();
+        for row in rows {
+            let field = |column: &Option<String>| column.as_ref().and_then(|c| row.get(c)).map(|v| v.trim());
+
+            let name = match row.get(&columns.name).map(|v| v.trim()) {
+                Some(name) if !name.is_empty() => name.to_string(),
+                _ => continue,
+            };
+
+            let aliases = field(&columns.alias)
+                .map(|aliases| {
+                    aliases
+                        .split(columns.alias_separator.as_str())
+                        .map(|a| a.trim().to_string())
+                        .filter(|a| !a.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let value = match field(&columns.value) {
+                Some(raw) if !raw.is_empty() => raw.replace(',', "").parse::<f64>().map_err(|_| {
+                    Error::DataSource(format!("Invalid value '{}' for {} in {}", raw, name, self.config.name))
+                })?,
+                _ => 1.0,
+            };
+
+            signals.push(CustomSignal {
+                name,
+                aliases,
+                value,
+                timestamp: field(&columns.timestamp).and_then(parse_timestamp),
+            });
+        }
+
+        Ok(signals)
+    }
+
+    fn read_csv_rows(&self) -> Result<Vec<HashMap<String, String>>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::Headers)
+            .from_path(&self.config.path)
+            .map_err(|e| self.read_error(e))?;
+
+        let headers = reader.headers().map_err(|e| self.read_error(e))?.clone();
+
+        reader
+            .records()
+            .map(|record| {
+                let record = record.map_err(|e| self.read_error(e))?;
+                Ok(headers
+                    .iter()
+                    .zip(record.iter())
+                    .map(|(h, v)| (h.to_string(), v.to_string()))
+                    .collect())
+            })
+            .collect()
+    }
+
+    fn read_jsonl_rows(&self) -> Result<Vec<HashMap<String, String>>> {
+        let content = std::fs::read_to_string(&self.config.path).map_err(|e| self.read_error(e))?;
+
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let object: serde_json::Map<String, Value> = serde_json::from_str(line)?;
+                Ok(object
+                    .into_iter()
+                    .filter_map(|(key, value)| {
+                        let value = match value {
+                            Value::String(s) => s,
+                            Value::Null => return None,
+                            Value::Array(items) => items
+                                .iter()
+                                .filter_map(|v| v.as_str())
+                                .collect::<Vec<_>>()
+                                .join(self.config.columns.alias_separator.as_str()),
+                            other => other.to_string(),
+                        };
+                        Some((key, value))
+                    })
+                    .collect())
+            })
+            .collect()
+    }
+
+    fn read_error(&self, e: impl std::fmt::Display) -> Error {
+        Error::DataSource(format!(
+            "Failed to read {} ({}): {}",
+            self.config.name,
+            self.config.path.display(),
+            e
+        ))
+    }
+}
+
+fn parse_timestamp(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|dt| dt.and_utc())
+        })
+}
+
+#[async_trait]
+impl DataCollector for FileCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let collected_data = self.read_signals()?
+            .into_iter()
+            .map(|signal| CollectedData {
+                source: self.config.name.clone(),
+                data_type: "signal".to_string(),
+                raw_data: serde_json::to_value(&signal).unwrap_or_default(),
+                collected_at: Utc::now(),
+            })
+            .collect();
+
+        Ok(collected_data)
+    }
+
+    fn source_name(&self) -> &str {
+        &self.config.name
+    }
+}
+
+/// Match custom signals to integrations by name and aliases and record
+/// per-source totals on each matched integration
+pub fn apply_custom_signals(
+    integrations: &mut [NormalizedIntegration],
+    source: &str,
+    weight: f64,
+    signals: &[CustomSignal],
+) {
+    let engine = MatchingEngine::from_integrations(integrations);
+
+    for signal in signals {
+        let mut matched: Vec<usize> = std::iter::once(&signal.name)
+            .chain(signal.aliases.iter())
+            .flat_map(|text| engine.match_text(text))
+            .map(|m| m.index)
+            .collect();
+        matched.sort_unstable();
+        matched.dedup();
+
+        for index in matched {
+            let total = integrations[index]
+                .custom_signals
+                .entry(source.to_string())
+                .or_insert_with(|| CustomSignalTotal {
+                    weight,
+                    ..Default::default()
+                });
+            total.value += signal.value;
+            total.records += 1;
+            total.last_seen = total.last_seen.max(signal.timestamp);
+        }
+    }
+}
+
+/// Weighted custom demand across all custom sources
+pub fn custom_demand(integration: &NormalizedIntegration) -> f64 {
+    integration.custom_signals.values().map(|t| t.weighted()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_custom_signals() {
+        let mut integrations = vec![
+            NormalizedIntegration {
+                name: "Bosch Home Connect".to_string(),
+                domain: Some("home_connect".to_string()),
+                ..Default::default()
+            },
+            NormalizedIntegration {
+                name: "Miele".to_string(),
+                domain: Some("miele".to_string()),
+                ..Default::default()
+            },
+        ];
+        let signals = vec![
+            CustomSignal {
+                name: "Dishwasher".to_string(),
+                aliases: vec!["home connect".to_string()],
+                value: 3.0,
+                timestamp: parse_timestamp("2025-02-01"),
+            },
+            CustomSignal {
+                name: "Miele oven".to_string(),
+                aliases: Vec::new(),
+                value: 5.0,
+                timestamp: None,
+            },
+            CustomSignal {
+                name: "Unknown vendor".to_string(),
+                aliases: Vec::new(),
+                value: 100.0,
+                timestamp: None,
+            },
+        ];
+
+        apply_custom_signals(&mut integrations, "support_tickets", 2.0, &signals);
+
+        let bosch = &integrations[0].custom_signals["support_tickets"];
+        assert_eq!(bosch.records, 1);
+        assert_eq!(bosch.weighted(), 6.0);
+        assert!(bosch.last_seen.is_some());
+        assert_eq!(custom_demand(&integrations[1]), 10.0);
+    }
+}
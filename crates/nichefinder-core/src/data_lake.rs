@@ -0,0 +1,454 @@
+//! Partitioned raw data lake for collected data
+//!
+//! Each collection run is written to disk so analyses can be re-run against
+//! past inputs:
+//!
+//! ```text
+//! <root>/source=<source>/date=<YYYY-MM-DD>/<run_id>.jsonl   one record per line
+//! <root>/manifests/<run_id>.json                            run manifest
+//! ```
+//!
+//! Manifests list every partition with its record count and SHA-256 content
+//! hash, which is verified when the partition is read back.
+
+use crate::{
+    collectors::CollectedData,
+    orchestrator::CollectionRun,
+    types::SourceStatus,
+    Error, Result,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_DIR: &str = "manifests";
+
+/// Manifest of a single stored collection run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunManifest {
+    /// Run identifier; sorts chronologically
+    pub run_id: String,
+
+    /// When the run was stored
+    pub collected_at: DateTime<Utc>,
+
+    /// One partition per source with records
+    pub partitions: Vec<PartitionEntry>,
+
+    /// Per-source collection status, when known
+    #[serde(default)]
+    pub statuses: Vec<SourceStatus>,
+}
+
+/// A stored source partition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionEntry {
+    pub source: String,
+    pub date: NaiveDate,
+
+    /// Path relative to the lake root
+    pub path: String,
+
+    pub records: usize,
+
+    /// Hex SHA-256 of the partition file
+    pub sha256: String,
+}
+
+/// Which stored runs an analysis reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LakeQuery {
+    /// Most recent partition of each source
+    Latest,
+
+    /// Most recent partition of each source collected on a date
+    Date(NaiveDate),
+
+    /// Most recent partition of each source collected on or before a date
+    AsOf(NaiveDate),
+
+    /// Every partition collected between two dates (inclusive)
+    Range { from: NaiveDate, to: NaiveDate },
+}
+
+impl std::str::FromStr for LakeQuery {
+    type Err = Error;
+
+    /// Parse "latest", "YYYY-MM-DD", "..YYYY-MM-DD" (as of) or "YYYY-MM-DD..YYYY-MM-DD"
+    fn from_str(s: &str) -> Result<Self> {
+        let date = |d: &str| {
+            NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d")
+                .map_err(|e| Error::Config(format!("Invalid date '{}': {}", d, e)))
+        };
+
+        if let Some(day) = s.trim().strip_prefix("..") {
+            return Ok(LakeQuery::AsOf(date(day)?));
+        }
+        match s.trim() {
+            "latest" => Ok(LakeQuery::Latest),
+            range if range.contains("..") => {
+                let (from, to) = range.split_once("..").unwrap_or_default();
+                Ok(LakeQuery::Range { from: date(from)?, to: date(to)? })
+            }
+            day => Ok(LakeQuery::Date(date(day)?)),
+        }
+    }
+}
+
+/// Retention settings for stored runs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    /// Delete runs older than this many days
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+
+    /// Keep at most this many runs
+    #[serde(default)]
+    pub max_runs: Option<usize>,
+}
+
+/// Raw data store rooted at a directory
+#[derive(Debug, Clone)]
+pub struct DataLake {
+    root: PathBuf,
+}
+
+fn io_error(what: &str, path: &Path, e: std::io::Error) -> Error {
+    Error::DataSource(format!("Failed to {} {}: {}", what, path.display(), e))
+}
+
+/// Whether a source name is safe as a partition directory name
+fn is_partition_name(source: &str) -> bool {
+    !source.is_empty() && source.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+impl DataLake {
+    /// Open (or lazily create) a lake at the given directory
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Lake root directory
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Store a collection run, partitioned by source and date
+    ///
+    /// Source names become directory names, so a run with a source name
+    /// outside `[A-Za-z0-9_-]` (declarative and custom sources are named by
+    /// users) is rejected before anything is written.
+    pub fn store(&self, run: &CollectionRun) -> Result<RunManifest> {
+        if let Some(record) = run.data.iter().find(|d| !is_partition_name(&d.source)) {
+            return Err(Error::DataSource(format!(
+                "Cannot store source '{}' in the data lake: names may only contain letters, digits, '_' and '-'",
+                record.source
+            )));
+        }
+
+        let collected_at = Utc::now();
+        let run_id = format!(
+            "{}-{}",
+            collected_at.format("%Y%m%dT%H%M%S%6fZ"),
+            &uuid::Uuid::new_v4().simple().to_string()[..8]
+        );
+        let date = collected_at.date_naive();
+
+        let mut by_source: BTreeMap<&str, Vec<&CollectedData>> = BTreeMap::new();
+        for record in &run.data {
+            by_source.entry(record.source.as_str()).or_default().push(record);
+        }
+
+        let mut partitions = Vec::new();
+        for (source, records) in by_source {
+            let relative = format!("source={}/date={}/{}.jsonl", source, date, run_id);
+            let path = self.root.join(&relative);
+            let dir = path.parent().unwrap_or(&self.root);
+            std::fs::create_dir_all(dir).map_err(|e| io_error("create", dir, e))?;
+
+            let mut content = Vec::new();
+            for record in &records {
+                serde_json::to_writer(&mut content, record)?;
+                content.push(b'\n');
+            }
+            std::fs::write(&path, &content).map_err(|e| io_error("write", &path, e))?;
+
+            partitions.push(PartitionEntry {
+                source: source.to_string(),
+                date,
+                path: relative,
+                records: records.len(),
+                sha256: sha256_hex(&content),
+            });
+        }
+
+        let manifest = RunManifest {
+            run_id,
+            collected_at,
+            partitions,
+            statuses: run.statuses.clone(),
+        };
+        self.write_manifest(&manifest)?;
+
+        Ok(manifest)
+    }
+
+    fn manifest_path(&self, run_id: &str) -> PathBuf {
+        self.root.join(MANIFEST_DIR).join(format!("{}.json", run_id))
+    }
+
+    fn write_manifest(&self, manifest: &RunManifest) -> Result<()> {
+        let path = self.manifest_path(&manifest.run_id);
+        let dir = path.parent().unwrap_or(&self.root);
+        std::fs::create_dir_all(dir).map_err(|e| io_error("create", dir, e))?;
+        std::fs::write(&path, serde_json::to_vec_pretty(manifest)?).map_err(|e| io_error("write", &path, e))
+    }
+
+    /// All run manifests, oldest first
+    pub fn manifests(&self) -> Result<Vec<RunManifest>> {
+        let dir = self.root.join(MANIFEST_DIR);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut manifests = Vec::new();
+        for entry in std::fs::read_dir(&dir).map_err(|e| io_error("read", &dir, e))? {
+            let path = entry.map_err(|e| io_error("read", &dir, e))?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                let content = std::fs::read(&path).map_err(|e| io_error("read", &path, e))?;
+                manifests.push(serde_json::from_slice::<RunManifest>(&content)?);
+            }
+        }
+        manifests.sort_by(|a, b| a.run_id.cmp(&b.run_id));
+
+        Ok(manifests)
+    }
+
+    /// Read a partition, verifying its content hash
+    pub fn read_partition(&self, partition: &PartitionEntry) -> Result<Vec<CollectedData>> {
+        let path = self.root.join(&partition.path);
+        let content = std::fs::read(&path).map_err(|e| io_error("read", &path, e))?;
+
+        if sha256_hex(&content) != partition.sha256 {
+            return Err(Error::DataSource(format!(
+                "Content hash mismatch for {}; the partition was modified after it was stored",
+                path.display()
+            )));
+        }
+
+        content
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).map_err(Error::from))
+            .collect()
+    }
+
+    /// Load stored records matching a query as a collection run
+    ///
+    /// `Latest`, `Date` and `AsOf` take the most recent partition of each source, so
+    /// a source that failed in the newest run falls back to an earlier one.
+    /// `Range` returns every partition in the range; `write_inputs` keeps the
+    /// newest copy of each record.
+    pub fn load(&self, query: LakeQuery) -> Result<CollectionRun> {
+        let manifests = self.manifests()?;
+
+        let in_query = |date: NaiveDate| match query {
+            LakeQuery::Latest => true,
+            LakeQuery::Date(day) => date == day,
+            LakeQuery::AsOf(day) => date <= day,
+            LakeQuery::Range { from, to } => date >= from && date <= to,
+        };
+
+        // (manifest, partition) pairs, newest run first
+        let mut selected: Vec<(&RunManifest, &PartitionEntry)> = Vec::new();
+        for manifest in manifests.iter().rev() {
+            for partition in manifest.partitions.iter().filter(|p| in_query(p.date)) {
+                let seen = selected.iter().any(|(_, p)| p.source == partition.source);
+                if matches!(query, LakeQuery::Range { .. }) || !seen {
+                    selected.push((manifest, partition));
+                }
+            }
+        }
+
+        if selected.is_empty() {
+            return Err(Error::DataSource(format!(
+                "No stored data in {} matches {:?}",
+                self.root.display(),
+                query
+            )));
+        }
+
+        let mut run = CollectionRun::default();
+        for (manifest, partition) in selected.into_iter().rev() {
+            run.data.extend(self.read_partition(partition)?);
+            run.artifacts.insert(partition.source.clone(), partition.path.clone());
+            let status = manifest.statuses.iter().find(|s| s.source.eq_ignore_ascii_case(&partition.source));
+            let listed = run.statuses.iter().any(|s| s.source.eq_ignore_ascii_case(&partition.source));
+            if let (Some(status), false) = (status, listed) {
+                run.statuses.push(status.clone());
+            }
+        }
+
+        Ok(run)
+    }
+
+    /// Delete runs outside the retention policy; returns the removed run ids
+    ///
+    /// The most recent run is always kept.
+    pub fn apply_retention(&self, policy: &RetentionPolicy) -> Result<Vec<String>> {
+        let manifests = self.manifests()?;
+        let cutoff = policy.max_age_days.map(|days| Utc::now() - Duration::days(days as i64));
+        let keep_from = policy
+            .max_runs
+            .map(|max| manifests.len().saturating_sub(max.max(1)))
+            .unwrap_or(0);
+
+        let mut removed = Vec::new();
+        for (index, manifest) in manifests.iter().enumerate() {
+            if index + 1 == manifests.len() {
+                break;
+            }
+
+            let too_old = cutoff.is_some_and(|cutoff| manifest.collected_at < cutoff);
+            if !too_old && index >= keep_from {
+                continue;
+            }
+
+            for partition in &manifest.partitions {
+                let path = self.root.join(&partition.path);
+                if path.exists() {
+                    std::fs::remove_file(&path).map_err(|e| io_error("remove", &path, e))?;
+                }
+            }
+            let path = self.manifest_path(&manifest.run_id);
+            std::fs::remove_file(&path).map_err(|e| io_error("remove", &path, e))?;
+            removed.push(manifest.run_id.clone());
+        }
+
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Fixtures;
+    use crate::types::SourceState;
+
+    fn run(source: &str, ids: &[u64]) -> CollectionRun {
+        CollectionRun {
+            data: ids
+                .iter()
+                .map(|id| CollectedData {
+                    source: source.to_string(),
+                    data_type: "item".to_string(),
+                    raw_data: serde_json::json!({ "id": id }),
+                    collected_at: Utc::now(),
+                })
+                .collect(),
+            statuses: vec![SourceStatus {
+                source: source.to_string(),
+                state: SourceState::Ok,
+                records: ids.len(),
+                query: None,
+                error: None,
+                duration_secs: 0.1,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_store_rejects_path_traversal() {
+        let fixtures = Fixtures::new();
+        let root = fixtures.path();
+        let lake = DataLake::new(root.join("lake"));
+
+        for source in ["../../x", "a/b", "", "name with space"] {
+            assert!(lake.store(&run(source, &[1])).is_err(), "{:?} was stored", source);
+        }
+        assert!(!root.join("x").exists());
+        assert!(lake.manifests().unwrap().is_empty());
+
+        assert!(lake.store(&run("internal-survey_2", &[1])).is_ok());
+    }
+
+    #[test]
+    fn test_store_query_and_retention() {
+        let fixtures = Fixtures::new();
+        let root = fixtures.path();
+        let lake = DataLake::new(root);
+
+        let first = lake.store(&run("hacs", &[1, 2])).unwrap();
+        lake.store(&run("github", &[3])).unwrap();
+        let third = lake.store(&run("hacs", &[4, 5, 6])).unwrap();
+        assert_eq!(first.partitions[0].records, 2);
+        assert_eq!(third.partitions[0].sha256.len(), 64);
+
+        // Latest: newest hacs partition plus github from the run before
+        let latest = lake.load(LakeQuery::Latest).unwrap();
+        assert_eq!(latest.records("hacs").count(), 3);
+        assert_eq!(latest.records("github").count(), 1);
+        assert_eq!(latest.statuses.len(), 2);
+
+        let today = Utc::now().date_naive();
+        let range = lake.load(LakeQuery::Range { from: today, to: today }).unwrap();
+        assert_eq!(range.data.len(), 6);
+        assert!(lake.load(LakeQuery::Date(today - Duration::days(30))).is_err());
+        assert!(lake.load(LakeQuery::AsOf(today - Duration::days(30))).is_err());
+        assert_eq!(lake.load(LakeQuery::AsOf(today + Duration::days(1))).unwrap().data.len(), latest.data.len());
+        assert_eq!("..2025-01-31".parse::<LakeQuery>().unwrap(), LakeQuery::AsOf(NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()));
+        assert_eq!("latest".parse::<LakeQuery>().unwrap(), LakeQuery::Latest);
+        assert!(matches!("2025-01-01..2025-01-31".parse::<LakeQuery>().unwrap(), LakeQuery::Range { .. }));
+
+        // Tampered partitions are rejected
+        std::fs::write(root.join(&first.partitions[0].path), b"{}\n").unwrap();
+        assert!(lake.read_partition(&first.partitions[0]).is_err());
+
+        let removed = lake.apply_retention(&RetentionPolicy { max_age_days: None, max_runs: Some(2) }).unwrap();
+        assert_eq!(removed, vec![first.run_id]);
+        assert_eq!(lake.manifests().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_range_inputs_keep_latest_record() {
+        let fixtures = Fixtures::new();
+        let lake = DataLake::new(fixtures.path().join("lake"));
+
+        let record = |source: &str, raw: serde_json::Value| CollectedData {
+            source: source.to_string(),
+            data_type: "item".to_string(),
+            raw_data: raw,
+            collected_at: Utc::now(),
+        };
+        let snapshot = |stars: u64, repos: &[u64]| {
+            let mut collection = run("hacs", &[]);
+            collection.data.push(record("hacs", serde_json::json!({ "id": "1", "full_name": "user/tado" })));
+            collection.data.extend(repos.iter().map(|id| {
+                record("github", serde_json::json!({ "id": id, "stargazers_count": stars }))
+            }));
+            collection
+        };
+        lake.store(&snapshot(10, &[1, 2])).unwrap();
+        lake.store(&snapshot(20, &[1, 2, 3])).unwrap();
+
+        let today = Utc::now().date_naive();
+        let range = lake.load(LakeQuery::Range { from: today, to: today }).unwrap();
+        assert_eq!(range.records("github").count(), 5);
+
+        // Two runs of the same repositories are written once, with the newest values
+        let inputs = range.write_inputs(fixtures.path()).unwrap();
+        let github: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&inputs.github_path).unwrap()).unwrap();
+        let items = github["items"].as_array().unwrap();
+        assert_eq!(items.len(), 3);
+        assert!(items.iter().all(|item| item["stargazers_count"] == 20));
+        assert_eq!(inputs.provenance["github"].records, 3);
+        assert_eq!(inputs.provenance["hacs"].records, 1);
+    }
+}
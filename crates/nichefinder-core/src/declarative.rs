@@ -0,0 +1,490 @@
+//! Declarative REST sources
+//!
+//! A source definition (YAML or TOML) describes a REST API — base URL, auth,
+//! endpoints, pagination and JSONPath mappings into `CollectedData` — and
+//! `DeclarativeCollector` executes it, so new sources don't need a
+//! hand-written collector.
+//!
+//! ```yaml
+//! name: lemmy_homeassistant
+//! base_url: https://lemmy.world/api/v3
+//! auth:
+//!   type: bearer
+//!   token_env: LEMMY_TOKEN
+//! endpoints:
+//!   - path: /post/list
+//!     data_type: post
+//!     params: { community_name: homeassistant, sort: New }
+//!     pagination: { style: page, param: page, max_pages: 5 }
+//!     records: $.posts[*]
+//!     mapping:
+//!       id: $.post.id
+//!       observed_at: $.post.published
+//!       fields:
+//!         title: $.post.name
+//!         score: $.counts.score
+//! ```
+
+use crate::{
+    collectors::{CollectedData, DataCollector, PartialCollection},
+    Error, Result,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_json_path::JsonPath;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use udm_connectors::plugins::{ApiConnector, RestApiConnector, rest_api::RestApiConfig};
+
+fn default_timeout() -> u64 {
+    30
+}
+
+fn default_max_pages() -> u32 {
+    1
+}
+
+fn default_start_page() -> u64 {
+    1
+}
+
+/// Declarative REST source definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceDefinition {
+    /// Source name, used as `CollectedData.source`
+    pub name: String,
+
+    /// API base URL
+    pub base_url: String,
+
+    /// Request timeout
+    #[serde(default = "default_timeout")]
+    pub timeout_seconds: u64,
+
+    /// Headers sent with every request
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    /// Authentication scheme
+    #[serde(default)]
+    pub auth: AuthScheme,
+
+    /// Endpoints to collect from
+    pub endpoints: Vec<EndpointDefinition>,
+}
+
+/// Authentication scheme; secrets are read from environment variables so
+/// definitions can be committed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthScheme {
+    #[default]
+    None,
+
+    /// `Authorization: Bearer <token>`
+    Bearer { token_env: String },
+
+    /// `Authorization: Basic <base64(user:password)>`
+    Basic { username_env: String, password_env: String },
+
+    /// Arbitrary header, e.g. `X-Api-Key`
+    Header { name: String, value_env: String },
+
+    /// Query parameter, e.g. `?key=...`
+    Query { param: String, value_env: String },
+}
+
+/// A single endpoint of a declarative source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointDefinition {
+    /// Path relative to the base URL
+    pub path: String,
+
+    /// Value for `CollectedData.data_type`
+    pub data_type: String,
+
+    /// Static query parameters
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+
+    /// Pagination style
+    #[serde(default)]
+    pub pagination: Pagination,
+
+    /// JSONPath selecting the records in a response (e.g. `$.items[*]`)
+    pub records: String,
+
+    /// Mapping from a record into `CollectedData`
+    #[serde(default)]
+    pub mapping: FieldMapping,
+}
+
+/// Pagination style of an endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "style", rename_all = "snake_case")]
+pub enum Pagination {
+    /// Single request
+    #[default]
+    None,
+
+    /// Page number parameter (`?page=1`, `?page=2`, ...)
+    Page {
+        param: String,
+        #[serde(default = "default_start_page")]
+        start: u64,
+        #[serde(default = "default_max_pages")]
+        max_pages: u32,
+    },
+
+    /// Offset/limit parameters (`?offset=0&limit=50`, ...)
+    Offset {
+        param: String,
+        limit_param: String,
+        limit: u64,
+        #[serde(default = "default_max_pages")]
+        max_pages: u32,
+    },
+
+    /// Cursor taken from the previous response (`?after=<next>`)
+    Cursor {
+        param: String,
+        /// JSONPath to the next cursor in a response
+        next: String,
+        #[serde(default = "default_max_pages")]
+        max_pages: u32,
+    },
+}
+
+/// JSONPath mapping from a record into `CollectedData`
+///
+/// Paths are evaluated against the record. With no `fields`, the whole
+/// record becomes `raw_data`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FieldMapping {
+    /// Record identifier, stored as `raw_data.id` and used to skip duplicates
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// Record timestamp (RFC 3339 string or unix seconds), stored as
+    /// `raw_data.observed_at`; `collected_at` is always the fetch time
+    #[serde(default, alias = "collected_at")]
+    pub observed_at: Option<String>,
+
+    /// Output field name to JSONPath
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+}
+
+impl SourceDefinition {
+    /// Load a definition from a `.yaml`/`.yml` or `.toml` file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read source definition {}: {}", path.display(), e)))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&content),
+            _ => Err(Error::Config(format!(
+                "Unsupported source definition format: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Parse a YAML definition
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|e| Error::Config(format!("Invalid source definition: {}", e)))
+    }
+
+    /// Parse a TOML definition
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        toml::from_str(content)
+            .map_err(|e| Error::Config(format!("Invalid source definition: {}", e)))
+    }
+}
+
+/// Endpoint with its JSONPaths parsed up front
+#[derive(Debug)]
+struct CompiledEndpoint {
+    definition: EndpointDefinition,
+    records: JsonPath,
+    id: Option<JsonPath>,
+    observed_at: Option<JsonPath>,
+    fields: Vec<(String, JsonPath)>,
+    next_cursor: Option<JsonPath>,
+}
+
+fn compile_path(path: &str) -> Result<JsonPath> {
+    JsonPath::parse(path)
+        .map_err(|e| Error::Config(format!("Invalid JSONPath '{}': {}", path, e)))
+}
+
+impl CompiledEndpoint {
+    fn new(definition: EndpointDefinition) -> Result<Self> {
+        let next_cursor = match &definition.pagination {
+            Pagination::Cursor { next, .. } => Some(compile_path(next)?),
+            _ => None,
+        };
+
+        Ok(Self {
+            records: compile_path(&definition.records)?,
+            id: definition.mapping.id.as_deref().map(compile_path).transpose()?,
+            observed_at: definition.mapping.observed_at.as_deref().map(compile_path).transpose()?,
+            fields: definition.mapping.fields
+                .iter()
+                .map(|(name, path)| Ok((name.clone(), compile_path(path)?)))
+                .collect::<Result<Vec<_>>>()?,
+            next_cursor,
+            definition,
+        })
+    }
+
+    fn max_pages(&self) -> u32 {
+        match &self.definition.pagination {
+            Pagination::None => 1,
+            Pagination::Page { max_pages, .. }
+            | Pagination::Offset { max_pages, .. }
+            | Pagination::Cursor { max_pages, .. } => *max_pages,
+        }
+    }
+
+    /// Query parameters for a page; `None` when there is no next page
+    fn page_params(&self, page: u32, cursor: Option<&str>) -> Option<HashMap<String, String>> {
+        let mut params = self.definition.params.clone();
+
+        match &self.definition.pagination {
+            Pagination::None => {}
+            Pagination::Page { param, start, .. } => {
+                params.insert(param.clone(), (start + page as u64).to_string());
+            }
+            Pagination::Offset { param, limit_param, limit, .. } => {
+                params.insert(param.clone(), (page as u64 * limit).to_string());
+                params.insert(limit_param.clone(), limit.to_string());
+            }
+            Pagination::Cursor { param, .. } => match (page, cursor) {
+                (0, _) => {}
+                (_, Some(cursor)) => {
+                    params.insert(param.clone(), cursor.to_string());
+                }
+                (_, None) => return None,
+            },
+        }
+
+        Some(params)
+    }
+}
+
+/// First value a JSONPath selects, if any
+fn first_value(path: &JsonPath, value: &Value) -> Option<Value> {
+    path.query(value).first().cloned()
+}
+
+fn parse_timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::String(s) => DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc)),
+        Value::Number(n) => n.as_i64().and_then(|secs| Utc.timestamp_opt(secs, 0).single()),
+        _ => None,
+    }
+}
+
+/// Generic collector executing a `SourceDefinition`
+pub struct DeclarativeCollector {
+    name: String,
+    connector: RestApiConnector,
+    auth_param: Option<(String, String)>,
+    endpoints: Vec<CompiledEndpoint>,
+}
+
+impl DeclarativeCollector {
+    /// Create a collector from a definition
+    /// Fails if a JSONPath is invalid or an auth environment variable is unset.
+    pub async fn new(definition: SourceDefinition) -> Result<Self> {
+        let mut config = RestApiConfig::default();
+        config.base_url = definition.base_url.clone();
+        config.timeout_seconds = definition.timeout_seconds;
+        config.default_headers.extend(definition.headers.clone());
+
+        let mut auth_param = None;
+        match &definition.auth {
+            AuthScheme::None => {}
+            AuthScheme::Bearer { token_env } => {
+                config.default_headers.insert(
+                    "Authorization".to_string(),
+                    format!("Bearer {}", Self::env(token_env)?),
+                );
+            }
+            AuthScheme::Basic { username_env, password_env } => {
+                use base64::Engine;
+                let credentials = format!("{}:{}", Self::env(username_env)?, Self::env(password_env)?);
+                config.default_headers.insert(
+                    "Authorization".to_string(),
+                    format!("Basic {}", base64::engine::general_purpose::STANDARD.encode(credentials)),
+                );
+            }
+            AuthScheme::Header { name, value_env } => {
+                config.default_headers.insert(name.clone(), Self::env(value_env)?);
+            }
+            AuthScheme::Query { param, value_env } => {
+                auth_param = Some((param.clone(), Self::env(value_env)?));
+            }
+        }
+
+        let endpoints = definition.endpoints
+            .into_iter()
+            .map(CompiledEndpoint::new)
+            .collect::<Result<Vec<_>>>()?;
+
+        let connector = RestApiConnector::with_config(config)
+            .await
+            .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+        Ok(Self {
+            name: definition.name,
+            connector,
+            auth_param,
+            endpoints,
+        })
+    }
+
+    /// Create a collector from a definition file
+    pub async fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(SourceDefinition::from_file(path)?).await
+    }
+
+    fn env(name: &str) -> Result<String> {
+        std::env::var(name)
+            .map_err(|_| Error::Config(format!("Environment variable {} is not set", name)))
+    }
+
+    /// Map one response of the given endpoint into collected records
+    pub fn map_response(&self, endpoint: usize, response: &Value) -> Result<Vec<CollectedData>> {
+        let endpoint = self.endpoints
+            .get(endpoint)
+            .ok_or_else(|| Error::Config(format!("No endpoint {} in source {}", endpoint, self.name)))?;
+        Ok(self.map_records(endpoint, response))
+    }
+
+    fn map_records(&self, endpoint: &CompiledEndpoint, response: &Value) -> Vec<CollectedData> {
+        endpoint.records
+            .query(response)
+            .all()
+            .into_iter()
+            .map(|record| {
+                let mut raw_data = if endpoint.fields.is_empty() {
+                    record.clone()
+                } else {
+                    let fields = endpoint.fields
+                        .iter()
+                        .map(|(name, path)| (name.clone(), first_value(path, record).unwrap_or(Value::Null)))
+                        .collect::<serde_json::Map<_, _>>();
+                    Value::Object(fields)
+                };
+
+                if let (Some(path), Value::Object(map)) = (&endpoint.id, &mut raw_data) {
+                    map.insert("id".to_string(), first_value(path, record).unwrap_or(Value::Null));
+                }
+
+                let observed_at = endpoint.observed_at
+                    .as_ref()
+                    .and_then(|path| first_value(path, record))
+                    .and_then(|value| parse_timestamp(&value));
+                if let (Some(observed_at), Value::Object(map)) = (observed_at, &mut raw_data) {
+                    map.insert("observed_at".to_string(), Value::String(observed_at.to_rfc3339()));
+                }
+
+                CollectedData {
+                    source: self.name.clone(),
+                    data_type: endpoint.definition.data_type.clone(),
+                    raw_data,
+                    collected_at: Utc::now(),
+                }
+            })
+            .collect()
+    }
+
+    async fn collect_endpoint(&self, endpoint: &CompiledEndpoint) -> Result<Vec<CollectedData>> {
+        let mut collected_data = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        for page in 0..endpoint.max_pages() {
+            let Some(mut params) = endpoint.page_params(page, cursor.as_deref()) else {
+                break;
+            };
+            if let Some((param, value)) = &self.auth_param {
+                params.insert(param.clone(), value.clone());
+            }
+
+            let response = self.connector.get(&endpoint.definition.path, Some(params))
+                .await
+                .map_err(|e| Error::Udm(udm_core::UdmError::Generic(e.to_string())))?;
+
+            let records = self.map_records(endpoint, &response);
+            if records.is_empty() {
+                break;
+            }
+            collected_data.extend(records);
+
+            cursor = endpoint.next_cursor
+                .as_ref()
+                .and_then(|path| first_value(path, &response))
+                .and_then(|value| match value {
+                    Value::String(s) => Some(s),
+                    Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                });
+        }
+
+        Ok(collected_data)
+    }
+}
+
+#[async_trait]
+impl DataCollector for DeclarativeCollector {
+    async fn collect(&self) -> Result<Vec<CollectedData>> {
+        let partial = self.collect_partial().await;
+        match partial.error {
+            Some(e) => Err(e),
+            None => Ok(partial.data),
+        }
+    }
+
+    /// Endpoints are collected independently; a failing endpoint keeps the
+    /// records of the others
+    async fn collect_partial(&self) -> PartialCollection {
+        let mut seen = std::collections::HashSet::new();
+        let mut collected_data = Vec::new();
+        let mut error = None;
+
+        for endpoint in &self.endpoints {
+            let records = match self.collect_endpoint(endpoint).await {
+                Ok(records) => records,
+                Err(e) => {
+                    error.get_or_insert(e);
+                    continue;
+                }
+            };
+
+            for record in records {
+                // Overlapping pages can repeat records
+                if let Some(id) = record.raw_data.get("id").filter(|id| !id.is_null()) {
+                    if !seen.insert((record.data_type.clone(), id.to_string())) {
+                        continue;
+                    }
+                }
+                collected_data.push(record);
+            }
+        }
+
+        PartialCollection { data: collected_data, error }
+    }
+
+    fn source_name(&self) -> &str {
+        &self.name
+    }
+}
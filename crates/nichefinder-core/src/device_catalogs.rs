@@ -0,0 +1,379 @@
+//! Protocol device catalogs (zigbee2mqtt, Z-Wave JS, Matter) and detection of
+//! vendors that Home Assistant only reaches through generic protocol integrations
+
+use crate::collectors::CoreIntegrationManifest;
+use crate::matching::{normalize_text, MatchingEngine};
+use crate::transform::NormalizedIntegration;
+use crate::types::OpportunityKind;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Vendors need at least this many catalogued devices to become a candidate
+pub const MIN_PROTOCOL_DEVICES: usize = 5;
+
+/// Trailing words dropped from vendor names before grouping
+const LEGAL_SUFFIXES: &[&str] = &[
+    "inc", "ltd", "llc", "gmbh", "co", "corp", "corporation", "company", "limited",
+    "bv", "ag", "sa", "sas", "srl", "oy", "ab", "as", "plc", "pty", "kg",
+];
+
+/// Catalog vendor entries that don't name a real vendor
+const GENERIC_VENDORS: &[&str] = &["custom devices diy", "diy", "generic", "unknown", "other"];
+
+/// Smart home protocol a catalogued device speaks
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceProtocol {
+    Zigbee,
+    ZWave,
+    Matter,
+}
+
+impl DeviceProtocol {
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeviceProtocol::Zigbee => "zigbee",
+            DeviceProtocol::ZWave => "z_wave",
+            DeviceProtocol::Matter => "matter",
+        }
+    }
+
+    /// Key of the catalog in `AnalysisConfig::enabled_sources`
+    pub fn source_key(&self) -> &'static str {
+        match self {
+            DeviceProtocol::Zigbee => "zigbee2mqtt",
+            DeviceProtocol::ZWave => "zwave_js",
+            DeviceProtocol::Matter => "matter",
+        }
+    }
+
+    /// Name of the catalog the devices came from
+    pub fn catalog_name(&self) -> &'static str {
+        match self {
+            DeviceProtocol::Zigbee => "zigbee2mqtt",
+            DeviceProtocol::ZWave => "Z-Wave JS",
+            DeviceProtocol::Matter => "Matter",
+        }
+    }
+}
+
+/// A single device from one of the protocol catalogs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogDevice {
+    pub protocol: DeviceProtocol,
+    pub vendor: String,
+    pub model: String,
+    pub description: Option<String>,
+}
+
+/// Entry in the zigbee2mqtt supported-devices export
+#[derive(Debug, Deserialize)]
+struct Zigbee2MqttDevice {
+    vendor: String,
+    model: String,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+/// Z-Wave JS device configuration file (`packages/config/config/devices/**.json`)
+#[derive(Debug, Deserialize)]
+struct ZWaveJsDeviceConfig {
+    manufacturer: String,
+    label: String,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+/// Entry in a Matter certified-product export
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MatterProduct {
+    #[serde(alias = "vendor", alias = "companyName")]
+    vendor_name: String,
+    #[serde(alias = "productLabel", alias = "model")]
+    product_name: String,
+    #[serde(default)]
+    device_type: Option<String>,
+}
+
+/// Load the zigbee2mqtt supported-devices list from JSON file
+pub fn load_zigbee2mqtt_devices(path: &str) -> Result<Vec<CatalogDevice>> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read zigbee2mqtt devices file")?;
+
+    let devices: Vec<Zigbee2MqttDevice> = serde_json::from_str(&content)
+        .context("Failed to parse zigbee2mqtt devices JSON")?;
+
+    Ok(devices
+        .into_iter()
+        .map(|d| CatalogDevice {
+            protocol: DeviceProtocol::Zigbee,
+            vendor: d.vendor,
+            model: d.model,
+            description: d.description,
+        })
+        .collect())
+}
+
+/// Load Z-Wave JS device configs
+///
+/// Accepts either a checkout of the device config directory (walked
+/// recursively, `//` comments allowed, `templates` directories skipped) or a
+/// JSON array of configs. Configs that don't parse are skipped with a warning.
+pub fn load_zwave_js_devices(path: &str) -> Result<Vec<CatalogDevice>> {
+    let path = Path::new(path);
+    let configs: Vec<ZWaveJsDeviceConfig> = if path.is_dir() {
+        let mut files = Vec::new();
+        collect_json_files(path, &mut files)?;
+        files.sort();
+
+        let mut configs = Vec::new();
+        let mut skipped = 0;
+        for file in files {
+            let content = std::fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read Z-Wave JS config {}", file.display()))?;
+            match serde_json::from_str(&strip_line_comments(&content)) {
+                Ok(config) => configs.push(config),
+                Err(e) => {
+                    tracing::warn!("Skipping Z-Wave JS config {}: {}", file.display(), e);
+                    skipped += 1;
+                }
+            }
+        }
+        if skipped > 0 {
+            tracing::warn!("Skipped {} of {} Z-Wave JS configs that did not parse", skipped, skipped + configs.len());
+        }
+        configs
+    } else {
+        let content = std::fs::read_to_string(path)
+            .context("Failed to read Z-Wave JS devices file")?;
+        serde_json::from_str(&strip_line_comments(&content))
+            .context("Failed to parse Z-Wave JS devices JSON")?
+    };
+
+    Ok(configs
+        .into_iter()
+        .map(|c| CatalogDevice {
+            protocol: DeviceProtocol::ZWave,
+            vendor: c.manufacturer,
+            model: c.label,
+            description: c.description,
+        })
+        .collect())
+}
+
+/// Load a Matter certified-product list from JSON file
+pub fn load_matter_products(path: &str) -> Result<Vec<CatalogDevice>> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read Matter products file")?;
+
+    let products: Vec<MatterProduct> = serde_json::from_str(&content)
+        .context("Failed to parse Matter products JSON")?;
+
+    Ok(products
+        .into_iter()
+        .map(|p| CatalogDevice {
+            protocol: DeviceProtocol::Matter,
+            vendor: p.vendor_name,
+            model: p.product_name,
+            description: p.device_type,
+        })
+        .collect())
+}
+
+fn collect_json_files(dir: &Path, files: &mut Vec<std::path::PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir).context("Failed to read Z-Wave JS config directory")? {
+        let path = entry?.path();
+        // Templates are partial configs imported by device files, not devices
+        if path.is_dir() && path.file_name().is_some_and(|name| name != "templates") {
+            collect_json_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Drop whole-line `//` comments, as used in Z-Wave JS config files
+fn strip_line_comments(content: &str) -> String {
+    content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Vendor name without legal suffixes, e.g. "Aeotec Ltd." -> "Aeotec"
+pub fn vendor_display_name(vendor: &str) -> String {
+    let cleaned = vendor.replace('.', "");
+    let mut words: Vec<&str> = cleaned
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    while words.len() > 1 && LEGAL_SUFFIXES.contains(&words[words.len() - 1].to_lowercase().as_str()) {
+        words.pop();
+    }
+
+    words.join(" ")
+}
+
+/// Total catalogued devices for a candidate
+pub fn protocol_device_count(integration: &NormalizedIntegration) -> usize {
+    integration.protocol_devices.values().sum()
+}
+
+/// Build "protocol-only" vendor candidates
+///
+/// Devices are grouped by vendor. A vendor is covered when its name appears
+/// in any HACS or core integration name/domain (or vice versa); uncovered
+/// vendors with at least `min_devices` devices become candidates, with device
+/// counts per protocol as evidence.
+pub fn find_protocol_only_vendors(
+    integrations: &[NormalizedIntegration],
+    core_manifests: &[CoreIntegrationManifest],
+    devices: &[CatalogDevice],
+    min_devices: usize,
+) -> Vec<NormalizedIntegration> {
+    // Group devices by normalized vendor
+    let mut vendors: BTreeMap<String, (String, BTreeMap<DeviceProtocol, usize>)> = BTreeMap::new();
+    for device in devices {
+        let display = vendor_display_name(&device.vendor);
+        let key = normalize_text(&display);
+        if key.is_empty() || GENERIC_VENDORS.contains(&key.as_str()) {
+            continue;
+        }
+        let (_, counts) = vendors.entry(key).or_insert_with(|| (display, BTreeMap::new()));
+        *counts.entry(device.protocol).or_insert(0) += 1;
+    }
+
+    // Everything Home Assistant already has a named integration for
+    let mut ha_terms: Vec<&str> = Vec::new();
+    for integration in integrations {
+        ha_terms.push(&integration.name);
+        ha_terms.extend(integration.domain.as_deref());
+    }
+    for manifest in core_manifests.iter().filter(|m| m.is_device_or_service()) {
+        ha_terms.push(&manifest.name);
+        ha_terms.push(&manifest.domain);
+    }
+
+    let keys: Vec<&String> = vendors.keys().collect();
+    let mut vendor_engine = MatchingEngine::new();
+    let mut ha_engine = MatchingEngine::new();
+    for (index, key) in keys.iter().enumerate() {
+        vendor_engine.add_term(index, key, 1.0);
+    }
+    for (index, term) in ha_terms.iter().enumerate() {
+        ha_engine.add_term(index, term, 1.0);
+    }
+
+    let mut covered = vec![false; keys.len()];
+    for term in &ha_terms {
+        for m in vendor_engine.match_text(term) {
+            covered[m.index] = true;
+        }
+    }
+    for (index, key) in keys.iter().enumerate() {
+        if !ha_engine.match_text(key).is_empty() {
+            covered[index] = true;
+        }
+    }
+
+    vendors
+        .into_iter()
+        .zip(covered)
+        .filter(|(_, covered)| !covered)
+        .map(|((key, (display, counts)), _)| (key, display, counts))
+        .filter(|(_, _, counts)| counts.values().sum::<usize>() >= min_devices)
+        .map(|(key, display, counts)| {
+            let protocols: Vec<&str> = counts.keys().map(|p| p.catalog_name()).collect();
+            NormalizedIntegration {
+                description: Some(format!(
+                    "{} devices reachable only through generic protocol integrations ({})",
+                    counts.values().sum::<usize>(),
+                    protocols.join(", "),
+                )),
+                name: display,
+                // Suggested Home Assistant domain for the vendor integration
+                domain: Some(key.replace(' ', "_")),
+                in_hacs: false,
+                kind: OpportunityKind::ProtocolOnly,
+                protocol_devices: counts,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Fixtures;
+
+    fn device(protocol: DeviceProtocol, vendor: &str, model: &str) -> CatalogDevice {
+        CatalogDevice {
+            protocol,
+            vendor: vendor.to_string(),
+            model: model.to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_find_protocol_only_vendors() {
+        let integrations = vec![NormalizedIntegration {
+            name: "Xiaomi Miio".to_string(),
+            domain: Some("xiaomi_miio".to_string()),
+            in_hacs: true,
+            ..Default::default()
+        }];
+
+        let mut devices = Vec::new();
+        for i in 0..4 {
+            devices.push(device(DeviceProtocol::Zigbee, "Xiaomi", &format!("XM-{}", i)));
+            devices.push(device(DeviceProtocol::Zigbee, "Sonoff", &format!("SNZB-{}", i)));
+        }
+        devices.push(device(DeviceProtocol::ZWave, "Zooz Inc.", "ZEN15"));
+        devices.push(device(DeviceProtocol::Matter, "Sonoff Co., Ltd.", "M5"));
+        devices.push(device(DeviceProtocol::Zigbee, "Custom devices (DiY)", "CC2530"));
+
+        let candidates = find_protocol_only_vendors(&integrations, &[], &devices, 2);
+
+        // Xiaomi is covered by name; Zooz has too few devices
+        assert_eq!(candidates.len(), 1);
+        let sonoff = &candidates[0];
+        assert_eq!(sonoff.name, "Sonoff");
+        assert_eq!(sonoff.kind, OpportunityKind::ProtocolOnly);
+        assert_eq!(sonoff.protocol_devices.get(&DeviceProtocol::Zigbee), Some(&4));
+        assert_eq!(sonoff.protocol_devices.get(&DeviceProtocol::Matter), Some(&1));
+        assert_eq!(protocol_device_count(sonoff), 5);
+    }
+
+    #[test]
+    fn test_vendor_display_name() {
+        assert_eq!(vendor_display_name("Aeotec Ltd."), "Aeotec");
+        assert_eq!(vendor_display_name("Shenzhen Co., Ltd."), "Shenzhen");
+        assert_eq!(vendor_display_name("Signify Netherlands B.V."), "Signify Netherlands");
+        assert_eq!(strip_line_comments("// note\n{\"a\": 1}"), "{\"a\": 1}");
+    }
+
+    #[test]
+    fn test_load_zwave_js_directory() {
+        let fixtures = Fixtures::new();
+        fixtures.write_str(
+            "0x0086/zw100.json",
+            "// Aeotec MultiSensor 6\n{\"manufacturer\": \"AEON Labs\", \"label\": \"ZW100\", \"description\": \"MultiSensor 6\"}",
+        );
+        fixtures.write_str("0x0086/templates/aeotec_template.json", "{\"base_enable\": {}}");
+        fixtures.write_str("0x0086/broken.json", "{\"manufacturer\": ");
+
+        let devices = load_zwave_js_devices(&fixtures.path().to_string_lossy()).unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].vendor, "AEON Labs");
+        assert_eq!(devices[0].model, "ZW100");
+    }
+}
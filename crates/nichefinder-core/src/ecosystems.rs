@@ -0,0 +1,206 @@
+//! Cross-ecosystem gap analysis: devices supported by Homebridge or openHAB
+//! but by neither HACS nor Home Assistant core
+
+use crate::collectors::{CoreIntegrationManifest, HomebridgePlugin, OpenHabAddon};
+use crate::matching::{normalize_text, MatchingEngine};
+use crate::transform::NormalizedIntegration;
+use crate::types::{Evidence, EvidenceKind, OpportunityKind};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A plugin or add-on in another smart home ecosystem
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForeignPort {
+    /// Ecosystem ("homebridge" or "openhab")
+    pub ecosystem: String,
+
+    /// Package or add-on identifier
+    pub name: String,
+
+    /// Link to the package or add-on page
+    pub url: String,
+
+    /// Popularity in its ecosystem (monthly downloads or installs)
+    pub popularity: u64,
+
+    /// Description, if any
+    pub description: Option<String>,
+}
+
+impl ForeignPort {
+    fn from_homebridge(plugin: &HomebridgePlugin) -> Self {
+        Self {
+            ecosystem: "homebridge".to_string(),
+            name: plugin.package.name.clone(),
+            url: plugin.url(),
+            popularity: plugin.monthly_downloads(),
+            description: plugin.package.description.clone(),
+        }
+    }
+
+    fn from_openhab(addon: &OpenHabAddon) -> Self {
+        Self {
+            ecosystem: "openhab".to_string(),
+            name: addon.uid.clone(),
+            url: addon.url(),
+            popularity: addon.popularity(),
+            description: addon.description.clone(),
+        }
+    }
+
+    /// Citable evidence for the port
+    pub fn evidence(&self) -> Evidence {
+        let metric = match self.ecosystem.as_str() {
+            "homebridge" => "monthly_downloads",
+            _ => "installs",
+        };
+        Evidence {
+            source: self.ecosystem.clone(),
+            kind: EvidenceKind::Package,
+            url: self.url.clone(),
+            title: self.name.clone(),
+            timestamp: None,
+            engagement: BTreeMap::from([(metric.to_string(), self.popularity)]),
+            match_quality: 1.0,
+        }
+    }
+}
+
+/// Devices/vendors supported elsewhere, grouped by normalized target name
+#[derive(Debug, Default)]
+struct ForeignTarget {
+    display_name: String,
+    ports: Vec<ForeignPort>,
+}
+
+/// Build "ported elsewhere, missing here" candidates
+///
+/// Every Homebridge device plugin (see `HomebridgePlugin::is_device_plugin`)
+/// and openHAB binding is resolved against HACS integrations and core
+/// manifests by name; the ones with no Home Assistant counterpart become new
+/// candidates, merged across ecosystems by target name.
+pub fn find_ported_elsewhere(
+    integrations: &[NormalizedIntegration],
+    core_manifests: &[CoreIntegrationManifest],
+    homebridge: &[HomebridgePlugin],
+    openhab: &[OpenHabAddon],
+) -> Vec<NormalizedIntegration> {
+    let engine = home_assistant_engine(integrations, core_manifests);
+    let mut targets: BTreeMap<String, ForeignTarget> = BTreeMap::new();
+
+    for plugin in homebridge.iter().filter(|p| p.is_device_plugin()) {
+        let name = plugin.target_name();
+        if engine.match_text(&name).is_empty() {
+            let target = targets.entry(normalize_text(&name)).or_default();
+            if target.display_name.is_empty() {
+                target.display_name = title_case(&name);
+            }
+            target.ports.push(ForeignPort::from_homebridge(plugin));
+        }
+    }
+
+    for addon in openhab {
+        let name = addon.target_name();
+        if engine.match_text(&name).is_empty() {
+            let target = targets.entry(normalize_text(&name)).or_default();
+            // openHAB labels are properly cased, so prefer them
+            target.display_name = name.clone();
+            target.ports.push(ForeignPort::from_openhab(addon));
+        }
+    }
+
+    targets
+        .into_iter()
+        .filter(|(key, _)| !key.is_empty())
+        .map(|(key, target)| NormalizedIntegration {
+            name: target.display_name,
+            // Suggested Home Assistant domain for the new integration
+            domain: Some(key.replace(' ', "_")),
+            description: target.ports.iter().find_map(|p| p.description.clone()),
+            in_hacs: false,
+            kind: OpportunityKind::PortedElsewhere,
+            evidence: target.ports.iter().map(ForeignPort::evidence).collect(),
+            foreign_ports: target.ports,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Total popularity of a candidate's foreign ports
+pub fn foreign_popularity(integration: &NormalizedIntegration) -> u64 {
+    integration.foreign_ports.iter().map(|p| p.popularity).sum()
+}
+
+/// Matching engine over everything Home Assistant already supports
+fn home_assistant_engine(
+    integrations: &[NormalizedIntegration],
+    core_manifests: &[CoreIntegrationManifest],
+) -> MatchingEngine {
+    let mut engine = MatchingEngine::from_integrations(integrations);
+    let offset = integrations.len();
+
+    for (i, manifest) in core_manifests.iter().filter(|m| m.is_device_or_service()).enumerate() {
+        engine.add_term(offset + i, &manifest.name, 1.0);
+        engine.add_term(offset + i, &manifest.domain, 0.9);
+    }
+
+    engine
+}
+
+fn title_case(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_ported_elsewhere() {
+        let integrations = vec![NormalizedIntegration {
+            name: "Roborock".to_string(),
+            domain: Some("roborock".to_string()),
+            in_hacs: true,
+            ..Default::default()
+        }];
+        let core: Vec<CoreIntegrationManifest> = serde_json::from_value(serde_json::json!([
+            {"domain": "hue", "name": "Philips Hue"}
+        ]))
+        .unwrap();
+        let homebridge: Vec<HomebridgePlugin> = serde_json::from_value(serde_json::json!([
+            {"package": {"name": "homebridge-roborock-vacuum", "keywords": ["homebridge-plugin"]}, "downloads": {"monthly": 900}},
+            {"package": {"name": "homebridge-hue", "keywords": ["homebridge-plugin"]}, "downloads": {"monthly": 30000}},
+            {"package": {"name": "@acme/homebridge-eufy-security", "keywords": ["Homebridge-Plugin", "eufy"]}, "downloads": {"monthly": 12000}},
+            // A device plugin that talks MQTT is still a device plugin
+            {"package": {"name": "homebridge-shelly", "keywords": ["homebridge-plugin", "shelly", "mqtt"]}, "downloads": {"monthly": 3000}},
+            // Not a device integration: virtual switches, and a library without the plugin keyword
+            {"package": {"name": "homebridge-dummy", "keywords": ["homebridge-plugin", "dummy", "switch"]}, "downloads": {"monthly": 50000}},
+            {"package": {"name": "homebridge-lib", "keywords": ["homebridge"]}, "downloads": {"monthly": 40000}}
+        ]))
+        .unwrap();
+        let openhab: Vec<OpenHabAddon> = serde_json::from_value(serde_json::json!([
+            {"uid": "binding-eufysecurity", "id": "eufysecurity", "label": "Eufy Security Binding", "type": "binding", "likeCount": 20}
+        ]))
+        .unwrap();
+
+        let candidates = find_ported_elsewhere(&integrations, &core, &homebridge, &openhab);
+
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates.iter().any(|c| c.name == "Shelly"));
+        let eufy = candidates.iter().find(|c| c.name == "Eufy Security").unwrap();
+        assert_eq!(eufy.name, "Eufy Security");
+        assert_eq!(eufy.domain.as_deref(), Some("eufy_security"));
+        assert_eq!(eufy.kind, OpportunityKind::PortedElsewhere);
+        assert_eq!(eufy.foreign_ports.len(), 2);
+        assert_eq!(foreign_popularity(eufy), 12200);
+    }
+}
@@ -0,0 +1,303 @@
+//! Enrichment of normalized integrations with vendor library signals from PyPI
+
+use crate::transform::NormalizedIntegration;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A library with no release in this many days is considered unmaintained
+const MAINTAINED_WITHIN_DAYS: i64 = 365;
+
+/// A library with a release in this many days counts as recently released
+const RECENT_RELEASE_DAYS: i64 = 90;
+
+/// Monthly downloads at which the popularity component saturates
+const POPULAR_MONTHLY_DOWNLOADS: f64 = 1_000_000.0;
+
+/// PyPI project metadata (`/pypi/<name>/json`) plus pypistats download counts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PypiPackage {
+    pub info: PypiInfo,
+    #[serde(default)]
+    pub releases: HashMap<String, Vec<PypiReleaseFile>>,
+    /// Download statistics from pypistats.org (`/api/packages/<name>/recent`)
+    #[serde(default)]
+    pub downloads: Option<PypiDownloads>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PypiInfo {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub home_page: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PypiReleaseFile {
+    #[serde(default)]
+    pub upload_time_iso_8601: Option<String>,
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PypiDownloads {
+    #[serde(default)]
+    pub last_day: u64,
+    #[serde(default)]
+    pub last_week: u64,
+    #[serde(default)]
+    pub last_month: u64,
+}
+
+impl PypiPackage {
+    /// Most recent non-yanked upload across all releases
+    pub fn last_release(&self) -> Option<DateTime<Utc>> {
+        self.releases
+            .values()
+            .flatten()
+            .filter(|file| !file.yanked)
+            .filter_map(|file| file.upload_time_iso_8601.as_deref())
+            .filter_map(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+            .max()
+    }
+}
+
+/// Local stand-in for PyPI, keyed by normalized project name
+#[derive(Debug, Clone, Default)]
+pub struct PypiIndex {
+    packages: HashMap<String, PypiPackage>,
+}
+
+impl PypiIndex {
+    /// Build an index from packages
+    pub fn new(packages: Vec<PypiPackage>) -> Self {
+        let packages = packages
+            .into_iter()
+            .map(|p| (normalize_package_name(&p.info.name), p))
+            .collect();
+        Self { packages }
+    }
+
+    /// Look up a package by (unnormalized) name
+    pub fn get(&self, name: &str) -> Option<&PypiPackage> {
+        self.packages.get(&normalize_package_name(name))
+    }
+
+    /// Number of packages in the index
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+}
+
+/// PyPI dump file: a map of project name to metadata or a plain array
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PypiDumpFile {
+    ByName(HashMap<String, PypiPackage>),
+    List(Vec<PypiPackage>),
+}
+
+/// Load a local PyPI metadata dump from JSON file
+pub fn load_pypi_dump(path: &str) -> Result<PypiIndex> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read PyPI dump file")?;
+
+    let data: PypiDumpFile = serde_json::from_str(&content)
+        .context("Failed to parse PyPI dump JSON")?;
+
+    Ok(match data {
+        PypiDumpFile::ByName(packages) => PypiIndex::new(packages.into_values().collect()),
+        PypiDumpFile::List(packages) => PypiIndex::new(packages),
+    })
+}
+
+/// Feasibility and demand signals for a single manifest requirement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibrarySignals {
+    /// Requirement as written in the manifest (e.g. "aiohue==4.7.3")
+    pub requirement: String,
+
+    /// Project name extracted from the requirement
+    pub package: String,
+
+    /// Whether the project exists on PyPI
+    pub exists: bool,
+
+    /// Released within the last year
+    pub maintained: bool,
+
+    /// Released within the last 90 days
+    pub recently_released: bool,
+
+    /// Most recent release
+    pub last_release: Option<DateTime<Utc>>,
+
+    /// Downloads in the last month
+    pub monthly_downloads: u64,
+}
+
+impl LibrarySignals {
+    /// Quality of this library as an integration foundation (0.0 - 1.0)
+    pub fn quality(&self) -> f64 {
+        if !self.exists {
+            return 0.0;
+        }
+
+        let maintenance = if self.maintained { 0.3 } else { 0.0 };
+        let freshness = if self.recently_released { 0.1 } else { 0.0 };
+        let popularity = ((self.monthly_downloads as f64).ln_1p() / POPULAR_MONTHLY_DOWNLOADS.ln_1p()).min(1.0);
+
+        // Existing library is the baseline; maintenance, freshness and popularity add on top
+        0.4 + maintenance + freshness + popularity * 0.2
+    }
+}
+
+/// Library signals aggregated over all requirements of an integration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryFeasibility {
+    pub libraries: Vec<LibrarySignals>,
+
+    /// Mean library quality (0.0 - 1.0), used as `ScoringData.api_quality`
+    pub api_quality: f64,
+
+    /// Whether at least one vendor library exists on PyPI
+    pub has_library: bool,
+
+    /// Total monthly downloads across libraries
+    pub monthly_downloads: u64,
+}
+
+/// Extract the project name from a PEP 508 requirement
+/// ("aiohue==4.7.3" -> "aiohue", "pyfoo[extra]>=1.0; python_version>'3.9'" -> "pyfoo")
+pub fn requirement_package(requirement: &str) -> String {
+    let end = requirement
+        .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_' || c == '.'))
+        .unwrap_or(requirement.len());
+    requirement[..end].trim().to_string()
+}
+
+/// Normalize a project name per PEP 503
+pub fn normalize_package_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    let mut last_was_separator = false;
+
+    for c in name.trim().chars() {
+        if c == '-' || c == '_' || c == '.' {
+            if !last_was_separator {
+                normalized.push('-');
+            }
+            last_was_separator = true;
+        } else {
+            normalized.extend(c.to_lowercase());
+            last_was_separator = false;
+        }
+    }
+
+    normalized
+}
+
+/// Resolve a single requirement against the index
+pub fn resolve_requirement(requirement: &str, index: &PypiIndex, now: DateTime<Utc>) -> LibrarySignals {
+    let package = requirement_package(requirement);
+    let entry = index.get(&package);
+    let last_release = entry.and_then(|p| p.last_release());
+    let released_within = |days: i64| last_release.map(|dt| now - dt <= Duration::days(days)).unwrap_or(false);
+
+    LibrarySignals {
+        requirement: requirement.to_string(),
+        package,
+        exists: entry.is_some(),
+        maintained: released_within(MAINTAINED_WITHIN_DAYS),
+        recently_released: released_within(RECENT_RELEASE_DAYS),
+        last_release,
+        monthly_downloads: entry
+            .and_then(|p| p.downloads.as_ref())
+            .map(|d| d.last_month)
+            .unwrap_or(0),
+    }
+}
+
+/// Resolve each integration's requirements against PyPI and record library signals
+///
+/// Integrations without requirements are left untouched so scoring falls back
+/// to its defaults.
+pub fn enrich_with_pypi(integrations: &mut [NormalizedIntegration], index: &PypiIndex, now: DateTime<Utc>) {
+    for integration in integrations.iter_mut() {
+        if integration.requirements.is_empty() {
+            continue;
+        }
+
+        let libraries: Vec<LibrarySignals> = integration.requirements
+            .iter()
+            .map(|r| resolve_requirement(r, index, now))
+            .collect();
+
+        let api_quality = libraries.iter().map(|l| l.quality()).sum::<f64>() / libraries.len() as f64;
+
+        integration.library_feasibility = Some(LibraryFeasibility {
+            has_library: libraries.iter().any(|l| l.exists),
+            monthly_downloads: libraries.iter().map(|l| l.monthly_downloads).sum(),
+            api_quality,
+            libraries,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requirement_parsing() {
+        assert_eq!(requirement_package("aiohue==4.7.3"), "aiohue");
+        assert_eq!(requirement_package("python-miio[extra]>=0.5"), "python-miio");
+        assert_eq!(requirement_package("PyTurboJPEG; sys_platform != 'win32'"), "PyTurboJPEG");
+        assert_eq!(normalize_package_name("Py_Turbo.JPEG"), "py-turbo-jpeg");
+    }
+
+    #[test]
+    fn test_resolve_requirement() {
+        let now = Utc::now();
+        let recent = (now - Duration::days(30)).to_rfc3339();
+        let stale = (now - Duration::days(800)).to_rfc3339();
+
+        let packages: Vec<PypiPackage> = serde_json::from_value(serde_json::json!([
+            {
+                "info": {"name": "aiohue", "version": "4.7.3"},
+                "releases": {"4.7.3": [{"upload_time_iso_8601": recent}]},
+                "downloads": {"last_month": 250000}
+            },
+            {
+                "info": {"name": "pyoldvendor", "version": "0.1"},
+                "releases": {"0.1": [{"upload_time_iso_8601": stale}]}
+            }
+        ]))
+        .unwrap();
+        let index = PypiIndex::new(packages);
+
+        let fresh = resolve_requirement("aiohue==4.7.3", &index, now);
+        assert!(fresh.exists && fresh.maintained && fresh.recently_released);
+        assert_eq!(fresh.monthly_downloads, 250000);
+
+        let old = resolve_requirement("PyOldVendor==0.1", &index, now);
+        assert!(old.exists && !old.maintained);
+
+        let missing = resolve_requirement("not-on-pypi==1.0", &index, now);
+        assert!(!missing.exists);
+        assert_eq!(missing.quality(), 0.0);
+
+        assert!(fresh.quality() > old.quality());
+        assert!(fresh.quality() <= 1.0);
+    }
+}
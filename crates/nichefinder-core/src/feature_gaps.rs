@@ -0,0 +1,402 @@
+//! Feature-gap mining from incumbent issue trackers
+//!
+//! Where an integration already exists, its open issues describe what users
+//! still cannot do. Open issues are classified as feature requests or bugs
+//! (labels first, then the phrasing of title and body), similar requests are
+//! clustered by the overlap of their title terms, and the clusters are
+//! ranked by demand. The ranked gaps are attached to the opportunity as its
+//! differentiation opportunities: what a new integration could do better.
+
+use crate::{
+    collectors::{GitHubIssue, RepoActivity},
+    matching::normalize_text,
+    transform::NormalizedIntegration,
+    types::{markdown_link, Evidence, EvidenceKind},
+};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Label fragments marking a feature request
+const FEATURE_LABELS: &[&str] = &["feature", "enhancement", "improvement", "idea", "device request"];
+
+/// Label fragments marking a bug
+const BUG_LABELS: &[&str] = &["bug", "defect", "regression", "crash"];
+
+/// Label fragments marking issues that are not actionable
+const EXCLUDED_LABELS: &[&str] = &["duplicate", "invalid", "wontfix", "won't fix", "question", "stale"];
+
+/// Title/body phrases (normalized) marking a bug report
+const BUG_PHRASES: &[&str] = &[
+    "error",
+    "exception",
+    "traceback",
+    "crash",
+    "crashes",
+    "not working",
+    "doesn t work",
+    "does not work",
+    "stopped working",
+    "broken",
+    "fails",
+    "failed",
+    "bug",
+    "unavailable",
+    "steps to reproduce",
+];
+
+/// Title/body phrases (normalized) marking a feature request
+const FEATURE_PHRASES: &[&str] = &[
+    "feature request",
+    "fr",
+    "add support",
+    "support for",
+    "please add",
+    "add",
+    "allow",
+    "option to",
+    "ability to",
+    "would be nice",
+    "would be great",
+    "request",
+    "enhancement",
+    "is your feature request related",
+];
+
+/// Feature phrases too generic to trust in a body: issue templates say
+/// "Add any other context" and "request" whatever the issue is
+const TITLE_ONLY_PHRASES: &[&str] = &["fr", "add", "request"];
+
+/// Words too common in issue titles to tell requests apart
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "from", "that", "this", "when", "into", "not", "are", "can", "could",
+    "would", "should", "please", "add", "added", "adding", "support", "supported", "feature", "request",
+    "allow", "option", "ability", "able", "new", "possible", "integration", "home", "assistant", "use",
+    "using", "have", "has", "nice", "great", "make", "like", "also", "other", "more", "via", "enhancement",
+];
+
+/// What an issue asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    FeatureRequest,
+    Bug,
+
+    /// Questions, duplicates and anything unclassified
+    Other,
+}
+
+/// Classify an issue by its labels, then by the phrasing of its title and body
+///
+/// Bug phrasing wins over request phrasing ("Error when adding a scene" is a
+/// bug); the body is only consulted when the title says neither, and only for
+/// the more specific request phrases.
+pub fn classify_issue(issue: &GitHubIssue) -> IssueKind {
+    let labels: Vec<String> = issue.labels.iter().map(|l| l.name.to_lowercase()).collect();
+    let labeled = |fragments: &[&str]| labels.iter().any(|l| fragments.iter().any(|f| l.contains(f)));
+    if labeled(EXCLUDED_LABELS) {
+        return IssueKind::Other;
+    }
+    if labeled(BUG_LABELS) {
+        return IssueKind::Bug;
+    }
+    if labeled(FEATURE_LABELS) {
+        return IssueKind::FeatureRequest;
+    }
+
+    let title = format!(" {} ", normalize_text(&issue.title));
+    let body = format!(" {} ", normalize_text(issue.body.as_deref().unwrap_or_default()));
+    let says = |text: &str, phrases: &[&str]| phrases.iter().any(|p| text.contains(&format!(" {} ", p)));
+    let body_feature_phrases: Vec<&str> = FEATURE_PHRASES
+        .iter()
+        .copied()
+        .filter(|p| !TITLE_ONLY_PHRASES.contains(p))
+        .collect();
+    if says(&title, BUG_PHRASES) {
+        IssueKind::Bug
+    } else if says(&title, FEATURE_PHRASES) {
+        IssueKind::FeatureRequest
+    } else if says(&body, BUG_PHRASES) {
+        IssueKind::Bug
+    } else if says(&body, &body_feature_phrases) {
+        IssueKind::FeatureRequest
+    } else {
+        IssueKind::Other
+    }
+}
+
+/// An open issue backing a feature gap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GapIssue {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+}
+
+/// A cluster of similar unmet feature requests on an incumbent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureGap {
+    /// Title of the most engaged request in the cluster
+    pub summary: String,
+
+    /// Terms most of the cluster's requests share
+    pub terms: Vec<String>,
+
+    pub issues: Vec<GapIssue>,
+
+    /// 👍 reactions across the cluster
+    pub upvotes: u64,
+
+    /// Comments across the cluster
+    pub comments: u64,
+
+    /// Ranking score: requests plus upvotes plus half the comments
+    pub demand: f64,
+}
+
+impl FeatureGap {
+    /// Citable evidence for the gap: its lead issue with the cluster's engagement
+    pub fn evidence(&self) -> Option<Evidence> {
+        let lead = self.issues.first()?;
+        Some(Evidence {
+            source: "github".to_string(),
+            kind: EvidenceKind::Issue,
+            url: lead.url.clone(),
+            title: lead.title.clone(),
+            timestamp: None,
+            engagement: BTreeMap::from([
+                ("requests".to_string(), self.issues.len() as u64),
+                ("upvotes".to_string(), self.upvotes),
+                ("comments".to_string(), self.comments),
+            ]),
+            match_quality: 1.0,
+        })
+    }
+
+    /// Markdown link to the lead request with the cluster's demand
+    pub fn markdown_citation(&self) -> String {
+        let url = self.issues.first().map(|i| i.url.as_str()).unwrap_or_default();
+        let numbers = self.issues.iter().map(|i| format!("#{}", i.number)).collect::<Vec<_>>().join(", ");
+        format!(
+            "{} — {} requests, {} upvotes, {} comments ({})",
+            markdown_link(&self.summary, url),
+            self.issues.len(),
+            self.upvotes,
+            self.comments,
+            numbers
+        )
+    }
+}
+
+/// Mines ranked feature gaps from an incumbent's open issues
+#[derive(Debug, Clone)]
+pub struct GapMiner {
+    similarity: f64,
+    max_gaps: usize,
+}
+
+impl GapMiner {
+    /// Miner with the default similarity threshold, keeping ten gaps
+    pub fn new() -> Self {
+        Self {
+            similarity: 0.34,
+            max_gaps: 10,
+        }
+    }
+
+    /// Set the title-term Jaccard similarity (0-1) needed to join a cluster
+    pub fn with_similarity(mut self, similarity: f64) -> Self {
+        self.similarity = similarity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Keep at most this many gaps per incumbent
+    pub fn with_max_gaps(mut self, max_gaps: usize) -> Self {
+        self.max_gaps = max_gaps;
+        self
+    }
+
+    /// Ranked feature gaps of a repository, most demanded first
+    pub fn mine(&self, activity: &RepoActivity) -> Vec<FeatureGap> {
+        let mut requests: Vec<&GitHubIssue> = activity
+            .issues
+            .iter()
+            .filter(|i| i.pull_request.is_none() && i.state == "open")
+            .filter(|i| classify_issue(i) == IssueKind::FeatureRequest)
+            .collect();
+        // Most engaged requests first, so they seed the clusters
+        requests.sort_by(|a, b| engagement(b).total_cmp(&engagement(a)).then(a.number.cmp(&b.number)));
+
+        // Greedy clustering against each cluster's seed terms
+        let mut clusters: Vec<(BTreeSet<String>, Vec<&GitHubIssue>)> = Vec::new();
+        for issue in requests {
+            let terms = title_terms(&issue.title);
+            let best = clusters
+                .iter_mut()
+                .map(|cluster| (jaccard(&cluster.0, &terms), cluster))
+                .filter(|(similarity, _)| *similarity >= self.similarity)
+                .max_by(|a, b| a.0.total_cmp(&b.0));
+            match best {
+                Some((_, cluster)) if !terms.is_empty() => cluster.1.push(issue),
+                _ => clusters.push((terms, vec![issue])),
+            }
+        }
+
+        let mut gaps: Vec<FeatureGap> = clusters
+            .into_iter()
+            .map(|(_, issues)| gap(&activity.full_name, &issues))
+            .collect();
+        gaps.sort_by(|a, b| b.demand.total_cmp(&a.demand));
+        gaps.truncate(self.max_gaps);
+        gaps
+    }
+}
+
+impl Default for GapMiner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn gap(full_name: &str, issues: &[&GitHubIssue]) -> FeatureGap {
+    let upvotes = issues.iter().filter_map(|i| i.reactions.as_ref()).map(|r| r.plus_one).sum();
+    let comments = issues.iter().map(|i| i.comments).sum();
+
+    // Terms shared by at least half the cluster, most shared first
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for issue in issues {
+        for term in title_terms(&issue.title) {
+            *counts.entry(term).or_default() += 1;
+        }
+    }
+    let mut terms: Vec<(String, usize)> = counts.into_iter().filter(|(_, n)| n * 2 >= issues.len()).collect();
+    terms.sort_by_key(|(_, n)| Reverse(*n));
+
+    FeatureGap {
+        summary: issues[0].title.clone(),
+        terms: terms.into_iter().map(|(term, _)| term).take(5).collect(),
+        issues: issues
+            .iter()
+            .map(|i| GapIssue {
+                number: i.number,
+                title: i.title.clone(),
+                url: format!("https://github.com/{}/issues/{}", full_name, i.number),
+            })
+            .collect(),
+        upvotes,
+        comments,
+        demand: issues.len() as f64 + upvotes as f64 + comments as f64 * 0.5,
+    }
+}
+
+fn engagement(issue: &GitHubIssue) -> f64 {
+    issue.reactions.as_ref().map(|r| r.plus_one).unwrap_or(0) as f64 + issue.comments as f64 * 0.5
+}
+
+/// Distinctive title terms, with plural "s" stripped
+fn title_terms(title: &str) -> BTreeSet<String> {
+    normalize_text(title)
+        .split(' ')
+        .filter(|word| word.len() >= 3 && !STOP_WORDS.contains(word))
+        .map(|word| match word.strip_suffix('s') {
+            Some(stem) if stem.len() >= 3 && !stem.ends_with('s') => stem.to_string(),
+            _ => word.to_string(),
+        })
+        .collect()
+}
+
+fn jaccard(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Mine each integration's repository and record its feature gaps
+///
+/// Repositories are matched by GitHub full name (case-insensitive).
+pub fn enrich_with_feature_gaps(integrations: &mut [NormalizedIntegration], activity: &[RepoActivity], miner: &GapMiner) {
+    let by_name: HashMap<String, &RepoActivity> = activity
+        .iter()
+        .map(|a| (a.full_name.to_lowercase(), a))
+        .collect();
+
+    for integration in integrations.iter_mut() {
+        let repo = integration.sources.github_full_name.as_ref().and_then(|name| by_name.get(&name.to_lowercase()));
+        if let Some(repo) = repo {
+            integration.feature_gaps = miner.mine(repo);
+            integration.evidence.extend(integration.feature_gaps.iter().filter_map(FeatureGap::evidence));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(number: u64, title: &str, labels: &[&str], plus_one: u64, comments: u64) -> serde_json::Value {
+        serde_json::json!({
+            "id": number, "number": number, "title": title, "state": "open", "comments": comments,
+            "created_at": "2025-05-01T00:00:00Z", "updated_at": "2025-05-01T00:00:00Z", "closed_at": null,
+            "labels": labels.iter().map(|l| serde_json::json!({"name": l, "color": "ffffff"})).collect::<Vec<_>>(),
+            "reactions": {"total_count": plus_one, "+1": plus_one},
+        })
+    }
+
+    fn parse(value: serde_json::Value) -> GitHubIssue {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_classify_issue() {
+        assert_eq!(classify_issue(&parse(issue(1, "Support for scenes", &["enhancement"], 0, 0))), IssueKind::FeatureRequest);
+        assert_eq!(classify_issue(&parse(issue(2, "Add scenes", &["bug"], 0, 0))), IssueKind::Bug);
+        assert_eq!(classify_issue(&parse(issue(3, "Please add energy sensors", &[], 0, 0))), IssueKind::FeatureRequest);
+        assert_eq!(classify_issue(&parse(issue(4, "Error when adding a scene", &[], 0, 0))), IssueKind::Bug);
+        assert_eq!(classify_issue(&parse(issue(5, "[FR] Boost mode", &[], 0, 0))), IssueKind::FeatureRequest);
+        assert_eq!(classify_issue(&parse(issue(6, "How do I pair?", &[], 0, 0))), IssueKind::Other);
+        assert_eq!(classify_issue(&parse(issue(7, "Add scenes", &["duplicate", "enhancement"], 0, 0))), IssueKind::Other);
+
+        // The body decides only when the title says nothing either way
+        let with_body = |number, title: &str, body: &str| {
+            let mut value = issue(number, title, &[], 0, 0);
+            value["body"] = serde_json::json!(body);
+            parse(value)
+        };
+        let bug_template = "### The problem\nThermostat shows 0 degrees since the update.\n\n\
+            ### Steps to reproduce\n1. Restart\n\n### Additional context\n\
+            Add any other context about the problem here. Request logs are attached.";
+        assert_eq!(classify_issue(&with_body(8, "Thermostat shows 0 degrees", bug_template)), IssueKind::Bug);
+        let feature_template = "**Is your feature request related to a problem?**\nI want a boost button.";
+        assert_eq!(classify_issue(&with_body(9, "Boost button", feature_template)), IssueKind::FeatureRequest);
+        assert_eq!(classify_issue(&with_body(10, "Boost button", "Add a request for boost mode")), IssueKind::Other);
+    }
+
+    #[test]
+    fn test_mine_ranks_clustered_requests() {
+        let activity: RepoActivity = serde_json::from_value(serde_json::json!({
+            "full_name": "someone/tado",
+            "issues": [
+                issue(1, "Support for open window detection", &["enhancement"], 3, 2),
+                issue(2, "Add open window detection", &[], 5, 4),
+                issue(3, "Open window detection sensor", &["feature request"], 1, 0),
+                issue(4, "Please add hot water schedules", &[], 2, 0),
+                issue(5, "Integration crashes on startup", &[], 20, 10),
+                {"id": 6, "number": 6, "title": "Add boost mode", "state": "closed", "comments": 0,
+                 "created_at": "2025-05-01T00:00:00Z", "updated_at": "2025-05-01T00:00:00Z", "closed_at": "2025-05-02T00:00:00Z"},
+            ],
+        }))
+        .unwrap();
+
+        let gaps = GapMiner::new().mine(&activity);
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0].summary, "Add open window detection");
+        assert_eq!(gaps[0].issues.len(), 3);
+        assert_eq!(gaps[0].terms, vec!["detection", "open", "window"]);
+        assert_eq!(gaps[0].upvotes, 9);
+        assert_eq!(gaps[0].issues[0].url, "https://github.com/someone/tado/issues/2");
+        assert_eq!(gaps[1].summary, "Please add hot water schedules");
+        assert_eq!(GapMiner::new().with_max_gaps(1).mine(&activity).len(), 1);
+    }
+}
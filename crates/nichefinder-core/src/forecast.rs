@@ -0,0 +1,442 @@
+//! Forecasting demand signals from snapshot history
+//!
+//! Every stored snapshot adds one observation of an integration's stars,
+//! mentions (YouTube, Hacker News and forum topics) and active installs.
+//! Holt's linear trend method smooths each series into a level and a trend,
+//! projects it `horizon_days` ahead and puts a Student-t prediction interval
+//! around the projection from the one-step-ahead errors.
+//!
+//! The projected star trend (stars per day) feeds `ScoringData::growth_rate`
+//! in place of the lifetime average when history is available.
+
+use crate::{
+    backtest::analyze_snapshot,
+    data_lake::DataLake,
+    types::{AnalysisConfig, AnalysisResult, OpportunityKind},
+    Result,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+use statrs::distribution::{ContinuousCDF, StudentsT};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Observations needed before a series is forecast
+pub const MIN_OBSERVATIONS: usize = 3;
+
+/// Default forecast horizon, in days
+pub const DEFAULT_HORIZON_DAYS: u32 = 30;
+
+/// Most recent collection dates analyzed for the signal history; each one
+/// is a full analysis of the stored run
+pub const MAX_HISTORY_SNAPSHOTS: usize = 30;
+
+/// A forecast demand signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForecastSignal {
+    /// GitHub stars
+    Stars,
+
+    /// YouTube videos, Hacker News stories and forum topics
+    Mentions,
+
+    /// Active installs from HA analytics
+    Installs,
+}
+
+impl ForecastSignal {
+    /// All signals, in report order
+    pub const ALL: [ForecastSignal; 3] = [ForecastSignal::Stars, ForecastSignal::Mentions, ForecastSignal::Installs];
+
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ForecastSignal::Stars => "stars",
+            ForecastSignal::Mentions => "mentions",
+            ForecastSignal::Installs => "installs",
+        }
+    }
+
+    /// Value of the signal in a feature vector's `signals` map
+    pub fn value(&self, signals: &BTreeMap<String, f64>) -> Option<f64> {
+        match self {
+            ForecastSignal::Stars => signals.get("stars").copied(),
+            ForecastSignal::Mentions => {
+                let mentions: Vec<f64> = ["youtube_mentions", "hackernews_mentions", "forum_topics"]
+                    .iter()
+                    .filter_map(|name| signals.get(*name).copied())
+                    .collect();
+                (!mentions.is_empty()).then(|| mentions.iter().sum())
+            }
+            ForecastSignal::Installs => signals.get("active_installs").copied(),
+        }
+    }
+}
+
+/// A signal value at a point in time
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    pub at: DateTime<Utc>,
+    pub value: f64,
+}
+
+/// Projection of one signal of an opportunity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalForecast {
+    pub signal: ForecastSignal,
+
+    /// Observations the forecast was fitted to
+    pub observations: usize,
+
+    /// Latest observed value
+    pub current: f64,
+
+    pub horizon_days: u32,
+
+    /// Expected value `horizon_days` after the latest observation
+    pub projected: f64,
+
+    /// Smoothed trend, in signal units per day
+    pub trend_per_day: f64,
+
+    /// Trend relative to the current value, per day
+    pub growth_rate: f64,
+
+    /// Prediction interval around `projected`
+    pub lower: f64,
+    pub upper: f64,
+
+    /// Coverage of the interval (e.g. 0.9)
+    pub confidence: f64,
+}
+
+/// Holt's linear trend (double exponential smoothing) forecaster
+#[derive(Debug, Clone)]
+pub struct HoltForecaster {
+    alpha: f64,
+    beta: f64,
+    horizon_days: u32,
+    confidence: f64,
+}
+
+impl HoltForecaster {
+    /// Forecaster with moderate smoothing, a 30 day horizon and 90% intervals
+    pub fn new() -> Self {
+        Self {
+            alpha: 0.5,
+            beta: 0.3,
+            horizon_days: DEFAULT_HORIZON_DAYS,
+            confidence: 0.9,
+        }
+    }
+
+    /// Set the level (alpha) and trend (beta) smoothing factors, each in 0-1
+    pub fn with_smoothing(mut self, alpha: f64, beta: f64) -> Self {
+        self.alpha = alpha.clamp(0.0, 1.0);
+        self.beta = beta.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set how many days ahead to project
+    pub fn with_horizon_days(mut self, days: u32) -> Self {
+        self.horizon_days = days;
+        self
+    }
+
+    /// Set the coverage of the prediction interval
+    pub fn with_confidence(mut self, confidence: f64) -> Self {
+        self.confidence = confidence.clamp(0.5, 0.999);
+        self
+    }
+
+    /// Forecast a series ordered by time
+    ///
+    /// Snapshots need not be evenly spaced; the series is smoothed step by
+    /// step and the trend converted to days with the mean snapshot interval.
+    /// Returns `None` for fewer than `MIN_OBSERVATIONS` observations or a
+    /// series spanning less than a day.
+    pub fn forecast(&self, signal: ForecastSignal, series: &[Observation]) -> Option<SignalForecast> {
+        let (first, last) = (series.first()?, series.last()?);
+        let span_days = (last.at - first.at).num_seconds() as f64 / 86_400.0;
+        if series.len() < MIN_OBSERVATIONS || span_days < 1.0 {
+            return None;
+        }
+        let step_days = span_days / (series.len() - 1) as f64;
+
+        let values = Array1::from_iter(series.iter().map(|o| o.value));
+        let mut level = values[0];
+        let mut trend = values[1] - values[0];
+        let mut errors = Vec::with_capacity(values.len());
+        for (t, &value) in values.iter().enumerate().skip(1) {
+            // The first step only initializes the trend
+            if t > 1 {
+                errors.push(value - (level + trend));
+            }
+            let previous = level;
+            level = self.alpha * value + (1.0 - self.alpha) * (level + trend);
+            trend = self.beta * (level - previous) + (1.0 - self.beta) * trend;
+        }
+        let errors = Array1::from(errors);
+        let sigma = (errors.mapv(|e| e * e).sum() / errors.len() as f64).sqrt();
+
+        // Variance of the h-step-ahead error relative to the one-step error
+        let steps = f64::from(self.horizon_days) / step_days;
+        let variance_factor = 1.0
+            + (1..steps.ceil() as usize)
+                .map(|j| (self.alpha * (1.0 + j as f64 * self.beta)).powi(2))
+                .sum::<f64>();
+        let quantile = StudentsT::new(0.0, 1.0, errors.len() as f64)
+            .map(|t| t.inverse_cdf(0.5 + self.confidence / 2.0))
+            .ok()?;
+        let margin = quantile * sigma * variance_factor.sqrt();
+
+        // Counts never fall below zero
+        let projected = (level + steps * trend).max(0.0);
+        let trend_per_day = trend / step_days;
+        Some(SignalForecast {
+            signal,
+            observations: series.len(),
+            current: last.value,
+            horizon_days: self.horizon_days,
+            projected,
+            trend_per_day,
+            growth_rate: if last.value > 0.0 { trend_per_day / last.value } else { 0.0 },
+            lower: (projected - margin).max(0.0),
+            upper: projected + margin,
+            confidence: self.confidence,
+        })
+    }
+}
+
+impl Default for HoltForecaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Key of an opportunity across snapshots
+pub fn history_key(kind: OpportunityKind, name: &str) -> String {
+    format!("{}:{}", kind.as_str(), name.to_lowercase())
+}
+
+/// Signals of an opportunity observed at one snapshot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalSnapshot {
+    pub at: DateTime<Utc>,
+
+    /// Feature vector signals (see `ScoringData::signals`)
+    pub signals: BTreeMap<String, f64>,
+}
+
+/// Per-opportunity signal snapshots across stored runs
+#[derive(Debug, Clone, Default)]
+pub struct SignalHistory {
+    snapshots: BTreeMap<String, Vec<SignalSnapshot>>,
+}
+
+impl SignalHistory {
+    /// Create an empty history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Analyze the stored runs on or before `until` (all runs when `None`)
+    /// and record their signals
+    ///
+    /// Only the latest `MAX_HISTORY_SNAPSHOTS` collection dates are analyzed.
+    /// The score threshold and result limit are lifted so every candidate
+    /// gets a series.
+    pub fn from_lake(lake: &DataLake, config: &AnalysisConfig, until: Option<NaiveDate>) -> Result<Self> {
+        let config = AnalysisConfig {
+            min_score: 0.0,
+            max_results: usize::MAX,
+            scorers: Vec::new(),
+            ..config.clone()
+        };
+
+        // One snapshot per collection date, recorded at the time its data was
+        // fetched so an analysis of the same run (with `AnalysisInputs::as_of`
+        // set to `CollectionRun::observed_at`) replaces it instead of adding
+        // a second observation
+        let dates: BTreeSet<NaiveDate> = lake
+            .manifests()?
+            .iter()
+            .map(|manifest| manifest.collected_at.date_naive())
+            .filter(|date| until.is_none_or(|until| *date <= until))
+            .collect();
+
+        let mut history = Self::new();
+        for date in dates.into_iter().rev().take(MAX_HISTORY_SNAPSHOTS) {
+            let (observed_at, result) = analyze_snapshot(lake, &config, date)?;
+            history.record(observed_at, &result);
+        }
+        Ok(history)
+    }
+
+    /// Record the signals of every opportunity in a result
+    pub fn record(&mut self, at: DateTime<Utc>, result: &AnalysisResult) {
+        for opportunity in &result.opportunities {
+            if let Some(features) = &opportunity.features {
+                self.observe(&history_key(opportunity.kind, &opportunity.name), at, &features.signals);
+            }
+        }
+    }
+
+    /// Record signals observed at a time; a later observation at the same
+    /// time replaces the earlier one
+    pub fn observe(&mut self, key: &str, at: DateTime<Utc>, signals: &BTreeMap<String, f64>) {
+        let snapshots = self.snapshots.entry(key.to_string()).or_default();
+        let index = snapshots.partition_point(|s| s.at < at);
+        let snapshot = SignalSnapshot { at, signals: signals.clone() };
+        match snapshots.get_mut(index) {
+            Some(existing) if existing.at == at => *existing = snapshot,
+            _ => snapshots.insert(index, snapshot),
+        }
+    }
+
+    /// Snapshots of an opportunity, oldest first
+    pub fn snapshots(&self, key: &str) -> &[SignalSnapshot] {
+        self.snapshots.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Snapshots of an opportunity followed by the signals observed now
+    ///
+    /// When `now` is the time of a recorded snapshot (the analyzed run is
+    /// part of the history) the current signals replace it.
+    pub fn with_current(&self, key: &str, now: DateTime<Utc>, signals: &BTreeMap<String, f64>) -> Vec<SignalSnapshot> {
+        let mut current = SignalHistory::new();
+        current.snapshots.insert(key.to_string(), self.snapshots(key).to_vec());
+        current.observe(key, now, signals);
+        current.snapshots.remove(key).unwrap_or_default()
+    }
+
+    /// Observations of one forecast signal of an opportunity, oldest first
+    pub fn series(&self, key: &str, signal: ForecastSignal) -> Vec<Observation> {
+        signal_series(self.snapshots(key), |signals| signal.value(signals))
+    }
+
+    /// Number of opportunities with snapshots
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Whether nothing was recorded
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Forecast every signal of an opportunity, including the signals
+    /// observed now
+    pub fn forecast(
+        &self,
+        key: &str,
+        now: DateTime<Utc>,
+        signals: &BTreeMap<String, f64>,
+        forecaster: &HoltForecaster,
+    ) -> Vec<SignalForecast> {
+        let snapshots = self.with_current(key, now, signals);
+        ForecastSignal::ALL
+            .iter()
+            .filter_map(|&signal| forecaster.forecast(signal, &signal_series(&snapshots, |s| signal.value(s))))
+            .collect()
+    }
+}
+
+/// Observations of a value over snapshots, skipping snapshots without it
+pub fn signal_series(
+    snapshots: &[SignalSnapshot],
+    value: impl Fn(&BTreeMap<String, f64>) -> Option<f64>,
+) -> Vec<Observation> {
+    snapshots
+        .iter()
+        .filter_map(|s| value(&s.signals).map(|value| Observation { at: s.at, value }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn series(values: &[f64]) -> Vec<Observation> {
+        let start = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| Observation { at: start + Duration::days(7 * i as i64), value })
+            .collect()
+    }
+
+    #[test]
+    fn test_holt_linear_series() {
+        // 70 stars a week: 10 a day
+        let stars = series(&[100.0, 170.0, 240.0, 310.0, 380.0, 450.0]);
+        let forecast = HoltForecaster::new().forecast(ForecastSignal::Stars, &stars).unwrap();
+
+        assert!((forecast.trend_per_day - 10.0).abs() < 1e-9);
+        assert!((forecast.projected - 750.0).abs() < 1e-9);
+        // A perfectly linear series has no forecast error
+        assert!((forecast.upper - forecast.lower).abs() < 1e-9);
+        assert!((forecast.growth_rate - 10.0 / 450.0).abs() < 1e-9);
+
+        assert!(HoltForecaster::new().forecast(ForecastSignal::Stars, &stars[..2]).is_none());
+    }
+
+    #[test]
+    fn test_holt_interval_widens_with_noise_and_horizon() {
+        let noisy = series(&[100.0, 180.0, 230.0, 330.0, 370.0, 460.0, 500.0]);
+        let short = HoltForecaster::new().with_horizon_days(7).forecast(ForecastSignal::Stars, &noisy).unwrap();
+        let long = HoltForecaster::new().with_horizon_days(90).forecast(ForecastSignal::Stars, &noisy).unwrap();
+
+        assert!(short.lower < short.projected && short.projected < short.upper);
+        assert!(long.upper - long.lower > short.upper - short.lower);
+        assert!(long.trend_per_day > 0.0);
+    }
+
+    #[test]
+    fn test_history_includes_current_observation() {
+        let key = history_key(OpportunityKind::CommunityIntegration, "Acme Hub");
+        let mut history = SignalHistory::new();
+        for observation in series(&[10.0, 20.0, 30.0]) {
+            let signals = BTreeMap::from([
+                ("stars".to_string(), observation.value),
+                ("youtube_mentions".to_string(), 1.0),
+            ]);
+            history.observe(&key, observation.at, &signals);
+        }
+        assert_eq!(history.series(&key, ForecastSignal::Stars).len(), 3);
+        assert!(history.series(&key, ForecastSignal::Installs).is_empty());
+
+        let now = history.series(&key, ForecastSignal::Stars)[2].at + Duration::days(7);
+        let signals = BTreeMap::from([("stars".to_string(), 40.0)]);
+        let forecasts = history.forecast(&key, now, &signals, &HoltForecaster::new());
+
+        let stars = forecasts.iter().find(|f| f.signal == ForecastSignal::Stars).unwrap();
+        assert_eq!(stars.observations, 4);
+        assert_eq!(stars.current, 40.0);
+        // Mentions were not observed now, so only three points remain
+        assert_eq!(forecasts.iter().find(|f| f.signal == ForecastSignal::Mentions).unwrap().observations, 3);
+    }
+
+    #[test]
+    fn test_history_containing_the_analyzed_run() {
+        // A lake analysis: the history already holds the run being analyzed
+        let key = history_key(OpportunityKind::CommunityIntegration, "Acme Hub");
+        let mut history = SignalHistory::new();
+        let observations = series(&[10.0, 20.0, 30.0, 40.0]);
+        for observation in &observations {
+            history.observe(&key, observation.at, &BTreeMap::from([("stars".to_string(), observation.value)]));
+        }
+
+        let now = observations[3].at;
+        let signals = BTreeMap::from([("stars".to_string(), 40.0)]);
+        let forecasts = history.forecast(&key, now, &signals, &HoltForecaster::new());
+
+        let stars = forecasts.iter().find(|f| f.signal == ForecastSignal::Stars).unwrap();
+        assert_eq!(stars.observations, 4);
+        let expected = HoltForecaster::new().forecast(ForecastSignal::Stars, &observations).unwrap();
+        assert_eq!(stars.trend_per_day, expected.trend_per_day);
+        assert!(stars.trend_per_day > 1.0);
+    }
+}
@@ -6,20 +6,43 @@
 pub mod error;
 pub mod types;
 pub mod scoring;
+pub mod script_scoring;
 pub mod reporting;
 pub mod collectors;
 pub mod connector_gen;
 pub mod transform;
+pub mod matching;
+pub mod enrichment;
+pub mod maintenance;
+pub mod feature_gaps;
+pub mod ecosystems;
+pub mod device_catalogs;
+pub mod declarative;
+pub mod custom_sources;
 pub mod analysis;
+pub mod pipeline;
+pub mod orchestrator;
+pub mod data_lake;
+pub mod backtest;
+pub mod forecast;
+pub mod breakouts;
+pub mod lifecycle;
+pub mod training;
+
+#[cfg(test)]
+mod test_support;
 
 // Re-export commonly used types
 pub use error::{Error, Result};
 pub use types::{
-    NicheOpportunity, IntegrationScore, DataSource, AnalysisConfig, AnalysisResult,
+    NicheOpportunity, OpportunityKind, IntegrationScore, DataSource, Evidence, EvidenceKind, AnalysisConfig,
+    AnalysisResult, EnsembleMethod, ScorerResult,
 };
-pub use scoring::OpportunityScorer;
+pub use scoring::{Ensemble, OpportunityScorer, ScoringData};
 pub use reporting::ReportGenerator;
-pub use analysis::IntegrationAnalyzer;
+pub use analysis::{IntegrationAnalyzer, AnalysisInputs};
+pub use orchestrator::{CollectionOrchestrator, CollectionRun};
+pub use pipeline::{AnalysisPipeline, Enricher};
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
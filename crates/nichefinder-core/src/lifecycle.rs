@@ -0,0 +1,250 @@
+//! Lifecycle stage classification of niches
+//!
+//! Go/no-go rules differ sharply by stage, so every opportunity is labeled
+//! from its feature vector (age, growth, competition and maintenance
+//! activity) and, when snapshot history was available, its forecasts.
+//! A niche with no age, no activity date and no forecast is left
+//! unclassified. Otherwise rules are checked in order and the first match
+//! wins:
+//!
+//! 1. **Declining**: no activity for `stale_days`, or mentions or installs
+//!    forecast to fall by at least `declining_rate` per day (relative) or
+//!    with the whole prediction interval below the current value
+//! 2. **Saturated**: at least `saturated_competitors` existing integrations
+//! 3. **Emerging**: younger than `emerging_days`
+//! 4. **Growing**: stars forecast to grow at least `growing_rate` per day
+//!    (relative); without a forecast, younger than `growing_days` and active
+//!    within `active_days`
+//! 5. **Mature**: everything else
+
+use crate::{
+    forecast::{ForecastSignal, SignalForecast},
+    scoring::ScoringData,
+    Error, Result,
+};
+use serde::{Deserialize, Serialize};
+
+/// Where a niche is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleStage {
+    /// New, little adoption yet
+    Emerging,
+
+    /// Adoption is rising
+    Growing,
+
+    /// Established and stable
+    Mature,
+
+    /// Several integrations already compete
+    Saturated,
+
+    /// Activity or interest is falling off
+    Declining,
+}
+
+impl LifecycleStage {
+    /// All stages, in lifecycle order
+    pub const ALL: [LifecycleStage; 5] = [
+        LifecycleStage::Emerging,
+        LifecycleStage::Growing,
+        LifecycleStage::Mature,
+        LifecycleStage::Saturated,
+        LifecycleStage::Declining,
+    ];
+
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LifecycleStage::Emerging => "emerging",
+            LifecycleStage::Growing => "growing",
+            LifecycleStage::Mature => "mature",
+            LifecycleStage::Saturated => "saturated",
+            LifecycleStage::Declining => "declining",
+        }
+    }
+}
+
+impl std::str::FromStr for LifecycleStage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        LifecycleStage::ALL
+            .into_iter()
+            .find(|stage| stage.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "Unknown lifecycle stage '{}' (expected emerging, growing, mature, saturated or declining)",
+                    s
+                ))
+            })
+    }
+}
+
+/// Rule-based lifecycle classifier
+#[derive(Debug, Clone)]
+pub struct LifecycleClassifier {
+    /// Days without activity after which a niche is declining
+    pub stale_days: u32,
+
+    /// Relative fall in mentions or installs per day from which a niche is declining
+    pub declining_rate: f64,
+
+    /// Existing integrations at which a niche is saturated
+    pub saturated_competitors: usize,
+
+    /// Age below which a niche is emerging
+    pub emerging_days: f64,
+
+    /// Relative star growth per day from which a niche is growing
+    pub growing_rate: f64,
+
+    /// Without a forecast: age below which an active niche is growing
+    pub growing_days: f64,
+
+    /// Without a forecast: days since activity within which a niche is active
+    pub active_days: u32,
+}
+
+impl LifecycleClassifier {
+    /// Classifier with the default thresholds
+    pub fn new() -> Self {
+        Self {
+            stale_days: 365,
+            declining_rate: 0.001,
+            saturated_competitors: 3,
+            emerging_days: 180.0,
+            // About 44% a year
+            growing_rate: 0.001,
+            growing_days: 730.0,
+            active_days: 90,
+        }
+    }
+
+    /// Stage of an opportunity from its features and forecasts, or `None`
+    /// when there is nothing to date it by
+    pub fn classify(&self, data: &ScoringData, forecasts: &[SignalForecast]) -> Option<LifecycleStage> {
+        let forecast = |signal| forecasts.iter().find(|f| f.signal == signal);
+        let falling = [ForecastSignal::Mentions, ForecastSignal::Installs]
+            .into_iter()
+            .filter_map(forecast)
+            .any(|f| f.growth_rate <= -self.declining_rate || f.upper < f.current);
+        let age_days = data.signals.get("age_days").copied();
+        let active = data.signals.contains_key("days_since_activity");
+        if age_days.is_none() && !active && forecasts.is_empty() {
+            return None;
+        }
+
+        let stale = active && data.days_since_last_request > self.stale_days;
+        Some(if stale || falling {
+            LifecycleStage::Declining
+        } else if data.existing_integrations >= self.saturated_competitors {
+            LifecycleStage::Saturated
+        } else if age_days.is_some_and(|age| age < self.emerging_days) {
+            LifecycleStage::Emerging
+        } else if self.growing(data, age_days, forecast(ForecastSignal::Stars)) {
+            LifecycleStage::Growing
+        } else {
+            LifecycleStage::Mature
+        })
+    }
+
+    fn growing(&self, data: &ScoringData, age_days: Option<f64>, stars: Option<&SignalForecast>) -> bool {
+        match stars {
+            Some(stars) => stars.growth_rate >= self.growing_rate,
+            None => {
+                age_days.is_some_and(|age| age < self.growing_days) && data.days_since_last_request <= self.active_days
+            }
+        }
+    }
+}
+
+impl Default for LifecycleClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn data(age_days: Option<f64>, existing_integrations: usize, days_since_last_request: u32) -> ScoringData {
+        let mut signals = BTreeMap::from([("days_since_activity".to_string(), f64::from(days_since_last_request))]);
+        if let Some(age) = age_days {
+            signals.insert("age_days".to_string(), age);
+        }
+        ScoringData {
+            request_count: 10,
+            custom_signals: 0.0,
+            growth_rate: 0.0,
+            has_api: true,
+            api_quality: 0.8,
+            existing_integrations,
+            official_integrations: 0,
+            days_since_last_request,
+            signals,
+        }
+    }
+
+    fn forecast(signal: ForecastSignal, trend_per_day: f64, growth_rate: f64) -> SignalForecast {
+        SignalForecast {
+            signal,
+            observations: 4,
+            current: 100.0,
+            horizon_days: 30,
+            projected: 100.0 + 30.0 * trend_per_day,
+            trend_per_day,
+            growth_rate,
+            lower: 0.0,
+            upper: 200.0,
+            confidence: 0.9,
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let classifier = LifecycleClassifier::new();
+        let classify = |data: &ScoringData, forecasts: &[SignalForecast]| classifier.classify(data, forecasts).unwrap();
+
+        assert_eq!(classify(&data(Some(60.0), 1, 5), &[]), LifecycleStage::Emerging);
+        assert_eq!(classify(&data(Some(400.0), 1, 30), &[]), LifecycleStage::Growing);
+        assert_eq!(classify(&data(Some(1500.0), 1, 30), &[]), LifecycleStage::Mature);
+        assert_eq!(classify(&data(Some(60.0), 4, 5), &[]), LifecycleStage::Saturated);
+        assert_eq!(classify(&data(Some(60.0), 4, 400), &[]), LifecycleStage::Declining);
+
+        // Forecasts override the age-based growth estimate
+        let old = data(Some(1500.0), 1, 30);
+        let rising = [forecast(ForecastSignal::Stars, 1.0, 0.01)];
+        assert_eq!(classify(&old, &rising), LifecycleStage::Growing);
+        let fading = [forecast(ForecastSignal::Stars, 1.0, 0.01), forecast(ForecastSignal::Mentions, -0.2, -0.002)];
+        assert_eq!(classify(&old, &fading), LifecycleStage::Declining);
+
+        // A slight dip inside the prediction interval is noise, not decline
+        let wobbling = [forecast(ForecastSignal::Mentions, -0.01, -0.0001)];
+        assert_eq!(classify(&old, &wobbling), LifecycleStage::Mature);
+        let mut confident = forecast(ForecastSignal::Installs, -0.01, -0.0001);
+        confident.upper = 99.0;
+        assert_eq!(classify(&old, &[confident]), LifecycleStage::Declining);
+
+        assert_eq!("Saturated".parse::<LifecycleStage>().unwrap(), LifecycleStage::Saturated);
+        assert!("peak".parse::<LifecycleStage>().is_err());
+    }
+
+    #[test]
+    fn test_classify_without_evidence() {
+        let classifier = LifecycleClassifier::new();
+
+        // No age, no activity date and no history: not mature, just unknown
+        let mut unknown = data(None, 1, 365);
+        unknown.signals.clear();
+        assert_eq!(classifier.classify(&unknown, &[]), None);
+
+        // The fallback recency alone doesn't make it stale either
+        let mut undated = data(Some(1500.0), 1, 365);
+        undated.signals.remove("days_since_activity");
+        assert_eq!(classifier.classify(&undated, &[]), Some(LifecycleStage::Mature));
+    }
+}
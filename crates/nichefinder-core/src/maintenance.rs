@@ -0,0 +1,262 @@
+//! Maintenance health of existing integrations
+//!
+//! A neglected incumbent is weaker competition than a well-run one: users
+//! of an integration with stale issues, no releases and a single absent
+//! maintainer are ready to switch. `assess` rates a repository's activity
+//! from GitHub on five components, each scaled to 0-1:
+//!
+//! - **Issue response**: median days to close an issue (`1 / (1 + d / 30)`)
+//! - **Open ratio**: share of issues still open (`1 - ratio`)
+//! - **Release cadence**: days since the last release (`1 / (1 + d / 180)`)
+//! - **Bus factor**: contributors covering half the commits (capped at 3)
+//! - **Commit recency**: days since the last commit (`1 / (1 + d / 90)`)
+//!
+//! The score is the weighted mean of the components the repository has data
+//! for. Pull requests are not counted as issues.
+
+use crate::{collectors::RepoActivity, transform::NormalizedIntegration};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Component weights: response, open ratio, release cadence, bus factor, commit recency
+const WEIGHTS: [f64; 5] = [0.2, 0.15, 0.2, 0.2, 0.25];
+
+/// Bus factor at which the component saturates
+const HEALTHY_BUS_FACTOR: usize = 3;
+
+/// Maintenance health of a repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceHealth {
+    /// Weighted health (0.0 - 1.0); 1 is a well-maintained incumbent
+    pub score: f64,
+
+    /// Median days from opening to closing an issue
+    pub median_close_days: Option<f64>,
+
+    /// Share of issues still open
+    pub open_ratio: Option<f64>,
+
+    /// Days since the last published release
+    pub days_since_release: Option<f64>,
+
+    /// Median days between consecutive releases
+    pub release_interval_days: Option<f64>,
+
+    /// Contributors with at least one commit
+    pub contributors: usize,
+
+    /// Fewest contributors accounting for half of all commits
+    pub bus_factor: usize,
+
+    /// Days since the last commit
+    pub days_since_commit: Option<f64>,
+}
+
+/// Rate the maintenance health of a repository as of `now`
+pub fn assess(activity: &RepoActivity, now: DateTime<Utc>) -> MaintenanceHealth {
+    let days_since = |at: DateTime<Utc>| (now - at).num_seconds().max(0) as f64 / 86_400.0;
+
+    let issues: Vec<_> = activity.issues.iter().filter(|i| i.pull_request.is_none()).collect();
+    let close_days: Vec<f64> = issues
+        .iter()
+        .filter_map(|i| {
+            let opened = parse_time(&i.created_at)?;
+            let closed = parse_time(i.closed_at.as_deref()?)?;
+            Some((closed - opened).num_seconds().max(0) as f64 / 86_400.0)
+        })
+        .collect();
+    let open_ratio = (!issues.is_empty())
+        .then(|| issues.iter().filter(|i| i.state == "open").count() as f64 / issues.len() as f64);
+
+    let mut releases: Vec<DateTime<Utc>> = activity
+        .releases
+        .iter()
+        .filter(|r| !r.draft)
+        .filter_map(|r| parse_time(r.published_at.as_deref().unwrap_or(&r.created_at)))
+        .collect();
+    releases.sort();
+    let intervals: Vec<f64> = releases
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).num_seconds() as f64 / 86_400.0)
+        .collect();
+
+    let days_since_commit = activity
+        .commits
+        .iter()
+        .filter_map(|c| parse_time(&c.commit.committer.as_ref()?.date))
+        .max()
+        .map(days_since);
+
+    let mut health = MaintenanceHealth {
+        score: 0.0,
+        median_close_days: median(close_days),
+        open_ratio,
+        days_since_release: releases.last().copied().map(days_since),
+        release_interval_days: median(intervals),
+        contributors: activity.contributors.len(),
+        bus_factor: bus_factor(&activity.contributors.iter().map(|c| c.contributions).collect::<Vec<_>>()),
+        days_since_commit,
+    };
+    health.score = health_score(&health);
+    health
+}
+
+/// Weighted mean of the available components
+fn health_score(health: &MaintenanceHealth) -> f64 {
+    let decay = |days: f64, scale: f64| 1.0 / (1.0 + days / scale);
+    let components = [
+        health.median_close_days.map(|d| decay(d, 30.0)),
+        health.open_ratio.map(|r| 1.0 - r),
+        health.days_since_release.map(|d| decay(d, 180.0)),
+        (health.contributors > 0).then(|| health.bus_factor.min(HEALTHY_BUS_FACTOR) as f64 / HEALTHY_BUS_FACTOR as f64),
+        health.days_since_commit.map(|d| decay(d, 90.0)),
+    ];
+
+    let (total, weight) = components
+        .iter()
+        .zip(WEIGHTS)
+        .filter_map(|(component, weight)| component.map(|c| (c * weight, weight)))
+        .fold((0.0, 0.0), |(total, sum), (c, w)| (total + c, sum + w));
+    if weight > 0.0 {
+        total / weight
+    } else {
+        0.0
+    }
+}
+
+/// Fewest contributors whose commits add up to at least half of all commits
+fn bus_factor(contributions: &[u64]) -> usize {
+    let mut sorted = contributions.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let total: u64 = sorted.iter().sum();
+
+    let mut covered = 0;
+    for (count, contributions) in sorted.iter().enumerate() {
+        covered += contributions;
+        if covered * 2 >= total {
+            return count + 1;
+        }
+    }
+    0
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+fn parse_time(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw).ok().map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Load repository activity collected by `GitHubMaintenanceCollector` from JSON file
+pub fn load_maintenance_data(path: &str) -> Result<Vec<RepoActivity>> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read maintenance data file")?;
+
+    let activity: Vec<RepoActivity> = serde_json::from_str(&content)
+        .context("Failed to parse maintenance data JSON")?;
+
+    Ok(activity)
+}
+
+/// Rate each integration's repository and record its health
+///
+/// Repositories are matched by GitHub full name (case-insensitive);
+/// integrations without collected activity are left untouched.
+pub fn enrich_with_maintenance(integrations: &mut [NormalizedIntegration], activity: &[RepoActivity], now: DateTime<Utc>) {
+    let by_name: HashMap<String, &RepoActivity> = activity
+        .iter()
+        .map(|a| (a.full_name.to_lowercase(), a))
+        .collect();
+
+    for integration in integrations.iter_mut() {
+        let repo = integration.sources.github_full_name.as_ref().and_then(|name| by_name.get(&name.to_lowercase()));
+        if let Some(repo) = repo {
+            integration.maintenance = Some(assess(repo, now));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(issues: serde_json::Value, releases: serde_json::Value, contributors: serde_json::Value, last_commit: &str) -> RepoActivity {
+        serde_json::from_value(serde_json::json!({
+            "full_name": "someone/tado",
+            "issues": issues,
+            "releases": releases,
+            "contributors": contributors,
+            "commits": [{"sha": "abc", "commit": {"committer": {"date": last_commit}}}],
+        }))
+        .unwrap()
+    }
+
+    fn issue(number: u64, created_at: &str, closed_at: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "id": number, "number": number, "title": "Bug", "comments": 1,
+            "state": if closed_at.is_some() { "closed" } else { "open" },
+            "created_at": created_at, "updated_at": created_at, "closed_at": closed_at,
+        })
+    }
+
+    #[test]
+    fn test_assess() {
+        let now = DateTime::parse_from_rfc3339("2025-06-01T00:00:00Z").unwrap().with_timezone(&Utc);
+
+        let healthy = activity(
+            serde_json::json!([
+                issue(1, "2025-05-01T00:00:00Z", Some("2025-05-03T00:00:00Z")),
+                issue(2, "2025-05-10T00:00:00Z", Some("2025-05-11T00:00:00Z")),
+                issue(3, "2025-05-20T00:00:00Z", None),
+                // Pull requests are not issues
+                {"id": 4, "number": 4, "title": "PR", "state": "open", "comments": 0,
+                 "created_at": "2025-05-21T00:00:00Z", "updated_at": "2025-05-21T00:00:00Z", "closed_at": null,
+                 "pull_request": {}},
+            ]),
+            serde_json::json!([
+                {"tag_name": "v2", "created_at": "2025-05-15T00:00:00Z", "published_at": "2025-05-15T00:00:00Z"},
+                {"tag_name": "v1", "created_at": "2025-04-15T00:00:00Z", "published_at": "2025-04-15T00:00:00Z"},
+            ]),
+            serde_json::json!([
+                {"login": "a", "contributions": 40},
+                {"login": "b", "contributions": 35},
+                {"login": "c", "contributions": 25},
+            ]),
+            "2025-05-30T00:00:00Z",
+        );
+        let health = assess(&healthy, now);
+        assert_eq!(health.median_close_days, Some(1.5));
+        assert_eq!(health.open_ratio, Some(1.0 / 3.0));
+        assert_eq!(health.days_since_release, Some(17.0));
+        assert_eq!(health.release_interval_days, Some(30.0));
+        assert_eq!(health.bus_factor, 2);
+        assert!(health.score > 0.7, "score {}", health.score);
+
+        let abandoned = activity(
+            serde_json::json!([
+                issue(1, "2023-01-01T00:00:00Z", Some("2023-06-01T00:00:00Z")),
+                issue(2, "2023-02-01T00:00:00Z", None),
+                issue(3, "2023-03-01T00:00:00Z", None),
+            ]),
+            serde_json::json!([]),
+            serde_json::json!([{"login": "a", "contributions": 90}, {"login": "b", "contributions": 2}]),
+            "2023-03-01T00:00:00Z",
+        );
+        let health = assess(&abandoned, now);
+        assert_eq!(health.days_since_release, None);
+        assert_eq!(health.bus_factor, 1);
+        assert!(health.score < 0.3, "score {}", health.score);
+    }
+}
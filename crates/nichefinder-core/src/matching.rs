@@ -0,0 +1,151 @@
+//! Matching engine for linking free-text mentions to integration candidates
+
+use crate::transform::NormalizedIntegration;
+
+/// Terms too generic to identify a single integration on their own
+const STOP_TERMS: &[&str] = &[
+    "home assistant",
+    "homeassistant",
+    "hass",
+    "hacs",
+    "integration",
+    "component",
+    "sensor",
+    "custom",
+    "smart home",
+    "api",
+    "cloud",
+    "local",
+];
+
+/// Minimum length for a term to be considered a match on its own
+const MIN_TERM_LEN: usize = 3;
+
+/// A single match between a piece of text and an integration
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntegrationMatch {
+    /// Index of the matched integration in the slice the engine was built from
+    pub index: usize,
+
+    /// Match quality (0.0 - 1.0), based on which term matched
+    pub quality: f64,
+
+    /// The term that matched
+    pub term: String,
+}
+
+#[derive(Debug, Clone)]
+struct MatchTerm {
+    index: usize,
+    term: String,
+    quality: f64,
+}
+
+/// Matches text against integration names, domains and aliases using
+/// whole-word comparison, so "hue" does not match "hues".
+#[derive(Debug, Clone, Default)]
+pub struct MatchingEngine {
+    terms: Vec<MatchTerm>,
+}
+
+impl MatchingEngine {
+    /// Create an empty matching engine
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a matching engine from normalized integrations
+    ///
+    /// Names match with full quality, domains slightly lower since they are
+    /// often abbreviations (e.g. "tplink_omada").
+    pub fn from_integrations(integrations: &[NormalizedIntegration]) -> Self {
+        let mut engine = Self::new();
+
+        for (index, integration) in integrations.iter().enumerate() {
+            engine.add_term(index, &integration.name, 1.0);
+            if let Some(domain) = &integration.domain {
+                engine.add_term(index, domain, 0.9);
+            }
+        }
+
+        engine
+    }
+
+    /// Register an additional term for an integration
+    pub fn add_term(&mut self, index: usize, term: &str, quality: f64) {
+        let term = normalize_text(term);
+        if term.len() < MIN_TERM_LEN || STOP_TERMS.contains(&term.as_str()) {
+            return;
+        }
+
+        let exists = self.terms
+            .iter()
+            .any(|t| t.index == index && t.term == term);
+        if !exists {
+            self.terms.push(MatchTerm {
+                index,
+                term,
+                quality: quality.clamp(0.0, 1.0),
+            });
+        }
+    }
+
+    /// Find all integrations mentioned in the given text
+    ///
+    /// Returns at most one match per integration, keeping the best quality.
+    pub fn match_text(&self, text: &str) -> Vec<IntegrationMatch> {
+        let haystack = format!(" {} ", normalize_text(text));
+        let mut matches: Vec<IntegrationMatch> = Vec::new();
+
+        for term in &self.terms {
+            if !haystack.contains(&format!(" {} ", term.term)) {
+                continue;
+            }
+
+            match matches.iter_mut().find(|m| m.index == term.index) {
+                Some(existing) if existing.quality < term.quality => {
+                    existing.quality = term.quality;
+                    existing.term = term.term.clone();
+                }
+                Some(_) => {}
+                None => matches.push(IntegrationMatch {
+                    index: term.index,
+                    quality: term.quality,
+                    term: term.term.clone(),
+                }),
+            }
+        }
+
+        matches
+    }
+}
+
+/// Lowercase text and collapse everything that is not alphanumeric into
+/// single spaces, so "Philips_Hue" and "philips-hue" both become "philips hue"
+pub fn normalize_text(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whole_word_matching() {
+        let mut engine = MatchingEngine::new();
+        engine.add_term(0, "hue", 0.9);
+        engine.add_term(1, "Philips Hue", 1.0);
+        engine.add_term(2, "sensor", 1.0);
+
+        let matches = engine.match_text("Anyone got philips-hue working with Home Assistant?");
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().any(|m| m.index == 1 && m.quality == 1.0));
+
+        assert!(engine.match_text("Subtle hues of blue").is_empty());
+        assert!(engine.match_text("A new temperature sensor").is_empty());
+    }
+}
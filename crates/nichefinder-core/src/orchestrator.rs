@@ -0,0 +1,384 @@
+//! Concurrent collection across several data sources
+//!
+//! `CollectionOrchestrator` runs collectors with bounded parallelism and a
+//! timeout per source. A failing source never aborts the run: its status is
+//! recorded and analysis continues with the sources that succeeded.
+
+use crate::{
+    analysis::AnalysisInputs,
+    collectors::{CollectedData, DataCollector, PartialCollection},
+    types::{SourceProvenance, SourceState, SourceStatus},
+    Error, Result,
+};
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Collectors running at the same time by default
+pub const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// Per-source timeout unless overridden
+pub const DEFAULT_SOURCE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Runs data collectors concurrently
+pub struct CollectionOrchestrator {
+    collectors: Vec<Box<dyn DataCollector>>,
+    max_concurrency: usize,
+    default_timeout: Duration,
+    timeouts: HashMap<String, Duration>,
+}
+
+/// Records and per-source status of an orchestrated collection
+#[derive(Debug, Default)]
+pub struct CollectionRun {
+    /// Records from all sources that returned any
+    pub data: Vec<CollectedData>,
+
+    /// Status per source, in registration order
+    pub statuses: Vec<SourceStatus>,
+
+    /// Stored location of each source's records (data lake partition path)
+    pub artifacts: HashMap<String, String>,
+}
+
+impl CollectionOrchestrator {
+    /// Create an orchestrator with default concurrency and timeout
+    pub fn new() -> Self {
+        Self {
+            collectors: Vec::new(),
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            default_timeout: DEFAULT_SOURCE_TIMEOUT,
+            timeouts: HashMap::new(),
+        }
+    }
+
+    /// Set the maximum number of collectors running at once
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Set the timeout for sources without an explicit one
+    pub fn with_default_timeout(mut self, timeout: Duration) -> Self {
+        self.default_timeout = timeout;
+        self
+    }
+
+    /// Set the timeout for a source, by `DataCollector::source_name`
+    pub fn with_timeout(mut self, source: &str, timeout: Duration) -> Self {
+        self.timeouts.insert(source.to_string(), timeout);
+        self
+    }
+
+    /// Register a collector
+    pub fn add(&mut self, collector: impl DataCollector + 'static) {
+        self.collectors.push(Box::new(collector));
+    }
+
+    /// Number of registered collectors
+    pub fn len(&self) -> usize {
+        self.collectors.len()
+    }
+
+    /// Whether no collectors are registered
+    pub fn is_empty(&self) -> bool {
+        self.collectors.is_empty()
+    }
+
+    /// Run all collectors and gather their records and statuses
+    pub async fn run(&self) -> CollectionRun {
+        let results: Vec<(SourceStatus, Vec<CollectedData>)> = stream::iter(0..self.collectors.len())
+            .map(|index| self.run_one(index))
+            .buffered(self.max_concurrency)
+            .collect()
+            .await;
+
+        let mut run = CollectionRun::default();
+        for (status, data) in results {
+            run.statuses.push(status);
+            run.data.extend(data);
+        }
+        run
+    }
+
+    async fn run_one(&self, index: usize) -> (SourceStatus, Vec<CollectedData>) {
+        let collector = &self.collectors[index];
+        let source = collector.source_name().to_string();
+        let timeout = self.timeouts.get(&source).copied().unwrap_or(self.default_timeout);
+        let start = Instant::now();
+
+        tracing::info!("Collecting {}", source);
+        let collection = match tokio::time::timeout(timeout, collector.collect_partial()).await {
+            Ok(collection) => collection,
+            Err(_) => PartialCollection {
+                data: Vec::new(),
+                error: Some(Error::DataSource(format!("timed out after {}s", timeout.as_secs()))),
+            },
+        };
+
+        let state = match (&collection.error, collection.data.is_empty()) {
+            (None, _) => SourceState::Ok,
+            (Some(_), false) => SourceState::Partial,
+            (Some(_), true) => SourceState::Failed,
+        };
+        match &collection.error {
+            Some(e) => tracing::warn!("{} collection {:?}: {}", source, state, e),
+            None => tracing::info!("{} collected {} records", source, collection.data.len()),
+        }
+
+        let status = SourceStatus {
+            source,
+            state,
+            records: collection.data.len(),
+            query: collector.query(),
+            error: collection.error.map(|e| e.to_string()),
+            duration_secs: start.elapsed().as_secs_f64(),
+        };
+        (status, collection.data)
+    }
+}
+
+impl Default for CollectionOrchestrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CollectionRun {
+    /// Records collected from a source (by `CollectedData.source`)
+    pub fn records<'a>(&'a self, source: &'a str) -> impl Iterator<Item = &'a CollectedData> + 'a {
+        self.data.iter().filter(move |d| d.source == source)
+    }
+
+    /// Sources that produced usable records
+    pub fn succeeded(&self) -> Vec<String> {
+        self.statuses
+            .iter()
+            .filter(|s| s.state != SourceState::Failed)
+            .map(|s| s.source.clone())
+            .collect()
+    }
+
+    /// When the newest record was fetched; an analysis of a stored run is
+    /// evaluated as of this time
+    pub fn observed_at(&self) -> Option<DateTime<Utc>> {
+        self.data.iter().map(|d| d.collected_at).max()
+    }
+
+    /// Collection time, location, query and record count of a source
+    pub fn provenance(&self, source: &str) -> SourceProvenance {
+        SourceProvenance {
+            collected_at: self.records(source).map(|d| d.collected_at).max(),
+            artifact: self.artifacts.get(source).cloned(),
+            query: self.statuses.iter().find(|s| s.source == source).and_then(|s| s.query.clone()),
+            records: self.records(source).count(),
+        }
+    }
+
+    /// Newest copy of each record, in collection order
+    ///
+    /// A run loaded from a lake range holds one copy per stored run. Records
+    /// are identified by source, type and their `id`, `objectID`, `full_name`
+    /// or `domain` field; records without one are all kept.
+    fn latest_records(&self) -> Vec<&CollectedData> {
+        fn key(d: &CollectedData) -> Option<(&str, &str, String)> {
+            record_id(&d.raw_data).map(|id| (d.source.as_str(), d.data_type.as_str(), id))
+        }
+        let mut newest: HashMap<_, &CollectedData> = HashMap::new();
+        for record in &self.data {
+            if let Some(key) = key(record) {
+                let current = newest.entry(key).or_insert(record);
+                if record.collected_at >= current.collected_at {
+                    *current = record;
+                }
+            }
+        }
+        self.data
+            .iter()
+            .filter(|d| key(d).is_none_or(|key| std::ptr::eq(newest[&key], *d)))
+            .collect()
+    }
+
+    /// Write collected records as the data files the analyzer loads
+    ///
+    /// HACS is required; GitHub and YouTube files are written empty when
+    /// those sources are missing, and optional sources are only set when
+    /// they returned records. Only the newest copy of each record is written.
+    pub fn write_inputs(&self, dir: &Path) -> Result<AnalysisInputs> {
+        let latest = self.latest_records();
+        let records = |source: &str| latest.iter().copied().filter(|d| d.source == source).collect::<Vec<_>>();
+        let raw = |source: &str| records(source).into_iter().map(|d| d.raw_data.clone()).collect::<Vec<Value>>();
+
+        let hacs: serde_json::Map<String, Value> = records("hacs")
+            .into_iter()
+            .filter_map(|d| {
+                let id = d.raw_data.get("id").and_then(|id| id.as_str())
+                    .or_else(|| d.raw_data.get("full_name").and_then(|n| n.as_str()))?;
+                Some((id.to_string(), d.raw_data.clone()))
+            })
+            .collect();
+        if hacs.is_empty() {
+            return Err(Error::DataSource("No HACS integrations collected; nothing to analyze".to_string()));
+        }
+
+        let write = |name: &str, value: Value| -> Result<String> {
+            let path = dir.join(name);
+            std::fs::write(&path, serde_json::to_vec(&value)?)
+                .map_err(|e| Error::DataSource(format!("Failed to write {}: {}", path.display(), e)))?;
+            Ok(path.to_string_lossy().into_owned())
+        };
+
+        let github = raw("github");
+        let mut inputs = AnalysisInputs::new(
+            &write("hacs.json", Value::Object(hacs))?,
+            &write("github.json", serde_json::json!({
+                "total_count": github.len(),
+                "incomplete_results": false,
+                "items": github,
+            }))?,
+            &write("youtube.json", serde_json::json!({ "items": raw("youtube") }))?,
+        );
+
+        let hackernews = raw("hackernews");
+        if !hackernews.is_empty() {
+            inputs.hackernews_path = Some(write("hackernews.json", serde_json::json!({ "hits": hackernews }))?);
+        }
+
+        let reddit = raw("reddit");
+        if !reddit.is_empty() {
+            let children: Vec<Value> = reddit.into_iter().map(|post| serde_json::json!({ "kind": "t3", "data": post })).collect();
+            inputs.reddit_path = Some(write("reddit.json", serde_json::json!({
+                "kind": "Listing",
+                "data": { "children": children, "after": null, "before": null },
+            }))?);
+        }
+
+        let forum = raw("ha_community");
+        if !forum.is_empty() {
+            inputs.forum_path = Some(write("forum.json", Value::Array(forum))?);
+        }
+
+        let maintenance = raw("github_maintenance");
+        if !maintenance.is_empty() {
+            inputs.maintenance_path = Some(write("github_maintenance.json", Value::Array(maintenance))?);
+        }
+
+        let core = raw("ha_core");
+        if !core.is_empty() {
+            inputs.core_inventory_path = Some(write("ha_core.json", Value::Array(core))?);
+        }
+
+        // Analytics records are flattened per domain; rebuild the snapshot shape
+        let mut core_installs = serde_json::Map::new();
+        let mut custom_installs = serde_json::Map::new();
+        for record in records("ha_analytics") {
+            let (Some(domain), Some(installs)) = (
+                record.raw_data.get("domain").and_then(|d| d.as_str()),
+                record.raw_data.get("installs").cloned(),
+            ) else {
+                continue;
+            };
+            match record.data_type.as_str() {
+                "custom_installs" => {
+                    custom_installs.insert(domain.to_string(), serde_json::json!({ "total": installs }));
+                }
+                _ => {
+                    core_installs.insert(domain.to_string(), installs);
+                }
+            }
+        }
+        if !core_installs.is_empty() || !custom_installs.is_empty() {
+            inputs.analytics_path = Some(write("ha_analytics.json", serde_json::json!({
+                "integrations": core_installs,
+                "custom_integrations": custom_installs,
+            }))?);
+        }
+
+        for source in ["hacs", "github", "youtube", "hackernews", "reddit", "ha_community", "ha_core", "ha_analytics", "github_maintenance"] {
+            let count = records(source).len();
+            if count > 0 {
+                inputs.provenance.insert(source.to_string(), SourceProvenance { records: count, ..self.provenance(source) });
+            }
+        }
+
+        Ok(inputs)
+    }
+}
+
+/// Identifier of a raw record, across the shapes the collectors store
+fn record_id(raw: &Value) -> Option<String> {
+    ["id", "objectID", "full_name", "domain"]
+        .iter()
+        .find_map(|field| raw.get(field))
+        .map(|id| match id {
+            Value::String(id) => id.clone(),
+            other => other.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct MockCollector {
+        name: &'static str,
+        records: usize,
+        fail: bool,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl DataCollector for MockCollector {
+        async fn collect(&self) -> Result<Vec<CollectedData>> {
+            Err(Error::DataSource("use collect_partial".to_string()))
+        }
+
+        async fn collect_partial(&self) -> PartialCollection {
+            tokio::time::sleep(self.delay).await;
+            let data = (0..self.records)
+                .map(|i| CollectedData {
+                    source: self.name.to_string(),
+                    data_type: "item".to_string(),
+                    raw_data: serde_json::json!({ "id": i }),
+                    collected_at: chrono::Utc::now(),
+                })
+                .collect();
+            let error = self.fail.then(|| Error::DataSource("rate limited".to_string()));
+            PartialCollection { data, error }
+        }
+
+        fn source_name(&self) -> &str {
+            self.name
+        }
+    }
+
+    #[tokio::test]
+    async fn test_partial_failure_tolerance() {
+        let mut orchestrator = CollectionOrchestrator::new()
+            .with_max_concurrency(2)
+            .with_timeout("slow", Duration::from_millis(20));
+        let mock = |name, records, fail, delay_ms| MockCollector {
+            name,
+            records,
+            fail,
+            delay: Duration::from_millis(delay_ms),
+        };
+        orchestrator.add(mock("healthy", 3, false, 0));
+        orchestrator.add(mock("flaky", 2, true, 0));
+        orchestrator.add(mock("down", 0, true, 0));
+        orchestrator.add(mock("slow", 5, false, 500));
+
+        let run = orchestrator.run().await;
+
+        let states: Vec<SourceState> = run.statuses.iter().map(|s| s.state).collect();
+        assert_eq!(states, vec![SourceState::Ok, SourceState::Partial, SourceState::Failed, SourceState::Failed]);
+        assert_eq!(run.data.len(), 5);
+        assert_eq!(run.records("flaky").count(), 2);
+        assert!(run.statuses[3].error.as_deref().unwrap().contains("timed out"));
+        assert_eq!(run.succeeded(), vec!["healthy", "flaky"]);
+    }
+}
@@ -0,0 +1,904 @@
+//! Composable analysis pipeline
+//!
+//! An analysis runs as explicit stages: a `Normalizer` loads the raw inputs
+//! into candidates, a chain of `Enricher`s adds features (and candidates),
+//! forecasts and breakouts from snapshot history, the scorer rates every
+//! candidate, `PostFilter`s label (lifecycle stage) and prune the ranked list
+//! and `Reporter`s publish the result. `IntegrationAnalyzer` runs the
+//! standard stages; `AnalysisPipeline::builder` adds or replaces stages so a
+//! new signal does not require changes to `analysis.rs`.
+//!
+//! ```no_run
+//! use nichefinder_core::pipeline::AnalysisPipeline;
+//! use nichefinder_core::{AnalysisConfig, AnalysisInputs, NicheOpportunity, OpportunityKind};
+//!
+//! let pipeline = AnalysisPipeline::builder(AnalysisConfig::default())
+//!     .without_enricher("protocol_only")
+//!     .without_enricher("breakouts")
+//!     .with_filter(|o: &NicheOpportunity| o.kind != OpportunityKind::PortedElsewhere)
+//!     .build()?;
+//! let inputs = AnalysisInputs::new("hacs.json", "github.json", "youtube.json");
+//! let result = pipeline.run(&inputs)?;
+//! # Ok::<(), nichefinder_core::Error>(())
+//! ```
+
+use crate::{
+    analysis::{scoring_signals, AnalysisInputs, IntegrationAnalyzer},
+    breakouts::{Breakout, BreakoutCandidate, BreakoutDetector},
+    collectors::{CoreIntegrationManifest, HomebridgeCollector, OpenHabCollector, RepoActivity},
+    custom_sources::{apply_custom_signals, FileCollector},
+    device_catalogs::{
+        find_protocol_only_vendors, load_matter_products, load_zigbee2mqtt_devices, load_zwave_js_devices,
+        DeviceProtocol, MIN_PROTOCOL_DEVICES,
+    },
+    ecosystems::find_ported_elsewhere,
+    enrichment::{enrich_with_pypi, load_pypi_dump},
+    feature_gaps::{enrich_with_feature_gaps, GapMiner},
+    forecast::{history_key, HoltForecaster},
+    lifecycle::{LifecycleClassifier, LifecycleStage},
+    maintenance::{enrich_with_maintenance, load_maintenance_data},
+    reporting::{DefaultReportGenerator, ReportFormat, ReportGenerator},
+    scoring::OpportunityScorer,
+    transform::{
+        addressable_users, apply_core_coverage, apply_forum_signals, apply_hackernews_signals, apply_install_counts,
+        apply_reddit_signals, load_analytics_data, load_core_inventory_data, load_forum_data, load_github_data,
+        load_hacs_data, load_hackernews_data, load_reddit_data, load_youtube_data, normalize_integrations,
+        NormalizedIntegration,
+    },
+    types::{AnalysisConfig, AnalysisResult, NicheOpportunity, OpportunityKind, SourceProvenance},
+    Error, Result,
+};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// What the base demand signal of an integration is derived from
+///
+/// Ordered by strength; each integration uses the strongest signal it has.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DemandBasis {
+    /// GitHub stars (proxy when no request or install data is available)
+    #[default]
+    Stars,
+
+    /// Active installations from HA analytics
+    Installs,
+
+    /// Forum feature-request votes and topics
+    Requests,
+}
+
+impl DemandBasis {
+    /// Strongest basis the integration has a signal for
+    pub fn for_integration(integration: &NormalizedIntegration) -> Self {
+        if integration.forum_topics > 0 {
+            Self::Requests
+        } else if addressable_users(integration).is_some() {
+            Self::Installs
+        } else {
+            Self::Stars
+        }
+    }
+}
+
+/// State shared by the stages of one analysis run
+pub struct PipelineContext<'a> {
+    /// Analysis configuration
+    pub config: &'a AnalysisConfig,
+
+    /// Raw input files
+    pub inputs: &'a AnalysisInputs,
+
+    /// Time the inputs are evaluated at (`AnalysisInputs::as_of`, else the run start)
+    pub now: DateTime<Utc>,
+
+    /// Official core manifests, once loaded by the core coverage stage
+    pub core_manifests: Vec<CoreIntegrationManifest>,
+
+    /// Repository activity of existing integrations, once loaded by the
+    /// maintenance stage
+    pub repo_activity: Vec<RepoActivity>,
+
+    /// Breakouts across every candidate, once detected by the breakout stage
+    pub breakouts: Vec<Breakout>,
+
+    window_start: Option<DateTime<Utc>>,
+    sources_used: Vec<String>,
+    provenance: BTreeMap<String, SourceProvenance>,
+}
+
+impl<'a> PipelineContext<'a> {
+    /// Create the context for a run
+    pub fn new(config: &'a AnalysisConfig, inputs: &'a AnalysisInputs) -> Self {
+        let now = inputs.as_of.unwrap_or_else(Utc::now);
+        Self {
+            config,
+            inputs,
+            now,
+            core_manifests: Vec::new(),
+            repo_activity: Vec::new(),
+            breakouts: Vec::new(),
+            window_start: config.window_start(now),
+            sources_used: Vec::new(),
+            provenance: BTreeMap::new(),
+        }
+    }
+
+    /// Whether a source is enabled; enabled sources are reported as used
+    ///
+    /// Custom sources are also enabled by the `custom` key.
+    pub fn use_source(&mut self, source: &str) -> bool {
+        let custom = self.inputs.custom_sources.iter().any(|c| c.name == source);
+        let enabled = self.config.source_enabled(source)
+            || (custom && self.config.source_enabled("custom") && !self.config.source_disabled(source));
+        if enabled && !self.sources_used.iter().any(|s| s == source) {
+            self.sources_used.push(source.to_string());
+        }
+        enabled
+    }
+
+    /// Record where a loaded source came from
+    ///
+    /// Caller-supplied provenance wins; missing fields fall back to the file
+    /// path, its modification time and the number of records read.
+    pub fn record_provenance(&mut self, source: &str, path: &str, records: usize) {
+        let mut provenance = self.inputs.provenance.get(source).cloned().unwrap_or_default();
+        if provenance.artifact.is_none() {
+            provenance.artifact = Some(path.to_string());
+        }
+        if provenance.collected_at.is_none() {
+            provenance.collected_at = std::fs::metadata(path)
+                .and_then(|m| m.modified())
+                .ok()
+                .map(DateTime::<Utc>::from);
+        }
+        if provenance.records == 0 {
+            provenance.records = records;
+        }
+        self.provenance.insert(source.to_string(), provenance);
+    }
+
+    /// Recorded provenance of a source
+    pub fn provenance_mut(&mut self, source: &str) -> Option<&mut SourceProvenance> {
+        self.provenance.get_mut(source)
+    }
+
+    /// Whether a signal falls inside the analysis window; undated signals are kept
+    pub fn in_window(&self, timestamp: Option<DateTime<Utc>>) -> bool {
+        match (timestamp, self.window_start) {
+            (Some(timestamp), Some(start)) => timestamp >= start,
+            _ => true,
+        }
+    }
+
+    /// Sources used so far, in load order
+    pub fn sources_used(&self) -> &[String] {
+        &self.sources_used
+    }
+
+    /// What the run recorded, for scoring and the result
+    pub(crate) fn finish(self) -> FinishedRun {
+        FinishedRun {
+            now: self.now,
+            sources_used: self.sources_used,
+            provenance: self.provenance,
+            breakouts: self.breakouts,
+        }
+    }
+}
+
+/// State a pipeline run hands to scoring once every stage has run
+pub(crate) struct FinishedRun {
+    pub now: DateTime<Utc>,
+    pub sources_used: Vec<String>,
+    /// Provenance of every loaded source, by source key
+    pub provenance: BTreeMap<String, SourceProvenance>,
+    pub breakouts: Vec<Breakout>,
+}
+
+/// Loads raw inputs and builds the initial candidate list
+pub trait Normalizer: Send + Sync {
+    /// Build candidates from the context's inputs
+    fn normalize(&self, ctx: &mut PipelineContext) -> Result<Vec<NormalizedIntegration>>;
+}
+
+/// Adds features (or candidates) to normalized integrations
+pub trait Enricher: Send + Sync {
+    /// Stage name, used to remove or replace standard stages
+    fn name(&self) -> &str;
+
+    /// Enrich the candidates in place
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()>;
+}
+
+/// Labels and drops scored opportunities in the ranked list
+pub trait PostFilter: Send + Sync {
+    /// Label an opportunity; every filter annotates before any filter decides
+    fn annotate(&self, _opportunity: &mut NicheOpportunity) {}
+
+    /// Whether to keep an opportunity
+    fn keep(&self, opportunity: &NicheOpportunity) -> bool;
+}
+
+impl<F> PostFilter for F
+where
+    F: Fn(&NicheOpportunity) -> bool + Send + Sync,
+{
+    fn keep(&self, opportunity: &NicheOpportunity) -> bool {
+        self(opportunity)
+    }
+}
+
+/// Publishes a finished analysis
+pub trait Reporter: Send + Sync {
+    /// Publish the result
+    fn report(&self, result: &AnalysisResult) -> Result<()>;
+}
+
+/// Parse an RFC 3339 timestamp from raw source data
+fn parse_time(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw).ok().map(|dt| dt.with_timezone(&Utc))
+}
+
+/// HACS integrations joined with GitHub repositories and YouTube mentions
+pub struct InputNormalizer;
+
+impl Normalizer for InputNormalizer {
+    fn normalize(&self, ctx: &mut PipelineContext) -> Result<Vec<NormalizedIntegration>> {
+        let inputs = ctx.inputs;
+
+        let hacs_data = if ctx.use_source("hacs") {
+            let data = load_hacs_data(&inputs.hacs_path)?;
+            ctx.record_provenance("hacs", &inputs.hacs_path, data.len());
+            data
+        } else {
+            Default::default()
+        };
+        let github_data = if ctx.use_source("github") {
+            let data = load_github_data(&inputs.github_path)?;
+            ctx.record_provenance("github", &inputs.github_path, data.len());
+            data
+        } else {
+            Vec::new()
+        };
+        let mut youtube_data = if ctx.use_source("youtube") {
+            let data = load_youtube_data(&inputs.youtube_path)?;
+            ctx.record_provenance("youtube", &inputs.youtube_path, data.len());
+            data
+        } else {
+            Vec::new()
+        };
+        youtube_data.retain(|video| ctx.in_window(parse_time(&video.snippet.published_at)));
+
+        Ok(normalize_integrations(hacs_data, github_data, youtube_data)?)
+    }
+}
+
+/// Official core coverage; later stages use the loaded manifests
+pub struct CoreCoverageEnricher;
+
+impl Enricher for CoreCoverageEnricher {
+    fn name(&self) -> &str {
+        "core_coverage"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        if let Some(path) = inputs.core_inventory_path.as_deref().filter(|_| ctx.use_source("ha_core")) {
+            ctx.core_manifests = load_core_inventory_data(path)?;
+            ctx.record_provenance("ha_core", path, ctx.core_manifests.len());
+        }
+        apply_core_coverage(integrations, &ctx.core_manifests);
+        Ok(())
+    }
+}
+
+/// Devices other ecosystems support but Home Assistant doesn't
+pub struct PortedElsewhereEnricher;
+
+impl Enricher for PortedElsewhereEnricher {
+    fn name(&self) -> &str {
+        "ported_elsewhere"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        let homebridge = match inputs.homebridge_path.as_deref().filter(|_| ctx.use_source("homebridge")) {
+            Some(path) => {
+                let plugins = HomebridgeCollector::from_dump(path).fetch_plugins()?;
+                ctx.record_provenance("homebridge", path, plugins.len());
+                plugins
+            }
+            None => Vec::new(),
+        };
+        let openhab = match inputs.openhab_path.as_deref().filter(|_| ctx.use_source("openhab")) {
+            Some(path) => {
+                let bindings = OpenHabCollector::from_dump(path).fetch_bindings()?;
+                ctx.record_provenance("openhab", path, bindings.len());
+                bindings
+            }
+            None => Vec::new(),
+        };
+
+        if !homebridge.is_empty() || !openhab.is_empty() {
+            let ported = find_ported_elsewhere(integrations, &ctx.core_manifests, &homebridge, &openhab);
+            integrations.extend(ported);
+        }
+        Ok(())
+    }
+}
+
+/// Vendors only reachable through generic Zigbee/Z-Wave/Matter support
+pub struct ProtocolOnlyEnricher;
+
+impl Enricher for ProtocolOnlyEnricher {
+    fn name(&self) -> &str {
+        "protocol_only"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        let mut devices = Vec::new();
+        for (protocol, path) in [
+            (DeviceProtocol::Zigbee, &inputs.zigbee_path),
+            (DeviceProtocol::ZWave, &inputs.zwave_path),
+            (DeviceProtocol::Matter, &inputs.matter_path),
+        ] {
+            let Some(path) = path.as_deref().filter(|_| ctx.use_source(protocol.source_key())) else {
+                continue;
+            };
+            let catalog = match protocol {
+                DeviceProtocol::Zigbee => load_zigbee2mqtt_devices(path)?,
+                DeviceProtocol::ZWave => load_zwave_js_devices(path)?,
+                DeviceProtocol::Matter => load_matter_products(path)?,
+            };
+            ctx.record_provenance(protocol.source_key(), path, catalog.len());
+            devices.extend(catalog);
+        }
+
+        if !devices.is_empty() {
+            let vendors = find_protocol_only_vendors(integrations, &ctx.core_manifests, &devices, MIN_PROTOCOL_DEVICES);
+            integrations.extend(vendors);
+        }
+        Ok(())
+    }
+}
+
+/// Install counts from HA analytics (after core coverage so covering
+/// integrations get counts too)
+pub struct InstallCountEnricher;
+
+impl Enricher for InstallCountEnricher {
+    fn name(&self) -> &str {
+        "install_counts"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        let Some(path) = inputs.analytics_path.as_deref().filter(|_| ctx.use_source("ha_analytics")) else {
+            return Ok(());
+        };
+
+        let snapshot = load_analytics_data(path)?;
+        ctx.record_provenance("ha_analytics", path, snapshot.integrations.len() + snapshot.custom_integrations.len());
+        // The snapshot's own timestamp beats the file time when no provenance was given
+        let updated = snapshot.last_updated.and_then(DateTime::from_timestamp_millis);
+        if let (Some(updated), false) = (updated, inputs.provenance.contains_key("ha_analytics")) {
+            if let Some(provenance) = ctx.provenance_mut("ha_analytics") {
+                provenance.collected_at = Some(updated);
+            }
+        }
+
+        apply_install_counts(integrations, &snapshot);
+        Ok(())
+    }
+}
+
+/// Vendor library feasibility (after core coverage, which contributes requirements)
+pub struct PypiEnricher;
+
+impl Enricher for PypiEnricher {
+    fn name(&self) -> &str {
+        "pypi"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        if let Some(path) = inputs.pypi_path.as_deref().filter(|_| ctx.use_source("pypi")) {
+            let index = load_pypi_dump(path)?;
+            ctx.record_provenance("pypi", path, index.len());
+            enrich_with_pypi(integrations, &index, ctx.now);
+        }
+        Ok(())
+    }
+}
+
+/// Maintenance health of existing integrations, which weakens the
+/// competition of neglected ones
+pub struct MaintenanceEnricher;
+
+impl Enricher for MaintenanceEnricher {
+    fn name(&self) -> &str {
+        "maintenance"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        if let Some(path) = inputs.maintenance_path.as_deref().filter(|_| ctx.use_source("github_maintenance")) {
+            ctx.repo_activity = load_maintenance_data(path)?;
+            ctx.record_provenance("github_maintenance", path, ctx.repo_activity.len());
+        }
+        enrich_with_maintenance(integrations, &ctx.repo_activity, ctx.now);
+        Ok(())
+    }
+}
+
+/// Unmet feature requests from the repository activity the maintenance
+/// stage loaded, kept as differentiation opportunities
+pub struct FeatureGapEnricher;
+
+impl Enricher for FeatureGapEnricher {
+    fn name(&self) -> &str {
+        "feature_gaps"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        enrich_with_feature_gaps(integrations, &ctx.repo_activity, &GapMiner::new());
+        Ok(())
+    }
+}
+
+/// Hacker News discussion
+pub struct HackerNewsEnricher;
+
+impl Enricher for HackerNewsEnricher {
+    fn name(&self) -> &str {
+        "hackernews"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        if let Some(path) = inputs.hackernews_path.as_deref().filter(|_| ctx.use_source("hackernews")) {
+            let mut hits = load_hackernews_data(path)?;
+            ctx.record_provenance("hackernews", path, hits.len());
+            hits.retain(|hit| {
+                let created = hit.created_at_i
+                    .and_then(|secs| DateTime::from_timestamp(secs, 0))
+                    .or_else(|| parse_time(&hit.created_at));
+                ctx.in_window(created)
+            });
+            apply_hackernews_signals(integrations, &hits);
+        }
+        Ok(())
+    }
+}
+
+/// Reddit discussion
+pub struct RedditEnricher;
+
+impl Enricher for RedditEnricher {
+    fn name(&self) -> &str {
+        "reddit"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        if let Some(path) = inputs.reddit_path.as_deref().filter(|_| ctx.use_source("reddit")) {
+            let mut posts = load_reddit_data(path)?;
+            ctx.record_provenance("reddit", path, posts.len());
+            posts.retain(|post| ctx.in_window(DateTime::from_timestamp(post.created_utc as i64, 0)));
+            apply_reddit_signals(integrations, &posts);
+        }
+        Ok(())
+    }
+}
+
+/// Internal CSV/JSON Lines sources counted as weighted demand
+pub struct CustomSourceEnricher;
+
+impl Enricher for CustomSourceEnricher {
+    fn name(&self) -> &str {
+        "custom_sources"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        for config in &inputs.custom_sources {
+            if !ctx.use_source(&config.name) {
+                continue;
+            }
+            let mut signals = FileCollector::new(config.clone()).read_signals()?;
+            ctx.record_provenance(&config.name, &config.path.to_string_lossy(), signals.len());
+            signals.retain(|signal| ctx.in_window(signal.timestamp));
+            apply_custom_signals(integrations, &config.name, config.weight, &signals);
+        }
+        Ok(())
+    }
+}
+
+/// Forum feature requests; votes are direct asks, so matched integrations use them as demand basis
+pub struct ForumEnricher;
+
+impl Enricher for ForumEnricher {
+    fn name(&self) -> &str {
+        "forum"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let inputs = ctx.inputs;
+        if let Some(path) = inputs.forum_path.as_deref().filter(|_| ctx.use_source("ha_community")) {
+            let mut topics = load_forum_data(path)?;
+            ctx.record_provenance("ha_community", path, topics.len());
+            topics.retain(|topic| {
+                let active = topic.last_posted_at.as_deref().or(topic.created_at.as_deref());
+                ctx.in_window(active.and_then(parse_time))
+            });
+            apply_forum_signals(integrations, &topics);
+        }
+        Ok(())
+    }
+}
+
+/// Forecasts of stars, mentions and installs from snapshot history
+///
+/// Runs after the signal enrichers so the current values are complete; the
+/// star forecast replaces the lifetime estimate of the growth rate.
+pub struct ForecastEnricher;
+
+impl Enricher for ForecastEnricher {
+    fn name(&self) -> &str {
+        "forecasts"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let Some(history) = ctx.inputs.history.as_ref() else {
+            return Ok(());
+        };
+        let forecaster = HoltForecaster::new().with_horizon_days(ctx.config.forecast_horizon_days);
+        for integration in integrations.iter_mut() {
+            let key = history_key(integration.kind, &integration.name);
+            let signals = scoring_signals(integration, ctx.now);
+            integration.forecasts = history.forecast(&key, ctx.now, &signals, &forecaster);
+        }
+        Ok(())
+    }
+}
+
+/// Signal breakouts across every candidate, not only the qualified ones
+pub struct BreakoutEnricher;
+
+impl Enricher for BreakoutEnricher {
+    fn name(&self) -> &str {
+        "breakouts"
+    }
+
+    fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, ctx: &mut PipelineContext) -> Result<()> {
+        let Some(history) = ctx.inputs.history.as_ref() else {
+            return Ok(());
+        };
+        let signals: Vec<_> = integrations.iter().map(|i| scoring_signals(i, ctx.now)).collect();
+        let candidates: Vec<BreakoutCandidate> = integrations
+            .iter()
+            .zip(&signals)
+            .map(|(integration, signals)| BreakoutCandidate {
+                kind: integration.kind,
+                name: &integration.name,
+                vendor: (integration.kind == OpportunityKind::ProtocolOnly).then_some(integration.name.as_str()),
+                signals,
+            })
+            .collect();
+        ctx.breakouts = BreakoutDetector::new().detect(history, &candidates, ctx.now);
+        Ok(())
+    }
+}
+
+/// Built-in enrichers, in the order they must run
+pub fn standard_enrichers() -> Vec<Box<dyn Enricher>> {
+    vec![
+        Box::new(CoreCoverageEnricher),
+        Box::new(PortedElsewhereEnricher),
+        Box::new(ProtocolOnlyEnricher),
+        Box::new(InstallCountEnricher),
+        Box::new(PypiEnricher),
+        Box::new(MaintenanceEnricher),
+        Box::new(FeatureGapEnricher),
+        Box::new(HackerNewsEnricher),
+        Box::new(RedditEnricher),
+        Box::new(CustomSourceEnricher),
+        Box::new(ForumEnricher),
+        Box::new(ForecastEnricher),
+        Box::new(BreakoutEnricher),
+    ]
+}
+
+/// Labels each opportunity with its lifecycle stage and keeps the stages
+/// enabled in `AnalysisConfig::lifecycle_stages`
+///
+/// Unclassified opportunities only pass when no stage is selected.
+pub struct LifecycleFilter {
+    classifier: LifecycleClassifier,
+    stages: Vec<LifecycleStage>,
+}
+
+impl LifecycleFilter {
+    /// Filter with the default classifier, keeping `stages` (all when empty)
+    pub fn new(stages: Vec<LifecycleStage>) -> Self {
+        Self {
+            classifier: LifecycleClassifier::new(),
+            stages,
+        }
+    }
+
+    /// Use a classifier with other thresholds
+    pub fn with_classifier(mut self, classifier: LifecycleClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+}
+
+impl PostFilter for LifecycleFilter {
+    fn annotate(&self, opportunity: &mut NicheOpportunity) {
+        opportunity.lifecycle = opportunity
+            .features
+            .as_ref()
+            .and_then(|features| self.classifier.classify(features, &opportunity.forecasts));
+    }
+
+    fn keep(&self, opportunity: &NicheOpportunity) -> bool {
+        match opportunity.lifecycle {
+            Some(stage) => self.stages.is_empty() || self.stages.contains(&stage),
+            None => self.stages.is_empty(),
+        }
+    }
+}
+
+/// Built-in post-filters for a configuration
+pub fn standard_filters(config: &AnalysisConfig) -> Vec<Box<dyn PostFilter>> {
+    vec![Box::new(LifecycleFilter::new(config.lifecycle_stages.clone()))]
+}
+
+/// Writes the result to a file with the default report generator
+pub struct FileReporter {
+    path: PathBuf,
+    format: ReportFormat,
+}
+
+impl FileReporter {
+    /// Create a reporter writing `format` to `path`
+    pub fn new(path: impl Into<PathBuf>, format: ReportFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+        }
+    }
+}
+
+impl Reporter for FileReporter {
+    fn report(&self, result: &AnalysisResult) -> Result<()> {
+        let report = DefaultReportGenerator::new().generate(result, self.format)?;
+        std::fs::write(&self.path, report)
+            .map_err(|e| Error::Reporting(format!("Failed to write {}: {}", self.path.display(), e)))
+    }
+}
+
+/// An analysis assembled from stages
+pub struct AnalysisPipeline {
+    analyzer: IntegrationAnalyzer,
+    normalizer: Box<dyn Normalizer>,
+    enrichers: Vec<Box<dyn Enricher>>,
+    filters: Vec<Box<dyn PostFilter>>,
+    reporters: Vec<Box<dyn Reporter>>,
+}
+
+/// Builder for `AnalysisPipeline`, starting from the standard stages
+pub struct PipelineBuilder {
+    config: AnalysisConfig,
+    normalizer: Box<dyn Normalizer>,
+    enrichers: Vec<Box<dyn Enricher>>,
+    missing_enrichers: Vec<String>,
+    scorer: Option<Box<dyn OpportunityScorer>>,
+    filters: Vec<Box<dyn PostFilter>>,
+    reporters: Vec<Box<dyn Reporter>>,
+}
+
+impl AnalysisPipeline {
+    /// Start a pipeline with the standard normalizer, enrichers, scorer and filters
+    pub fn builder(config: AnalysisConfig) -> PipelineBuilder {
+        PipelineBuilder {
+            filters: standard_filters(&config),
+            config,
+            normalizer: Box::new(InputNormalizer),
+            enrichers: standard_enrichers(),
+            missing_enrichers: Vec::new(),
+            scorer: None,
+            reporters: Vec::new(),
+        }
+    }
+
+    /// Run all stages, then hand the result to every reporter
+    pub fn run(&self, inputs: &AnalysisInputs) -> Result<AnalysisResult> {
+        let result = self.analyzer.analyze_with_stages(inputs, self.normalizer.as_ref(), &self.enrichers, &self.filters)?;
+        for reporter in &self.reporters {
+            reporter.report(&result)?;
+        }
+        Ok(result)
+    }
+}
+
+impl PipelineBuilder {
+    /// Replace the normalizer
+    pub fn with_normalizer(mut self, normalizer: impl Normalizer + 'static) -> Self {
+        self.normalizer = Box::new(normalizer);
+        self
+    }
+
+    /// Append an enricher after the existing ones
+    pub fn with_enricher(mut self, enricher: impl Enricher + 'static) -> Self {
+        self.enrichers.push(Box::new(enricher));
+        self
+    }
+
+    /// Insert an enricher before the one named `before`
+    ///
+    /// `build` fails if there is no enricher of that name.
+    pub fn with_enricher_before(mut self, before: &str, enricher: impl Enricher + 'static) -> Self {
+        match self.enrichers.iter().position(|e| e.name() == before) {
+            Some(index) => self.enrichers.insert(index, Box::new(enricher)),
+            None => self.missing_enrichers.push(before.to_string()),
+        }
+        self
+    }
+
+    /// Replace the enricher named `name`, keeping its position
+    ///
+    /// `build` fails if there is no enricher of that name.
+    pub fn replace_enricher(mut self, name: &str, enricher: impl Enricher + 'static) -> Self {
+        match self.enrichers.iter().position(|e| e.name() == name) {
+            Some(index) => self.enrichers[index] = Box::new(enricher),
+            None => self.missing_enrichers.push(name.to_string()),
+        }
+        self
+    }
+
+    /// Remove enrichers by `Enricher::name`
+    pub fn without_enricher(mut self, name: &str) -> Self {
+        self.enrichers.retain(|e| e.name() != name);
+        self
+    }
+
+    /// Replace the scorer (default: the one named by `AnalysisConfig.scorer`)
+    pub fn with_scorer(mut self, scorer: impl OpportunityScorer + 'static) -> Self {
+        self.scorer = Some(Box::new(scorer));
+        self
+    }
+
+    /// Add a post-filter; opportunities must pass every filter
+    pub fn with_filter(mut self, filter: impl PostFilter + 'static) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Add a reporter
+    pub fn with_reporter(mut self, reporter: impl Reporter + 'static) -> Self {
+        self.reporters.push(Box::new(reporter));
+        self
+    }
+
+    /// Assemble the pipeline
+    ///
+    /// Fails when an enricher to insert before or replace does not exist, or
+    /// when no scorer was set and the scoring script named in the
+    /// configuration cannot be loaded.
+    pub fn build(self) -> Result<AnalysisPipeline> {
+        if !self.missing_enrichers.is_empty() {
+            return Err(Error::Config(format!("No enricher named {}", self.missing_enrichers.join(", "))));
+        }
+        let analyzer = match self.scorer {
+            Some(scorer) => IntegrationAnalyzer::with_scorer(self.config, scorer),
+            None => IntegrationAnalyzer::from_config(self.config)?,
+        };
+        Ok(AnalysisPipeline {
+            analyzer,
+            normalizer: self.normalizer,
+            enrichers: self.enrichers,
+            filters: self.filters,
+            reporters: self.reporters,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Fixtures;
+    use crate::types::OpportunityKind;
+    use std::sync::Mutex;
+
+    /// Adds a fixed number of stars per integration
+    struct StarBoost(u32);
+
+    impl Enricher for StarBoost {
+        fn name(&self) -> &str {
+            "star_boost"
+        }
+
+        fn enrich(&self, integrations: &mut Vec<NormalizedIntegration>, _ctx: &mut PipelineContext) -> Result<()> {
+            for integration in integrations.iter_mut() {
+                integration.stars += self.0;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct Collect(Mutex<Vec<String>>);
+
+    impl Reporter for std::sync::Arc<Collect> {
+        fn report(&self, result: &AnalysisResult) -> Result<()> {
+            let mut names = self.0.lock().unwrap();
+            names.extend(result.opportunities.iter().map(|o| o.name.clone()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_custom_stages() {
+        let fixtures = Fixtures::new();
+        let write = |name: &str, value: serde_json::Value| fixtures.write(name, value);
+        let inputs = AnalysisInputs::new(
+            &write("hacs.json", serde_json::json!({
+                "1": { "domain": "tado", "full_name": "user/tado", "stargazers_count": 1 },
+                "2": { "domain": "miele", "full_name": "user/miele", "stargazers_count": 1 },
+            })),
+            &write("github.json", serde_json::json!({ "total_count": 0, "incomplete_results": false, "items": [] })),
+            &write("youtube.json", serde_json::json!({ "items": [] })),
+        );
+        let config = AnalysisConfig {
+            min_score: 0.0,
+            ..Default::default()
+        };
+
+        let reporter = std::sync::Arc::new(Collect::default());
+        let result = AnalysisPipeline::builder(config.clone())
+            .with_enricher(StarBoost(500))
+            .with_filter(|o: &NicheOpportunity| o.name != "miele")
+            .with_reporter(reporter.clone())
+            .build()
+            .unwrap()
+            .run(&inputs)
+            .unwrap();
+
+        assert_eq!(result.opportunities.len(), 1);
+        assert_eq!(result.opportunities[0].metadata["stars"], 501);
+        assert_eq!(result.opportunities[0].kind, OpportunityKind::CommunityIntegration);
+        assert_eq!(*reporter.0.lock().unwrap(), vec!["tado"]);
+
+        // Without the extra stage, the standard pipeline matches the analyzer
+        let result = AnalysisPipeline::builder(config.clone()).build().unwrap().run(&inputs).unwrap();
+        assert_eq!(result.opportunities.len(), 2);
+        assert_eq!(result.opportunities[0].metadata["stars"], 1);
+        
+        // Stages are inserted and replaced by name
+        let pipeline = AnalysisPipeline::builder(config.clone())
+            .with_enricher_before("core_coverage", StarBoost(100))
+            .replace_enricher("forum", StarBoost(10))
+            .build()
+            .unwrap();
+        let names: Vec<&str> = pipeline.enrichers.iter().map(|e| e.name()).collect();
+        assert_eq!(names[0], "star_boost");
+        assert_eq!(names.iter().filter(|&&n| n == "star_boost").count(), 2);
+        assert!(!names.contains(&"forum"));
+        assert_eq!(&names[names.len() - 2..], ["forecasts", "breakouts"]);
+        let result = pipeline.run(&inputs).unwrap();
+        assert_eq!(result.opportunities[0].metadata["stars"], 111);
+        assert!(AnalysisPipeline::builder(config.clone()).replace_enricher("nope", StarBoost(1)).build().is_err());
+        
+        // Lifecycle stages are labeled and filtered by a post-filter stage
+        let emerging_only = AnalysisConfig {
+            lifecycle_stages: vec![LifecycleStage::Emerging],
+            ..config
+        };
+        let result = AnalysisPipeline::builder(emerging_only).build().unwrap().run(&inputs).unwrap();
+        assert!(result.opportunities.is_empty(), "undated candidates are unclassified");
+    }
+}
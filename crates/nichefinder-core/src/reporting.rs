@@ -53,6 +53,9 @@ impl DefaultReportGenerator {
         for (idx, opp) in result.opportunities.iter().enumerate() {
             md.push_str(&format!("### {}. {} (Score: {:.1})\n\n", idx + 1, opp.name, opp.score));
             md.push_str(&format!("**Category:** {}\n\n", opp.category));
+            if let Some(stage) = opp.lifecycle {
+                md.push_str(&format!("**Lifecycle:** {}\n\n", stage.as_str()));
+            }
             
             md.push_str("**Scoring Breakdown:**\n");
             md.push_str(&format!("- Demand: {:.1}\n", opp.scoring_details.demand));
@@ -60,16 +63,79 @@ impl DefaultReportGenerator {
             md.push_str(&format!("- Competition: {:.1}\n", opp.scoring_details.competition));
             md.push_str(&format!("- Trend: {:.1}\n\n", opp.scoring_details.trend));
             
-            md.push_str(&format!("**Data Sources:** {}\n\n", 
-                opp.data_sources.iter()
-                    .map(|s| s.name.as_str())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            ));
+            if !opp.scorer_results.is_empty() {
+                md.push_str(&format!("**Scorers ({}):** {}\n\n", result.config.ensemble_method.as_str(), scorer_summary(opp)));
+            }
+            
+            if !opp.forecasts.is_empty() {
+                md.push_str(&format!("**Forecast ({}d):** {}\n\n", result.config.forecast_horizon_days, forecast_summary(opp)));
+            }
+            
+            md.push_str("**Data Sources:**\n");
+            for source in &opp.data_sources {
+                md.push_str(&format!("- {} (collected {}, {} days old)\n",
+                    source.name,
+                    source.collected_at.format("%Y-%m-%d"),
+                    source.age_days(result.analyzed_at)
+                ));
+            }
+            md.push('\n');
+            
+            if !opp.evidence.is_empty() {
+                md.push_str("**Evidence:**\n");
+                for (n, evidence) in opp.evidence.iter().enumerate() {
+                    md.push_str(&format!("{}. {}\n", n + 1, evidence.markdown_citation()));
+                }
+                md.push('\n');
+            }
+            
+            if !opp.differentiation.is_empty() {
+                md.push_str("**Differentiation Opportunities:**\n");
+                for (n, gap) in opp.differentiation.iter().take(MAX_GAPS).enumerate() {
+                    md.push_str(&format!("{}. {}\n", n + 1, gap.markdown_citation()));
+                }
+                md.push('\n');
+            }
             
             md.push_str("---\n\n");
         }
         
+        let disputed = result.scorer_disagreements(MAX_DISAGREEMENTS);
+        if !disputed.is_empty() {
+            md.push_str("## Scorer Disagreement\n\n");
+            md.push_str("Opportunities the scorers rank most differently (rank among all candidates).\n\n");
+            let scorers: Vec<&str> = disputed[0].scorer_results.iter().map(|r| r.scorer.as_str()).collect();
+            md.push_str(&format!("| Opportunity | {} | Rank spread |\n", scorers.join(" | ")));
+            md.push_str(&format!("|---|{}---|\n", "---|".repeat(scorers.len())));
+            for opp in disputed {
+                let cells: Vec<String> = opp.scorer_results
+                    .iter()
+                    .map(|r| format!("{:.1} (#{})", r.score.composite, r.rank))
+                    .collect();
+                md.push_str(&format!("| {} | {} | {} |\n", opp.name, cells.join(" | "), opp.rank_spread().unwrap_or(0.0)));
+            }
+            md.push('\n');
+        }
+        
+        if !result.breakouts.is_empty() {
+            md.push_str("## Breakouts\n\n");
+            md.push_str("Signals that spiked against their snapshot history (latest snapshot vs. baseline).\n\n");
+            md.push_str("| Subject | Scope | Signal | Observed | Expected | Ratio | p-value |\n");
+            md.push_str("|---|---|---|---|---|---|---|\n");
+            for breakout in result.breakouts.iter().take(MAX_BREAKOUTS) {
+                md.push_str(&format!("| {} | {} | {} | {:.0} | {:.1} | {:.1}x | {:.1e} |\n",
+                    breakout.subject,
+                    breakout.scope.as_str(),
+                    breakout.signal,
+                    breakout.observed,
+                    breakout.expected,
+                    breakout.ratio,
+                    breakout.p_value
+                ));
+            }
+            md.push('\n');
+        }
+        
         Ok(md)
     }
     
@@ -90,24 +156,95 @@ impl DefaultReportGenerator {
         for (idx, opp) in result.opportunities.iter().enumerate() {
             text.push_str(&format!("{}. {} (Score: {:.1})\n", idx + 1, opp.name, opp.score));
             text.push_str(&format!("   Category: {}\n", opp.category));
+            if let Some(stage) = opp.lifecycle {
+                text.push_str(&format!("   Lifecycle: {}\n", stage.as_str()));
+            }
             text.push_str(&format!("   Demand: {:.1} | Feasibility: {:.1} | Competition: {:.1} | Trend: {:.1}\n",
                 opp.scoring_details.demand,
                 opp.scoring_details.feasibility,
                 opp.scoring_details.competition,
                 opp.scoring_details.trend
             ));
+            if !opp.scorer_results.is_empty() {
+                text.push_str(&format!("   Scorers: {}\n", scorer_summary(opp)));
+            }
+            if !opp.forecasts.is_empty() {
+                text.push_str(&format!("   Forecast ({}d): {}\n", result.config.forecast_horizon_days, forecast_summary(opp)));
+            }
+            if !opp.differentiation.is_empty() {
+                text.push_str(&format!("   Differentiation: {}\n",
+                    opp.differentiation.iter()
+                        .take(MAX_GAPS)
+                        .map(|g| format!("{} ({} requests)", g.summary, g.issues.len()))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ));
+            }
             text.push_str(&format!("   Sources: {}\n\n",
                 opp.data_sources.iter()
-                    .map(|s| s.name.as_str())
+                    .map(|s| format!("{} ({}d old)", s.name, s.age_days(result.analyzed_at)))
                     .collect::<Vec<_>>()
                     .join(", ")
             ));
         }
         
+        if !result.breakouts.is_empty() {
+            text.push_str("BREAKOUTS\n");
+            text.push_str("---------\n\n");
+            for breakout in result.breakouts.iter().take(MAX_BREAKOUTS) {
+                text.push_str(&format!("{} ({}): {} {:.0} vs {:.1} expected ({:.1}x, p={:.1e})\n",
+                    breakout.subject,
+                    breakout.scope.as_str(),
+                    breakout.signal,
+                    breakout.observed,
+                    breakout.expected,
+                    breakout.ratio,
+                    breakout.p_value
+                ));
+            }
+            text.push('\n');
+        }
+        
         Ok(text)
     }
 }
 
+/// Opportunities listed in the scorer disagreement section
+const MAX_DISAGREEMENTS: usize = 10;
+
+/// Rows listed in the breakouts section
+const MAX_BREAKOUTS: usize = 20;
+
+/// Differentiation opportunities listed per opportunity
+const MAX_GAPS: usize = 5;
+
+/// "name score (#rank), ..." for each scorer of an ensemble
+fn scorer_summary(opp: &NicheOpportunity) -> String {
+    opp.scorer_results
+        .iter()
+        .map(|r| format!("{} {:.1} (#{})", r.scorer, r.score.composite, r.rank))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// "stars 450 -> 750 (90% 700-800, +10.0/day), ..." for each forecast signal
+fn forecast_summary(opp: &NicheOpportunity) -> String {
+    opp.forecasts
+        .iter()
+        .map(|f| format!(
+            "{} {:.0} -> {:.0} ({:.0}% {:.0}-{:.0}, {:+.1}/day)",
+            f.signal.as_str(),
+            f.current,
+            f.projected,
+            f.confidence * 100.0,
+            f.lower,
+            f.upper,
+            f.trend_per_day
+        ))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 impl Default for DefaultReportGenerator {
     fn default() -> Self {
         Self::new()
@@ -141,7 +278,10 @@ mod tests {
                 qualified_candidates: 10,
                 duration_secs: 5.5,
                 sources_used: vec!["github".to_string()],
+                source_status: Vec::new(),
             },
+            breakouts: vec![],
+            provenance: Default::default(),
         }
     }
     
@@ -152,5 +292,56 @@ mod tests {
         let report = generator.generate(&result, ReportFormat::Json).unwrap();
         assert!(report.contains("opportunities"));
     }
+    
+    #[test]
+    fn test_markdown_evidence_citations() {
+        let generator = DefaultReportGenerator::new();
+        let mut result = create_test_result();
+        result.opportunities.push(NicheOpportunity {
+            id: Uuid::new_v4(),
+            name: "Tado".to_string(),
+            category: "tado".to_string(),
+            kind: OpportunityKind::default(),
+            score: 80.0,
+            scoring_details: IntegrationScore {
+                demand: 80.0,
+                feasibility: 80.0,
+                competition: 80.0,
+                trend: 80.0,
+                composite: 80.0,
+                weights: Some(ScoreWeights::default()),
+            },
+            data_sources: vec![],
+            evidence: vec![Evidence {
+                source: "ha_community".to_string(),
+                kind: EvidenceKind::Thread,
+                url: "https://community.home-assistant.io/t/tado-x/7".to_string(),
+                title: "[WTH] Tado X support".to_string(),
+                timestamp: None,
+                engagement: [("votes".to_string(), 12)].into(),
+                match_quality: 0.9,
+            }, Evidence {
+                source: "web".to_string(),
+                kind: EvidenceKind::Thread,
+                url: "https://example.com/wiki/Tado_(X) setup".to_string(),
+                title: "Tado X setup".to_string(),
+                timestamp: None,
+                engagement: Default::default(),
+                match_quality: 0.8,
+            }],
+            features: None,
+            scorer_results: vec![],
+            forecasts: vec![],
+            lifecycle: None,
+            differentiation: Vec::new(),
+            discovered_at: Utc::now(),
+            metadata: serde_json::json!({}),
+        });
+        
+        let report = generator.generate(&result, ReportFormat::Markdown).unwrap();
+        assert!(report.contains(
+            "1. [\\[WTH\\] Tado X support](https://community.home-assistant.io/t/tado-x/7) — ha_community thread, 12 votes, match 90%"
+        ));
+        assert!(report.contains("2. [Tado X setup](https://example.com/wiki/Tado_%28X%29%20setup) — "));
+    }
 }
-
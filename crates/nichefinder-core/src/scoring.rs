@@ -1,20 +1,39 @@
 //! Scoring algorithms for integration opportunities
 
 use crate::{Error, Result};
-use crate::types::{IntegrationScore, ScoreWeights};
+use crate::types::{IntegrationScore, NicheOpportunity, ScoreWeights};
+use crate::types::{AnalysisConfig, EnsembleMethod, ScorerResult};
+use crate::script_scoring::ScriptScorer;
+use serde::{Deserialize, Serialize};
+use statrs::statistics::{Data, Median};
+use std::collections::BTreeMap;
 
 /// Trait for scoring integration opportunities
 pub trait OpportunityScorer: Send + Sync {
     /// Calculate a score for an integration opportunity
     fn score(&self, data: &ScoringData) -> Result<IntegrationScore>;
+    
+    /// Name shown when comparing scorers
+    fn name(&self) -> &str {
+        "custom"
+    }
 }
 
+/// Name of the built-in weighted scorer
+pub const WEIGHTED_SCORER: &str = "weighted";
+
 /// Data used for scoring
-#[derive(Debug, Clone)]
+///
+/// Persisted with each opportunity as its raw feature vector, so scores can
+/// be recomputed under other weights or scorers without re-collecting data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoringData {
     /// Number of user requests/mentions
     pub request_count: usize,
     
+    /// Weighted demand from custom imported sources (spreadsheets, surveys)
+    pub custom_signals: f64,
+    
     /// Growth rate (requests per day)
     pub growth_rate: f64,
     
@@ -27,31 +46,48 @@ pub struct ScoringData {
     /// Number of existing integrations
     pub existing_integrations: usize,
     
+    /// Number of official Home Assistant core integrations (subset of competition
+    /// that outweighs any number of custom ones)
+    pub official_integrations: usize,
+    
     /// Recency of requests (days since last request)
     pub days_since_last_request: u32,
+    
+    /// Normalized numeric fields by name (stars, forks, active_installs,
+    /// forum_votes...), for scorers that use more than the fields above
+    #[serde(default)]
+    pub signals: BTreeMap<String, f64>,
 }
 
 /// Default scorer implementation
 pub struct DefaultScorer {
     weights: ScoreWeights,
+    name: String,
 }
 
 impl DefaultScorer {
     /// Create a new scorer with default weights
     pub fn new() -> Self {
-        Self {
-            weights: ScoreWeights::default(),
-        }
+        Self::with_weights(ScoreWeights::default())
     }
     
     /// Create a new scorer with custom weights
     pub fn with_weights(weights: ScoreWeights) -> Self {
-        Self { weights }
+        Self {
+            weights,
+            name: WEIGHTED_SCORER.to_string(),
+        }
+    }
+    
+    /// Name the scorer, to tell weights profiles apart when comparing them
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
     }
     
     /// Calculate demand score based on request volume and recency
     fn calculate_demand(&self, data: &ScoringData) -> f64 {
-        let volume_score = (data.request_count as f64).min(100.0);
+        let volume_score = (data.request_count as f64 + data.custom_signals).min(100.0);
         let recency_score = if data.days_since_last_request == 0 {
             100.0
         } else {
@@ -80,14 +116,30 @@ impl DefaultScorer {
     }
     
     /// Calculate competition score (inverse - lower existing integrations = higher score)
+    ///
+    /// With a `maintenance_health` signal (0-1) the incumbent counts only as
+    /// much as it is maintained: an abandoned one is barely a competitor.
     fn calculate_competition(&self, data: &ScoringData) -> f64 {
-        match data.existing_integrations {
-            0 => 100.0, // No competition
-            1 => 70.0,  // One competitor
-            2 => 50.0,  // Two competitors
-            3 => 30.0,  // Three competitors
-            _ => 10.0,  // Saturated market
+        // Official core support means users already have a first-party option
+        if data.official_integrations > 0 {
+            return 5.0;
         }
+        
+        // No competition, one, two, three competitors, saturated market
+        const BY_COUNT: [f64; 5] = [100.0, 70.0, 50.0, 30.0, 10.0];
+        
+        let health = data.signals.get("maintenance_health").map(|h| h.clamp(0.0, 1.0));
+        let competitors = match health {
+            Some(health) if data.existing_integrations > 0 => data.existing_integrations as f64 - (1.0 - health),
+            _ => data.existing_integrations as f64,
+        };
+        
+        // Interpolate between neighbouring counts
+        let competitors = competitors.min((BY_COUNT.len() - 1) as f64);
+        let lower = competitors.floor() as usize;
+        let upper = competitors.ceil() as usize;
+        let fraction = competitors - lower as f64;
+        BY_COUNT[lower] + (BY_COUNT[upper] - BY_COUNT[lower]) * fraction
     }
     
     /// Calculate trend score based on growth rate
@@ -131,13 +183,201 @@ impl OpportunityScorer for DefaultScorer {
             competition,
             trend,
             composite: 0.0,
-            weights: self.weights.clone(),
+            weights: Some(self.weights.clone()),
         };
         
         score.composite = self.calculate_composite(&score);
         
         Ok(score)
     }
+    
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Scorer selected by `AnalysisConfig.scorer`
+///
+/// The default weighted scorer when none is named, otherwise the named weight
+/// profile or script from `AnalysisConfig.scorer_dir`.
+pub fn scorer_from_config(config: &AnalysisConfig) -> Result<Box<dyn OpportunityScorer>> {
+    named_scorer(config, config.scorer.as_deref().unwrap_or(WEIGHTED_SCORER))
+}
+
+/// Scorers named in `AnalysisConfig.scorers`, or the single
+/// `AnalysisConfig.scorer` when none are listed
+pub fn scorers_from_config(config: &AnalysisConfig) -> Result<Vec<Box<dyn OpportunityScorer>>> {
+    if config.scorers.is_empty() {
+        return Ok(vec![scorer_from_config(config)?]);
+    }
+    config.scorers.iter().map(|name| named_scorer(config, name)).collect()
+}
+
+/// The weighted scorer for `WEIGHTED_SCORER` or a weight profile, otherwise
+/// a scoring script
+fn named_scorer(config: &AnalysisConfig, name: &str) -> Result<Box<dyn OpportunityScorer>> {
+    if name == WEIGHTED_SCORER {
+        return Ok(Box::new(DefaultScorer::with_weights(config.weights.clone())));
+    }
+    if let Some(weights) = config.weight_profiles.get(name) {
+        return Ok(Box::new(DefaultScorer::with_weights(weights.clone()).named(name)));
+    }
+    Ok(Box::new(ScriptScorer::load(&config.scorer_dir, name, config.weights.clone())?))
+}
+
+/// Scorers run side by side and combined into one composite
+///
+/// With a single scorer its score is used as is. With several, every
+/// candidate is scored by each, ranked per scorer, and the composites are
+/// combined with the `EnsembleMethod`; components are averaged.
+pub struct Ensemble {
+    scorers: Vec<Box<dyn OpportunityScorer>>,
+    method: EnsembleMethod,
+}
+
+/// Combined score of one candidate
+#[derive(Debug, Clone)]
+pub struct EnsembleScore {
+    /// Ensemble score (the scorer's own score for a single scorer)
+    pub score: IntegrationScore,
+    
+    /// Each scorer's result; empty for a single scorer
+    pub results: Vec<ScorerResult>,
+}
+
+impl Ensemble {
+    /// Combine scorers with a method
+    pub fn new(scorers: Vec<Box<dyn OpportunityScorer>>, method: EnsembleMethod) -> Self {
+        Self { scorers, method }
+    }
+    
+    /// A single scorer
+    pub fn single(scorer: Box<dyn OpportunityScorer>) -> Self {
+        Self::new(vec![scorer], EnsembleMethod::default())
+    }
+    
+    /// Scorers named in `AnalysisConfig.scorers`, or the single
+    /// `AnalysisConfig.scorer` when none are listed
+    pub fn from_config(config: &AnalysisConfig) -> Result<Self> {
+        Ok(Self::new(scorers_from_config(config)?, config.ensemble_method))
+    }
+    
+    /// Names of the scorers, in order
+    pub fn names(&self) -> Vec<&str> {
+        self.scorers.iter().map(|s| s.name()).collect()
+    }
+    
+    /// Score every candidate with every scorer and combine the results
+    ///
+    /// Ranks are relative to the candidates passed in, so score the whole
+    /// candidate set at once.
+    pub fn score_all(&self, data: &[&ScoringData]) -> Result<Vec<EnsembleScore>> {
+        let per_scorer = self.scorers
+            .iter()
+            .map(|scorer| data.iter().map(|d| scorer.score(d)).collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<_>>>()?;
+        
+        match per_scorer.len() {
+            0 => return Err(Error::Config("Ensemble has no scorers".to_string())),
+            1 => {
+                let scores = per_scorer.into_iter().next().unwrap_or_default();
+                return Ok(scores.into_iter().map(|score| EnsembleScore { score, results: Vec::new() }).collect());
+            }
+            _ => {}
+        }
+        
+        let ranks: Vec<Vec<f64>> = per_scorer.iter().map(|scores| rank_by_composite(scores)).collect();
+        let candidates = data.len() as f64;
+        
+        let combined = (0..data.len())
+            .map(|i| {
+                let results: Vec<ScorerResult> = self.scorers
+                    .iter()
+                    .enumerate()
+                    .map(|(s, scorer)| ScorerResult {
+                        scorer: scorer.name().to_string(),
+                        score: per_scorer[s][i].clone(),
+                        rank: ranks[s][i],
+                    })
+                    .collect();
+                
+                let mean = |value: fn(&ScorerResult) -> f64| {
+                    results.iter().map(value).sum::<f64>() / results.len() as f64
+                };
+                let composite = match self.method {
+                    EnsembleMethod::Mean => mean(|r| r.score.composite),
+                    EnsembleMethod::Median => Data::new(results.iter().map(|r| r.score.composite).collect::<Vec<_>>()).median(),
+                    // Mean rank 1 scores 100, last place scores 0
+                    EnsembleMethod::RankAverage if candidates > 1.0 => {
+                        100.0 * (candidates - mean(|r| r.rank)) / (candidates - 1.0)
+                    }
+                    EnsembleMethod::RankAverage => 100.0,
+                };
+                
+                let score = IntegrationScore {
+                    demand: mean(|r| r.score.demand),
+                    feasibility: mean(|r| r.score.feasibility),
+                    competition: mean(|r| r.score.competition),
+                    trend: mean(|r| r.score.trend),
+                    composite,
+                    weights: None,
+                };
+                EnsembleScore { score, results }
+            })
+            .collect();
+        Ok(combined)
+    }
+}
+
+/// 1-based rank of each score by composite, best first, ties averaged
+fn rank_by_composite(scores: &[IntegrationScore]) -> Vec<f64> {
+    let descending: Vec<f64> = scores.iter().map(|s| -s.composite).collect();
+    average_ranks(&descending)
+}
+
+/// 1-based ranks, ascending, with ties sharing their average rank
+pub fn average_ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && values[order[end + 1]] == values[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end) as f64 / 2.0 + 1.0;
+        for &index in &order[start..=end] {
+            ranks[index] = rank;
+        }
+        start = end + 1;
+    }
+    ranks
+}
+
+/// Recompute scores from the persisted feature vectors and re-rank
+///
+/// Opportunities without a feature vector (stored before it was recorded)
+/// can't be rescored; they are removed rather than ranked by a score from
+/// different weights. Returns the number removed.
+pub fn rescore(opportunities: &mut Vec<NicheOpportunity>, ensemble: &Ensemble) -> Result<usize> {
+    let total = opportunities.len();
+    opportunities.retain(|o| o.features.is_some());
+    let features: Vec<&ScoringData> = opportunities
+        .iter()
+        .filter_map(|o| o.features.as_ref())
+        .collect();
+    let scores = ensemble.score_all(&features)?;
+    
+    for (opportunity, scored) in opportunities.iter_mut().zip(scores) {
+        opportunity.score = scored.score.composite;
+        opportunity.scoring_details = scored.score;
+        opportunity.scorer_results = scored.results;
+    }
+    
+    opportunities.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(total - opportunities.len())
 }
 
 #[cfg(test)]
@@ -149,11 +389,14 @@ mod tests {
         let scorer = DefaultScorer::new();
         let data = ScoringData {
             request_count: 100,
+            custom_signals: 0.0,
             growth_rate: 2.0,
             has_api: true,
             api_quality: 0.9,
             existing_integrations: 0,
+            official_integrations: 0,
             days_since_last_request: 1,
+            signals: BTreeMap::new(),
         };
         
         let score = scorer.score(&data).unwrap();
@@ -162,5 +405,184 @@ mod tests {
         assert_eq!(score.competition, 100.0);
         assert!(score.composite > 70.0);
     }
+    
+    #[test]
+    fn test_official_coverage_dominates_competition() {
+        let scorer = DefaultScorer::new();
+        let custom_only = ScoringData {
+            request_count: 50,
+            custom_signals: 0.0,
+            growth_rate: 1.0,
+            has_api: true,
+            api_quality: 0.8,
+            existing_integrations: 4,
+            official_integrations: 0,
+            days_since_last_request: 10,
+            signals: BTreeMap::new(),
+        };
+        let official = ScoringData {
+            existing_integrations: 1,
+            official_integrations: 1,
+            ..custom_only.clone()
+        };
+        
+        let custom_score = scorer.score(&custom_only).unwrap();
+        let official_score = scorer.score(&official).unwrap();
+        assert!(official_score.competition < custom_score.competition);
+    }
+    
+    #[test]
+    fn test_neglected_incumbent_is_weaker_competition() {
+        let scorer = DefaultScorer::new();
+        let unknown = ScoringData {
+            request_count: 50,
+            custom_signals: 0.0,
+            growth_rate: 1.0,
+            has_api: true,
+            api_quality: 0.8,
+            existing_integrations: 1,
+            official_integrations: 0,
+            days_since_last_request: 10,
+            signals: BTreeMap::new(),
+        };
+        let with_health = |health: f64| ScoringData {
+            signals: BTreeMap::from([("maintenance_health".to_string(), health)]),
+            ..unknown.clone()
+        };
+        
+        assert_eq!(scorer.score(&unknown).unwrap().competition, 70.0);
+        assert_eq!(scorer.score(&with_health(1.0)).unwrap().competition, 70.0);
+        assert_eq!(scorer.score(&with_health(0.5)).unwrap().competition, 85.0);
+        assert_eq!(scorer.score(&with_health(0.0)).unwrap().competition, 100.0);
+        
+        let crowded = ScoringData { existing_integrations: 6, ..with_health(0.0) };
+        assert_eq!(scorer.score(&crowded).unwrap().competition, 10.0);
+    }
+    
+    #[test]
+    fn test_rescore_from_features() {
+        let popular = ScoringData {
+            request_count: 100,
+            custom_signals: 0.0,
+            growth_rate: 1.0,
+            has_api: false,
+            api_quality: 0.0,
+            existing_integrations: 0,
+            official_integrations: 0,
+            days_since_last_request: 5,
+            signals: BTreeMap::new(),
+        };
+        let buildable = ScoringData {
+            request_count: 10,
+            has_api: true,
+            api_quality: 0.9,
+            ..popular.clone()
+        };
+        let opportunity = |name: &str, features: ScoringData| {
+            let score = DefaultScorer::new().score(&features).unwrap();
+            NicheOpportunity {
+                id: uuid::Uuid::new_v4(),
+                name: name.to_string(),
+                category: name.to_string(),
+                kind: Default::default(),
+                score: score.composite,
+                scoring_details: score,
+                data_sources: vec![],
+                evidence: vec![],
+                features: Some(features),
+                scorer_results: vec![],
+                forecasts: vec![],
+                lifecycle: None,
+                differentiation: Vec::new(),
+                discovered_at: chrono::Utc::now(),
+                metadata: serde_json::json!({}),
+            }
+        };
+        let mut unfeatured = opportunity("unfeatured", buildable.clone());
+        unfeatured.features = None;
+        unfeatured.score = 99.0;
+        let opportunities = vec![opportunity("popular", popular), opportunity("buildable", buildable), unfeatured];
+        
+        // Feature vectors survive a storage round trip
+        let stored = serde_json::to_string(&opportunities).unwrap();
+        let mut opportunities: Vec<NicheOpportunity> = serde_json::from_str(&stored).unwrap();
+        
+        let demand_only = DefaultScorer::with_weights(ScoreWeights { demand: 1.0, feasibility: 0.0, competition: 0.0, trend: 0.0 });
+        let demand_only = Ensemble::single(Box::new(demand_only));
+        // The stale score of an opportunity without features is not ranked
+        assert_eq!(rescore(&mut opportunities, &demand_only).unwrap(), 1);
+        assert_eq!(opportunities.len(), 2);
+        assert_eq!(opportunities[0].name, "popular");
+        assert_eq!(opportunities[0].scoring_details.weights.as_ref().unwrap().demand, 1.0);
+        
+        let feasibility_only = DefaultScorer::with_weights(ScoreWeights { demand: 0.0, feasibility: 1.0, competition: 0.0, trend: 0.0 });
+        let feasibility_only = Ensemble::single(Box::new(feasibility_only));
+        assert_eq!(rescore(&mut opportunities, &feasibility_only).unwrap(), 0);
+        assert_eq!(opportunities[0].name, "buildable");
+        
+        assert!(ScoreWeights::default().validate().is_ok());
+        assert!(ScoreWeights { demand: -1.0, ..Default::default() }.validate().is_err());
+        assert!(ScoreWeights { trend: f64::NAN, ..Default::default() }.validate().is_err());
+        assert!(ScoreWeights { demand: 0.0, feasibility: 0.0, competition: 0.0, trend: 0.0 }.validate().is_err());
+    }
+    
+    #[test]
+    fn test_ensemble_ranks_and_methods() {
+        let only = |demand, feasibility| -> Box<dyn OpportunityScorer> {
+            Box::new(DefaultScorer::with_weights(ScoreWeights { demand, feasibility, competition: 0.0, trend: 0.0 }))
+        };
+        let base = ScoringData {
+            request_count: 0,
+            custom_signals: 0.0,
+            growth_rate: 0.0,
+            has_api: true,
+            api_quality: 0.5,
+            existing_integrations: 0,
+            official_integrations: 0,
+            days_since_last_request: 0,
+            signals: BTreeMap::new(),
+        };
+        let candidates = [
+            ScoringData { request_count: 100, has_api: false, ..base.clone() },
+            ScoringData { request_count: 50, api_quality: 0.7, ..base.clone() },
+            ScoringData { request_count: 0, api_quality: 0.9, ..base.clone() },
+        ];
+        let data: Vec<&ScoringData> = candidates.iter().collect();
+        
+        let mean = Ensemble::new(vec![only(1.0, 0.0), only(0.0, 1.0)], EnsembleMethod::Mean);
+        let scores = mean.score_all(&data).unwrap();
+        let ranks: Vec<Vec<f64>> = scores.iter().map(|s| s.results.iter().map(|r| r.rank).collect()).collect();
+        assert_eq!(ranks, vec![vec![1.0, 3.0], vec![2.0, 2.0], vec![3.0, 1.0]]);
+        // The composite is not a weighted sum of the combined components
+        assert!(scores.iter().all(|s| s.score.weights.is_none()));
+        let expected = (scores[1].results[0].score.composite + scores[1].results[1].score.composite) / 2.0;
+        assert!((scores[1].score.composite - expected).abs() < 1e-9);
+        
+        let rank_average = Ensemble::new(vec![only(1.0, 0.0), only(0.0, 1.0)], EnsembleMethod::RankAverage);
+        let scores = rank_average.score_all(&data).unwrap();
+        assert!(scores.iter().all(|s| (s.score.composite - 50.0).abs() < 1e-9));
+        
+        // Tied candidates share their rank instead of splitting it by input order
+        let tied = [candidates[1].clone(), candidates[1].clone(), candidates[2].clone()];
+        let scores = mean.score_all(&tied.iter().collect::<Vec<_>>()).unwrap();
+        assert_eq!(scores[0].results[0].rank, 1.5);
+        assert_eq!(scores[1].results[0].rank, 1.5);
+        assert_eq!(scores[2].results[0].rank, 3.0);
+        
+        // Weight profiles are selected by name
+        let config = AnalysisConfig {
+            scorers: vec![WEIGHTED_SCORER.to_string(), "learned".to_string()],
+            weight_profiles: BTreeMap::from([("learned".to_string(), ScoreWeights { demand: 0.0, feasibility: 1.0, competition: 0.0, trend: 0.0 })]),
+            ..Default::default()
+        };
+        let profiled = Ensemble::from_config(&config).unwrap();
+        assert_eq!(profiled.names(), vec![WEIGHTED_SCORER, "learned"]);
+        let scores = profiled.score_all(&data).unwrap();
+        assert_eq!(scores[2].results[1].rank, 1.0);
+        assert!(Ensemble::from_config(&AnalysisConfig { scorer: Some("missing".to_string()), ..Default::default() }).is_err());
+        
+        // A single scorer is passed through without per-scorer results
+        let single = Ensemble::single(only(1.0, 0.0));
+        assert!(single.score_all(&data).unwrap().iter().all(|s| s.results.is_empty()));
+    }
 }
-
@@ -0,0 +1,220 @@
+//! Scoring formulas written as Rhai scripts
+//!
+//! Lets analysts try a scoring model without a Rust release. A script sees
+//! two read-only maps, `data` (the `ScoringData` fields plus the `signals`
+//! map of normalized fields) and `weights` (the configured `ScoreWeights`),
+//! and returns a map with `demand`, `feasibility`, `competition` and
+//! `trend`, and optionally `composite`; when it is left out the composite is
+//! the weighted sum of the four components. Every value must be within
+//! 0-100.
+//!
+//! ```rhai
+//! let demand = min(100.0, data.request_count + data.signals.forum_votes * 2);
+//! #{ demand: demand, feasibility: if data.has_api { 80 } else { 20 },
+//!    competition: 100 - min(100, data.existing_integrations * 25), trend: 0 }
+//! ```
+//!
+//! Scripts run sandboxed: no module imports or `eval`, and bounded
+//! operations, call depth and collection sizes, so a runaway script fails
+//! its scoring call instead of hanging the analysis.
+
+use crate::scoring::{OpportunityScorer, ScoringData};
+use crate::types::{IntegrationScore, ScoreWeights};
+use crate::{Error, Result};
+use rhai::{Dynamic, Engine, Map, Scope, AST};
+use std::path::Path;
+
+/// File extension of scoring scripts
+pub const SCRIPT_EXTENSION: &str = "rhai";
+
+/// Operations a script may run per opportunity
+pub const MAX_OPERATIONS: u64 = 100_000;
+
+/// Scorer that evaluates a Rhai script per opportunity
+pub struct ScriptScorer {
+    name: String,
+    engine: Engine,
+    ast: AST,
+    weights: ScoreWeights,
+}
+
+impl ScriptScorer {
+    /// Compile a script from source
+    pub fn from_source(name: &str, source: &str, weights: ScoreWeights) -> Result<Self> {
+        let engine = sandboxed_engine();
+        let ast = engine
+            .compile(source)
+            .map_err(|e| Error::Config(format!("Scoring script '{}' does not compile: {}", name, e)))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            engine,
+            ast,
+            weights,
+        })
+    }
+
+    /// Load `<dir>/<name>.rhai`
+    pub fn load(dir: impl AsRef<Path>, name: &str, weights: ScoreWeights) -> Result<Self> {
+        // Names only select a file in the scorer directory
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(Error::Config(format!("Invalid scorer name '{}'", name)));
+        }
+
+        let path = dir.as_ref().join(format!("{}.{}", name, SCRIPT_EXTENSION));
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| Error::Config(format!("Failed to read scoring script {}: {}", path.display(), e)))?;
+        Self::from_source(name, &source, weights)
+    }
+
+    fn component(&self, result: &Map, key: &str) -> Result<Option<f64>> {
+        let Some(value) = result.get(key) else {
+            return Ok(None);
+        };
+        let value = value
+            .as_float()
+            .or_else(|_| value.as_int().map(|v| v as f64))
+            .map_err(|t| Error::Scoring(format!("Script '{}' returned {} of type {}", self.name, key, t)))?;
+
+        if !value.is_finite() || !(0.0..=100.0).contains(&value) {
+            return Err(Error::Scoring(format!(
+                "Script '{}' returned {} = {} (expected 0-100)",
+                self.name, key, value
+            )));
+        }
+        Ok(Some(value))
+    }
+}
+
+impl OpportunityScorer for ScriptScorer {
+    fn score(&self, data: &ScoringData) -> Result<IntegrationScore> {
+        let to_dynamic = |value| {
+            rhai::serde::to_dynamic(value).map_err(|e| Error::Scoring(format!("Script '{}': {}", self.name, e)))
+        };
+        let mut scope = Scope::new();
+        scope.push_constant("data", to_dynamic(serde_json::to_value(data)?)?);
+        scope.push_constant("weights", to_dynamic(serde_json::to_value(&self.weights)?)?);
+
+        let result: Dynamic = self
+            .engine
+            .eval_ast_with_scope(&mut scope, &self.ast)
+            .map_err(|e| Error::Scoring(format!("Script '{}' failed: {}", self.name, e)))?;
+        let result = result
+            .try_cast::<Map>()
+            .ok_or_else(|| Error::Scoring(format!("Script '{}' must return a map of scores", self.name)))?;
+
+        let required = |key| {
+            self.component(&result, key)?
+                .ok_or_else(|| Error::Scoring(format!("Script '{}' returned no {}", self.name, key)))
+        };
+        let demand = required("demand")?;
+        let feasibility = required("feasibility")?;
+        let competition = required("competition")?;
+        let trend = required("trend")?;
+        let composite = match self.component(&result, "composite")? {
+            Some(composite) => composite,
+            None => (demand * self.weights.demand
+                + feasibility * self.weights.feasibility
+                + competition * self.weights.competition
+                + trend * self.weights.trend)
+                .clamp(0.0, 100.0),
+        };
+
+        Ok(IntegrationScore {
+            demand,
+            feasibility,
+            competition,
+            trend,
+            composite,
+            weights: Some(self.weights.clone()),
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(32);
+    engine.set_max_expr_depths(64, 32);
+    engine.set_max_string_size(4_096);
+    engine.set_max_array_size(1_024);
+    engine.set_max_map_size(256);
+    engine.on_print(|text| tracing::debug!("scoring script: {}", text));
+    engine
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::scorer_from_config;
+    use crate::test_support::Fixtures;
+    use crate::types::AnalysisConfig;
+    use std::collections::BTreeMap;
+
+    fn data() -> ScoringData {
+        ScoringData {
+            request_count: 40,
+            custom_signals: 0.0,
+            growth_rate: 1.0,
+            has_api: true,
+            api_quality: 0.8,
+            existing_integrations: 1,
+            official_integrations: 0,
+            days_since_last_request: 3,
+            signals: BTreeMap::from([("forum_votes".to_string(), 15.0)]),
+        }
+    }
+
+    #[test]
+    fn test_script_scorer() {
+        let fixtures = Fixtures::new();
+        fixtures.write_str(
+            "votes.rhai",
+            r#"
+            let demand = min(100.0, data.request_count + data.signals.forum_votes * 2);
+            #{ demand: demand, feasibility: if data.has_api { 80 } else { 20 },
+               competition: 100 - data.existing_integrations * 25, trend: 0 }
+            "#,
+        );
+
+        let config = AnalysisConfig {
+            scorer: Some("votes".to_string()),
+            scorer_dir: fixtures.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let score = scorer_from_config(&config).unwrap().score(&data()).unwrap();
+        assert_eq!(score.demand, 70.0);
+        assert_eq!(score.competition, 75.0);
+        // Weighted composite when the script does not return one
+        assert!((score.composite - (70.0 * 0.4 + 80.0 * 0.3 + 75.0 * 0.2)).abs() < 1e-9);
+
+        let missing = AnalysisConfig { scorer: Some("nope".to_string()), ..config.clone() };
+        assert!(scorer_from_config(&missing).is_err());
+        let traversal = AnalysisConfig { scorer: Some("../votes".to_string()), ..config };
+        assert!(scorer_from_config(&traversal).is_err());
+    }
+
+    #[test]
+    fn test_script_sandbox_and_validation() {
+        let weights = ScoreWeights::default();
+        let scorer = |source: &str| ScriptScorer::from_source("test", source, weights.clone()).unwrap();
+
+        let out_of_range = scorer("#{ demand: 150, feasibility: 50, competition: 50, trend: 50 }");
+        assert!(out_of_range.score(&data()).is_err());
+
+        let incomplete = scorer("#{ demand: 50 }");
+        assert!(incomplete.score(&data()).is_err());
+
+        let runaway = scorer("loop { } ");
+        assert!(runaway.score(&data()).is_err());
+
+        let import = scorer(r#"import "os" as os; #{ demand: 1, feasibility: 1, competition: 1, trend: 1 }"#);
+        assert!(import.score(&data()).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+//! Fixtures shared by unit tests
+
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Temporary directory for input files; removed on drop, also when a test
+/// panics
+pub(crate) struct Fixtures {
+    dir: TempDir,
+}
+
+impl Fixtures {
+    pub(crate) fn new() -> Self {
+        Self { dir: TempDir::new().expect("Failed to create fixture directory") }
+    }
+
+    /// Root of the fixture directory
+    pub(crate) fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Write a JSON fixture and return its path
+    pub(crate) fn write(&self, name: &str, value: serde_json::Value) -> String {
+        self.write_str(name, &value.to_string())
+    }
+
+    /// Write a text fixture (creating parent directories) and return its path
+    pub(crate) fn write_str(&self, name: &str, content: &str) -> String {
+        let path = self.dir.path().join(name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create fixture directory");
+        }
+        std::fs::write(&path, content).expect("Failed to write fixture");
+        path.to_string_lossy().into_owned()
+    }
+}
@@ -0,0 +1,382 @@
+//! Learning score weights from team feedback labels
+//!
+//! Team members label opportunities thumbs up/down or with a 1-5 rating.
+//! `WeightTrainer` fits a logistic model over the four score components of
+//! each labeled opportunity (computed from its persisted feature vector)
+//! and turns the fitted coefficients into a `ScoreWeights` profile, so the
+//! ranking follows what the team actually pursues.
+
+use crate::{
+    backtest::spearman,
+    scoring::{DefaultScorer, OpportunityScorer, ScoringData, WEIGHTED_SCORER},
+    types::{NicheOpportunity, OpportunityKind, ScoreWeights},
+    Error, Result,
+};
+use chrono::{DateTime, Utc};
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Labeled examples needed before weights are fitted
+pub const MIN_EXAMPLES: usize = 4;
+
+/// Component names, in feature column order
+const COMPONENTS: [&str; 4] = ["demand", "feasibility", "competition", "trend"];
+
+/// A team judgement of an opportunity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelValue {
+    /// Worth pursuing
+    Up,
+
+    /// Not worth pursuing
+    Down,
+
+    /// Rating from 1 (poor) to 5 (excellent)
+    Rating(u8),
+}
+
+impl LabelValue {
+    /// Training target in 0-1; `None` for ratings outside 1-5
+    pub fn target(&self) -> Option<f64> {
+        match *self {
+            LabelValue::Up => Some(1.0),
+            LabelValue::Down => Some(0.0),
+            LabelValue::Rating(rating @ 1..=5) => Some(f64::from(rating - 1) / 4.0),
+            LabelValue::Rating(_) => None,
+        }
+    }
+}
+
+/// A label attached to a stored opportunity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpportunityLabel {
+    pub opportunity_id: Uuid,
+    pub value: LabelValue,
+
+    /// Who labeled it
+    #[serde(default)]
+    pub labeled_by: Option<String>,
+
+    #[serde(default)]
+    pub note: Option<String>,
+
+    pub labeled_at: DateTime<Utc>,
+}
+
+/// A feature vector with its training target
+#[derive(Debug, Clone)]
+pub struct LabeledExample {
+    pub features: ScoringData,
+
+    /// 0 (bad) to 1 (good)
+    pub target: f64,
+}
+
+/// Pair labels with the feature vectors of their opportunities
+///
+/// Every label is one example. Opportunities stored by several runs are
+/// matched by kind and name, and labels use the newest copy's features.
+/// Labels of opportunities without a feature vector or with an invalid
+/// rating are skipped.
+pub fn labeled_examples(opportunities: &[NicheOpportunity], labels: &[OpportunityLabel]) -> Vec<LabeledExample> {
+    let mut newest: HashMap<(OpportunityKind, &str), &NicheOpportunity> = HashMap::new();
+    for opportunity in opportunities {
+        let current = newest.entry((opportunity.kind, opportunity.name.as_str())).or_insert(opportunity);
+        if opportunity.discovered_at > current.discovered_at {
+            *current = opportunity;
+        }
+    }
+    let features: HashMap<Uuid, &ScoringData> = opportunities
+        .iter()
+        .filter_map(|o| Some((o.id, newest[&(o.kind, o.name.as_str())].features.as_ref()?)))
+        .collect();
+
+    labels
+        .iter()
+        .filter_map(|label| {
+            Some(LabeledExample {
+                features: (*features.get(&label.opportunity_id)?).clone(),
+                target: label.value.target()?,
+            })
+        })
+        .collect()
+}
+
+/// How well a weights profile ranks the labeled examples
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitReport {
+    pub examples: usize,
+
+    /// Examples with target >= 0.5
+    pub positives: usize,
+
+    /// Logistic coefficient per component (on components scaled to 0-1)
+    pub coefficients: HashMap<String, f64>,
+    pub intercept: f64,
+
+    /// Mean log loss of the fitted model
+    pub log_loss: f64,
+
+    /// Share of examples the fitted model puts on the right side of 0.5
+    pub accuracy: f64,
+
+    /// Spearman correlation of composite and label, with the baseline weights
+    pub baseline_spearman: Option<f64>,
+
+    /// Spearman correlation of composite and label, with the learned weights
+    pub learned_spearman: Option<f64>,
+}
+
+/// Learned weights with the report of their fit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainedProfile {
+    pub name: String,
+    pub weights: ScoreWeights,
+    pub report: FitReport,
+}
+
+impl TrainedProfile {
+    /// Weighted scorer using the learned weights
+    pub fn scorer(&self) -> DefaultScorer {
+        DefaultScorer::with_weights(self.weights.clone()).named(&self.name)
+    }
+}
+
+/// Fits score weights to labeled examples by logistic regression
+pub struct WeightTrainer {
+    baseline: ScoreWeights,
+    iterations: usize,
+    learning_rate: f64,
+    l2: f64,
+}
+
+impl WeightTrainer {
+    /// Trainer comparing against the default weights
+    pub fn new() -> Self {
+        Self {
+            baseline: ScoreWeights::default(),
+            iterations: 5_000,
+            learning_rate: 0.5,
+            l2: 0.01,
+        }
+    }
+
+    /// Compare against (and fall back to) these weights
+    pub fn with_baseline(mut self, weights: ScoreWeights) -> Self {
+        self.baseline = weights;
+        self
+    }
+
+    /// Set the L2 penalty on coefficients
+    pub fn with_l2(mut self, l2: f64) -> Self {
+        self.l2 = l2.max(0.0);
+        self
+    }
+
+    /// Fit weights to the examples
+    ///
+    /// Coefficients are clamped at zero (a component cannot count against
+    /// an opportunity) and normalized to sum to 1. If every coefficient is
+    /// zero the baseline weights are kept. The built-in scorer's name is
+    /// reserved and cannot name a profile.
+    pub fn fit(&self, name: &str, examples: &[LabeledExample]) -> Result<TrainedProfile> {
+        if name.trim().is_empty() || name == WEIGHTED_SCORER {
+            return Err(Error::Config(format!("'{}' cannot name a weight profile", name)));
+        }
+        let positives = examples.iter().filter(|e| e.target >= 0.5).count();
+        if examples.len() < MIN_EXAMPLES || positives == 0 || positives == examples.len() {
+            return Err(Error::Scoring(format!(
+                "Need at least {} labeled opportunities with both good and bad labels (have {}, {} good)",
+                MIN_EXAMPLES,
+                examples.len(),
+                positives
+            )));
+        }
+
+        let components = self.components(examples)?;
+        let x = &components / 100.0;
+        let y = Array1::from_iter(examples.iter().map(|e| e.target));
+        let n = examples.len() as f64;
+
+        // Full-batch gradient descent on mean log loss with an L2 penalty
+        let mut coefficients = Array1::<f64>::zeros(COMPONENTS.len());
+        let mut intercept = 0.0;
+        for _ in 0..self.iterations {
+            let error = predict(&x, &coefficients, intercept) - &y;
+            let gradient = x.t().dot(&error) / n + &coefficients * self.l2;
+            coefficients = coefficients - gradient * self.learning_rate;
+            intercept -= self.learning_rate * error.sum() / n;
+        }
+
+        let predictions = predict(&x, &coefficients, intercept);
+        let log_loss = predictions
+            .iter()
+            .zip(&y)
+            .map(|(&p, &t)| {
+                let p = p.clamp(1e-12, 1.0 - 1e-12);
+                -(t * p.ln() + (1.0 - t) * (1.0 - p).ln())
+            })
+            .sum::<f64>()
+            / n;
+        let accuracy = predictions
+            .iter()
+            .zip(&y)
+            .filter(|(&p, &t)| (p >= 0.5) == (t >= 0.5))
+            .count() as f64
+            / n;
+
+        let positive = coefficients.mapv(|c| c.max(0.0));
+        let total = positive.sum();
+        let weights = if total > 0.0 {
+            ScoreWeights {
+                demand: positive[0] / total,
+                feasibility: positive[1] / total,
+                competition: positive[2] / total,
+                trend: positive[3] / total,
+            }
+        } else {
+            self.baseline.clone()
+        };
+
+        let targets: Vec<f64> = y.to_vec();
+        let composites = |w: &ScoreWeights| -> Vec<f64> {
+            let w = Array1::from(vec![w.demand, w.feasibility, w.competition, w.trend]);
+            components.dot(&w).to_vec()
+        };
+
+        Ok(TrainedProfile {
+            name: name.to_string(),
+            report: FitReport {
+                examples: examples.len(),
+                positives,
+                coefficients: COMPONENTS.iter().map(|c| c.to_string()).zip(coefficients.iter().copied()).collect(),
+                intercept,
+                log_loss,
+                accuracy,
+                baseline_spearman: spearman(&composites(&self.baseline), &targets),
+                learned_spearman: spearman(&composites(&weights), &targets),
+            },
+            weights,
+        })
+    }
+
+    /// Component scores (0-100) of each example, one row per example
+    fn components(&self, examples: &[LabeledExample]) -> Result<Array2<f64>> {
+        let scorer = DefaultScorer::with_weights(self.baseline.clone());
+        let mut components = Array2::<f64>::zeros((examples.len(), COMPONENTS.len()));
+        for (mut row, example) in components.axis_iter_mut(Axis(0)).zip(examples) {
+            let score = scorer.score(&example.features)?;
+            row.assign(&Array1::from(vec![score.demand, score.feasibility, score.competition, score.trend]));
+        }
+        Ok(components)
+    }
+}
+
+impl Default for WeightTrainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn predict(x: &Array2<f64>, coefficients: &Array1<f64>, intercept: f64) -> Array1<f64> {
+    (x.dot(coefficients) + intercept).mapv(|z| 1.0 / (1.0 + (-z).exp()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn features(request_count: usize, api_quality: f64) -> ScoringData {
+        ScoringData {
+            request_count,
+            custom_signals: 0.0,
+            growth_rate: 0.0,
+            has_api: true,
+            api_quality,
+            existing_integrations: 0,
+            official_integrations: 0,
+            days_since_last_request: 0,
+            signals: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_label_targets() {
+        assert_eq!(LabelValue::Up.target(), Some(1.0));
+        assert_eq!(LabelValue::Rating(3).target(), Some(0.5));
+        assert_eq!(LabelValue::Rating(9).target(), None);
+        assert_eq!(serde_json::to_string(&LabelValue::Rating(4)).unwrap(), r#"{"rating":4}"#);
+    }
+
+    #[test]
+    fn test_fit_learns_what_the_team_pursues() {
+        // The team likes buildable opportunities regardless of demand
+        let examples: Vec<LabeledExample> = [
+            (90, 0.2, 0.0),
+            (80, 0.3, 0.0),
+            (70, 0.1, 0.0),
+            (10, 0.9, 1.0),
+            (20, 0.8, 1.0),
+            (30, 0.7, 0.75),
+        ]
+        .iter()
+        .map(|&(requests, quality, target)| LabeledExample { features: features(requests, quality), target })
+        .collect();
+
+        let profile = WeightTrainer::new().fit("team", &examples).unwrap();
+        assert!(profile.weights.feasibility > profile.weights.demand);
+        let total = profile.weights.demand + profile.weights.feasibility + profile.weights.competition + profile.weights.trend;
+        assert!((total - 1.0).abs() < 1e-9);
+        assert_eq!(profile.report.accuracy, 1.0);
+        assert!(profile.report.learned_spearman.unwrap() > profile.report.baseline_spearman.unwrap());
+        assert_eq!(profile.scorer().name(), "team");
+
+        // One-sided labels cannot be fitted
+        let all_good: Vec<LabeledExample> = examples.iter().map(|e| LabeledExample { target: 1.0, ..e.clone() }).collect();
+        assert!(WeightTrainer::new().fit("team", &all_good).is_err());
+        assert!(WeightTrainer::new().fit(WEIGHTED_SCORER, &examples).is_err());
+    }
+
+    #[test]
+    fn test_labels_use_newest_copy_of_opportunity() {
+        let opportunity = |name: &str, days_ago: i64, requests: usize| {
+            let features = features(requests, 0.5);
+            let score = DefaultScorer::new().score(&features).unwrap();
+            NicheOpportunity {
+                id: Uuid::new_v4(),
+                name: name.to_string(),
+                category: name.to_string(),
+                kind: Default::default(),
+                score: score.composite,
+                scoring_details: score,
+                data_sources: vec![],
+                evidence: vec![],
+                features: Some(features),
+                scorer_results: vec![],
+                forecasts: vec![],
+                lifecycle: None,
+                differentiation: Vec::new(),
+                discovered_at: Utc::now() - chrono::Duration::days(days_ago),
+                metadata: serde_json::json!({}),
+            }
+        };
+        let stale = opportunity("tado", 7, 10);
+        let current = opportunity("tado", 0, 60);
+        let other = opportunity("roborock", 7, 30);
+        let label = |opportunity: &NicheOpportunity| OpportunityLabel {
+            opportunity_id: opportunity.id,
+            value: LabelValue::Up,
+            labeled_by: None,
+            note: None,
+            labeled_at: Utc::now(),
+        };
+
+        let examples = labeled_examples(&[stale.clone(), current, other.clone()], &[label(&stale), label(&other)]);
+        let requests: Vec<usize> = examples.iter().map(|e| e.features.request_count).collect();
+        assert_eq!(requests, vec![60, 30]);
+    }
+}
@@ -1,9 +1,22 @@
 //! Data transformation module for converting raw API responses into normalized structures
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use chrono::{DateTime, Utc};
 use anyhow::{Context, Result};
+use crate::collectors::{
+    AnalyticsSnapshot, CoreIntegrationManifest, DiscourseTopic, DiscourseTopicListResponse, HackerNewsHit, HackerNewsSearchResponse,
+    RedditListing, RedditPost,
+};
+use crate::custom_sources::CustomSignalTotal;
+use crate::device_catalogs::DeviceProtocol;
+use crate::ecosystems::ForeignPort;
+use crate::enrichment::LibraryFeasibility;
+use crate::maintenance::MaintenanceHealth;
+use crate::feature_gaps::FeatureGap;
+use crate::forecast::SignalForecast;
+use crate::matching::MatchingEngine;
+use crate::types::{Evidence, EvidenceKind, OpportunityKind};
 
 /// Raw HACS integration data (as returned from API)
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,6 +29,15 @@ pub struct HacsIntegration {
     pub last_updated: Option<String>,
     pub manifest_name: Option<String>,
     pub topics: Option<Vec<String>>,
+    #[serde(default)]
+    pub manifest: Option<HacsManifestInfo>,
+}
+
+/// Manifest fields included in HACS data (when present)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HacsManifestInfo {
+    #[serde(default)]
+    pub requirements: Vec<String>,
 }
 
 /// Raw GitHub repository data
@@ -73,7 +95,7 @@ pub struct YouTubeSearchResponse {
 }
 
 /// Normalized integration data combining multiple sources
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NormalizedIntegration {
     /// Integration name (from HACS or GitHub)
     pub name: String,
@@ -102,22 +124,142 @@ pub struct NormalizedIntegration {
     /// Last updated timestamp
     pub last_updated: Option<DateTime<Utc>>,
     
+    /// Repository creation time, when GitHub data is available
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    
     /// Whether it's available in HACS
     pub in_hacs: bool,
     
+    /// What kind of gap this candidate represents
+    #[serde(default)]
+    pub kind: OpportunityKind,
+    
+    /// Plugins/add-ons for the same device in other ecosystems
+    #[serde(default)]
+    pub foreign_ports: Vec<ForeignPort>,
+    
+    /// Catalogued devices per protocol, for protocol-only vendor candidates
+    #[serde(default)]
+    pub protocol_devices: BTreeMap<DeviceProtocol, usize>,
+    
+    /// Matched custom source signals, by source name
+    #[serde(default)]
+    pub custom_signals: BTreeMap<String, CustomSignalTotal>,
+    
+    /// Active installations reported by HA analytics (custom integration installs)
+    #[serde(default)]
+    pub active_installs: Option<u64>,
+    
+    /// Official Home Assistant core integrations covering the same device/service
+    #[serde(default)]
+    pub core_coverage: Vec<CoreCoverage>,
+    
+    /// Python requirements (vendor client libraries) from the integration's
+    /// manifest and the manifests of covering core integrations
+    #[serde(default)]
+    pub requirements: Vec<String>,
+    
+    /// Vendor library signals resolved from PyPI
+    #[serde(default)]
+    pub library_feasibility: Option<LibraryFeasibility>,
+    
+    /// Maintenance health of the integration's own repository
+    #[serde(default)]
+    pub maintenance: Option<MaintenanceHealth>,
+    
+    /// Unmet feature requests clustered from the repository's open issues
+    #[serde(default)]
+    pub feature_gaps: Vec<FeatureGap>,
+    
+    /// Stars, mentions and installs forecast from snapshot history
+    #[serde(default)]
+    pub forecasts: Vec<SignalForecast>,
+    
     /// YouTube mentions count
     pub youtube_mentions: u32,
     
+    /// Hacker News stories and comments mentioning the integration
+    #[serde(default)]
+    pub hackernews_mentions: u32,
+    
+    /// Total Hacker News points across matched stories
+    #[serde(default)]
+    pub hackernews_points: u64,
+    
+    /// Reddit posts mentioning the integration
+    #[serde(default)]
+    pub reddit_mentions: u32,
+    
+    /// Total Reddit score across matched posts
+    #[serde(default)]
+    pub reddit_score: u64,
+    
+    /// Forum feature-request topics asking for the integration
+    #[serde(default)]
+    pub forum_topics: u32,
+    
+    /// Total votes across matched forum topics
+    #[serde(default)]
+    pub forum_votes: u64,
+    
+    /// Total views across matched forum topics
+    #[serde(default)]
+    pub forum_views: u64,
+    
+    /// Total replies across matched forum topics
+    #[serde(default)]
+    pub forum_replies: u64,
+    
+    /// Citable items matched to the integration
+    #[serde(default)]
+    pub evidence: Vec<Evidence>,
+    
     /// Source data
     pub sources: IntegrationSources,
 }
 
-/// Source data references
+/// Official core integration covering a candidate
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoreCoverage {
+    pub domain: String,
+    pub name: String,
+    pub iot_class: Option<String>,
+    pub quality_scale: Option<String>,
+    pub config_flow: bool,
+    #[serde(default)]
+    pub requirements: Vec<String>,
+    /// Active installations reported by HA analytics
+    #[serde(default)]
+    pub active_installs: Option<u64>,
+}
+
+impl From<&CoreIntegrationManifest> for CoreCoverage {
+    fn from(manifest: &CoreIntegrationManifest) -> Self {
+        Self {
+            domain: manifest.domain.clone(),
+            name: manifest.name.clone(),
+            iot_class: manifest.iot_class.clone(),
+            quality_scale: manifest.quality_scale.clone(),
+            config_flow: manifest.config_flow,
+            requirements: manifest.requirements.clone(),
+            active_installs: None,
+        }
+    }
+}
+
+/// Source data references
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct IntegrationSources {
     pub hacs_id: Option<String>,
     pub github_full_name: Option<String>,
     pub youtube_video_ids: Vec<String>,
+    #[serde(default)]
+    pub hackernews_ids: Vec<String>,
+    #[serde(default)]
+    pub reddit_ids: Vec<String>,
+    #[serde(default)]
+    pub forum_topic_ids: Vec<u64>,
 }
 
 /// Load and parse HACS integrations from JSON file
@@ -153,6 +295,99 @@ pub fn load_youtube_data(path: &str) -> Result<Vec<YouTubeVideo>> {
     Ok(response.items)
 }
 
+/// Load and parse Hacker News hits from an Algolia search response file
+pub fn load_hackernews_data(path: &str) -> Result<Vec<HackerNewsHit>> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read Hacker News data file")?;
+
+    let response: HackerNewsSearchResponse = serde_json::from_str(&content)
+        .context("Failed to parse Hacker News JSON")?;
+
+    Ok(response.hits)
+}
+
+/// Load and parse Reddit posts from a listing (search response) file
+pub fn load_reddit_data(path: &str) -> Result<Vec<RedditPost>> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read Reddit data file")?;
+
+    let listing: RedditListing = serde_json::from_str(&content)
+        .context("Failed to parse Reddit JSON")?;
+
+    Ok(listing.data.children.into_iter().map(|child| child.data).collect())
+}
+
+/// Forum data file: either a raw Discourse category listing or a plain topic array
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ForumDataFile {
+    Listing(DiscourseTopicListResponse),
+    Topics(Vec<DiscourseTopic>),
+}
+
+/// Load and parse forum topics from JSON file
+pub fn load_forum_data(path: &str) -> Result<Vec<DiscourseTopic>> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read forum data file")?;
+
+    let data: ForumDataFile = serde_json::from_str(&content)
+        .context("Failed to parse forum JSON")?;
+
+    Ok(match data {
+        ForumDataFile::Listing(listing) => listing.topic_list.topics,
+        ForumDataFile::Topics(topics) => topics,
+    })
+}
+
+/// Core inventory file: a manifest array or a map of domain to manifest
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CoreInventoryFile {
+    List(Vec<CoreIntegrationManifest>),
+    ByDomain(HashMap<String, CoreIntegrationManifest>),
+}
+
+/// Load and parse Home Assistant core integration manifests from JSON file
+pub fn load_core_inventory_data(path: &str) -> Result<Vec<CoreIntegrationManifest>> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read HA core inventory file")?;
+
+    let data: CoreInventoryFile = serde_json::from_str(&content)
+        .context("Failed to parse HA core inventory JSON")?;
+
+    Ok(match data {
+        CoreInventoryFile::List(manifests) => manifests,
+        CoreInventoryFile::ByDomain(manifests) => manifests.into_values().collect(),
+    })
+}
+
+/// Analytics file: the current snapshot, or the full history keyed by timestamp
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AnalyticsDataFile {
+    Snapshot(AnalyticsSnapshot),
+    History(HashMap<String, AnalyticsSnapshot>),
+}
+
+/// Load and parse HA analytics install counts from JSON file
+/// For history files the most recent snapshot is used
+pub fn load_analytics_data(path: &str) -> Result<AnalyticsSnapshot> {
+    let content = std::fs::read_to_string(path)
+        .context("Failed to read HA analytics data file")?;
+
+    let data: AnalyticsDataFile = serde_json::from_str(&content)
+        .context("Failed to parse HA analytics JSON")?;
+
+    match data {
+        AnalyticsDataFile::Snapshot(snapshot) => Ok(snapshot),
+        AnalyticsDataFile::History(history) => history
+            .into_iter()
+            .max_by_key(|(timestamp, _)| timestamp.parse::<i64>().unwrap_or(0))
+            .map(|(_, snapshot)| snapshot)
+            .context("HA analytics history is empty"),
+    }
+}
+
 /// Normalize and combine data from all sources
 pub fn normalize_integrations(
     hacs_data: HashMap<String, HacsIntegration>,
@@ -170,6 +405,9 @@ pub fn normalize_integrations(
     // Process HACS integrations
     for (hacs_id, hacs_integration) in hacs_data {
         let github_repo = github_map.get(&hacs_integration.full_name);
+        let videos = match_youtube_videos(&hacs_integration.domain, &youtube_data);
+        let mut evidence = vec![repository_evidence(&hacs_integration, github_repo)];
+        evidence.extend(videos.iter().map(|(video, quality)| video_evidence(video, *quality)));
 
         let normalized = NormalizedIntegration {
             name: hacs_integration.manifest_name
@@ -190,12 +428,39 @@ pub fn normalize_integrations(
                 .or_else(|| github_repo.map(|r| r.topics.clone()))
                 .unwrap_or_default(),
             last_updated: parse_datetime(&hacs_integration.last_updated),
+            created_at: github_repo.and_then(|r| parse_rfc3339(&r.created_at)),
             in_hacs: true,
+            kind: OpportunityKind::CommunityIntegration,
+            foreign_ports: Vec::new(),
+            protocol_devices: BTreeMap::new(),
+            custom_signals: BTreeMap::new(),
+            active_installs: None,
+            core_coverage: Vec::new(),
+            requirements: hacs_integration.manifest
+                .as_ref()
+                .map(|m| m.requirements.clone())
+                .unwrap_or_default(),
+            library_feasibility: None,
+            maintenance: None,
+            feature_gaps: Vec::new(),
+            forecasts: Vec::new(),
             youtube_mentions: count_youtube_mentions(&hacs_integration.domain, &youtube_data),
+            hackernews_mentions: 0,
+            hackernews_points: 0,
+            reddit_mentions: 0,
+            reddit_score: 0,
+            forum_topics: 0,
+            forum_votes: 0,
+            forum_views: 0,
+            forum_replies: 0,
+            evidence,
             sources: IntegrationSources {
                 hacs_id: Some(hacs_id),
                 github_full_name: Some(hacs_integration.full_name.clone()),
-                youtube_video_ids: find_youtube_videos(&hacs_integration.domain, &youtube_data),
+                youtube_video_ids: videos.iter().filter_map(|(video, _)| video.id.video_id.clone()).collect(),
+                hackernews_ids: Vec::new(),
+                reddit_ids: Vec::new(),
+                forum_topic_ids: Vec::new(),
             },
         };
 
@@ -205,13 +470,167 @@ pub fn normalize_integrations(
     Ok(integrations)
 }
 
+/// Record official core integrations that cover each candidate
+///
+/// A core integration covers a candidate when it uses the same domain (the
+/// custom integration overrides a core one) or when its name mentions the
+/// candidate's name or domain.
+pub fn apply_core_coverage(integrations: &mut [NormalizedIntegration], manifests: &[CoreIntegrationManifest]) {
+    let engine = MatchingEngine::from_integrations(integrations);
+
+    for manifest in manifests.iter().filter(|m| m.is_device_or_service()) {
+        let mut covered: Vec<usize> = engine
+            .match_text(&format!("{} {}", manifest.name, manifest.domain))
+            .into_iter()
+            .map(|m| m.index)
+            .collect();
+
+        covered.extend(
+            integrations
+                .iter()
+                .enumerate()
+                .filter(|(_, i)| i.domain.as_deref() == Some(manifest.domain.as_str()))
+                .map(|(index, _)| index),
+        );
+        covered.sort_unstable();
+        covered.dedup();
+
+        for index in covered {
+            let integration = &mut integrations[index];
+            integration.core_coverage.push(CoreCoverage::from(manifest));
+            for requirement in &manifest.requirements {
+                if !integration.requirements.contains(requirement) {
+                    integration.requirements.push(requirement.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Attach HA analytics install counts to candidates and their core coverage
+///
+/// Should run after [`apply_core_coverage`] so core integrations get counts too.
+pub fn apply_install_counts(integrations: &mut [NormalizedIntegration], snapshot: &AnalyticsSnapshot) {
+    for integration in integrations.iter_mut() {
+        if let Some(domain) = &integration.domain {
+            integration.active_installs = snapshot.custom_installs(domain);
+        }
+        for coverage in integration.core_coverage.iter_mut() {
+            coverage.active_installs = snapshot.core_installs(&coverage.domain);
+        }
+    }
+}
+
+/// Estimated users in a niche: installs of the candidate plus installs of the
+/// official integrations covering the same devices
+pub fn addressable_users(integration: &NormalizedIntegration) -> Option<u64> {
+    let core_installs: Option<u64> = integration.core_coverage
+        .iter()
+        .filter_map(|c| c.active_installs)
+        .reduce(|a, b| a + b);
+
+    match (integration.active_installs, core_installs) {
+        (None, None) => None,
+        (custom, core) => Some(custom.unwrap_or(0) + core.unwrap_or(0)),
+    }
+}
+
+/// Attach Hacker News stories and comments to the integrations they mention
+pub fn apply_hackernews_signals(integrations: &mut [NormalizedIntegration], hits: &[HackerNewsHit]) {
+    let engine = MatchingEngine::from_integrations(integrations);
+
+    for hit in hits {
+        for m in engine.match_text(&hit.searchable_text()) {
+            let integration = &mut integrations[m.index];
+            integration.hackernews_mentions += 1;
+            integration.hackernews_points += hit.points.unwrap_or(0).max(0) as u64;
+            integration.sources.hackernews_ids.push(hit.object_id.clone());
+            integration.evidence.push(Evidence {
+                source: "hackernews".to_string(),
+                kind: EvidenceKind::Post,
+                url: hit.discussion_url(),
+                title: hit.title.clone()
+                    .or_else(|| hit.story_title.as_ref().map(|t| format!("Comment on: {}", t)))
+                    .unwrap_or_else(|| format!("Hacker News item {}", hit.object_id)),
+                timestamp: hit.created_at_i
+                    .and_then(|secs| DateTime::from_timestamp(secs, 0))
+                    .or_else(|| parse_rfc3339(&hit.created_at)),
+                engagement: BTreeMap::from([
+                    ("points".to_string(), hit.points.unwrap_or(0).max(0) as u64),
+                    ("comments".to_string(), hit.num_comments.unwrap_or(0)),
+                ]),
+                match_quality: m.quality,
+            });
+        }
+    }
+}
+
+/// Attach Reddit posts to the integrations they mention
+pub fn apply_reddit_signals(integrations: &mut [NormalizedIntegration], posts: &[RedditPost]) {
+    let engine = MatchingEngine::from_integrations(integrations);
+
+    for post in posts {
+        for m in engine.match_text(&post.searchable_text()) {
+            let integration = &mut integrations[m.index];
+            integration.reddit_mentions += 1;
+            integration.reddit_score += post.score.max(0) as u64;
+            integration.sources.reddit_ids.push(post.id.clone());
+            integration.evidence.push(Evidence {
+                source: "reddit".to_string(),
+                kind: EvidenceKind::Post,
+                url: post.discussion_url(),
+                title: format!("r/{}: {}", post.subreddit, post.title),
+                timestamp: DateTime::from_timestamp(post.created_utc as i64, 0),
+                engagement: BTreeMap::from([
+                    ("score".to_string(), post.score.max(0) as u64),
+                    ("comments".to_string(), post.num_comments),
+                ]),
+                match_quality: m.quality,
+            });
+        }
+    }
+}
+
+/// Attach forum feature-request topics to the integrations they ask for
+pub fn apply_forum_signals(integrations: &mut [NormalizedIntegration], topics: &[DiscourseTopic]) {
+    let engine = MatchingEngine::from_integrations(integrations);
+
+    for topic in topics {
+        for m in engine.match_text(&topic.searchable_text()) {
+            let integration = &mut integrations[m.index];
+            integration.forum_topics += 1;
+            integration.forum_votes += topic.vote_count;
+            integration.forum_views += topic.views;
+            integration.forum_replies += topic.reply_count;
+            integration.sources.forum_topic_ids.push(topic.id);
+            integration.evidence.push(Evidence {
+                source: "ha_community".to_string(),
+                kind: EvidenceKind::Thread,
+                url: topic.link(),
+                title: topic.title.clone(),
+                timestamp: parse_datetime(&topic.created_at),
+                engagement: BTreeMap::from([
+                    ("votes".to_string(), topic.vote_count),
+                    ("views".to_string(), topic.views),
+                    ("replies".to_string(), topic.reply_count),
+                    ("likes".to_string(), topic.like_count),
+                ]),
+                match_quality: m.quality,
+            });
+        }
+    }
+}
+
 /// Parse datetime string to DateTime<Utc>
 fn parse_datetime(datetime_str: &Option<String>) -> Option<DateTime<Utc>> {
-    datetime_str.as_ref().and_then(|s| {
-        DateTime::parse_from_rfc3339(s)
-            .ok()
-            .map(|dt| dt.with_timezone(&Utc))
-    })
+    datetime_str.as_deref().and_then(parse_rfc3339)
+}
+
+/// Parse an RFC 3339 timestamp
+fn parse_rfc3339(datetime_str: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(datetime_str)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
 }
 
 /// Count YouTube mentions for a given integration domain
@@ -232,19 +651,20 @@ fn count_youtube_mentions(domain: &str, youtube_data: &[YouTubeVideo]) -> u32 {
         .count() as u32
 }
 
-/// Find YouTube video IDs that mention a given integration domain
-fn find_youtube_videos(domain: &str, youtube_data: &[YouTubeVideo]) -> Vec<String> {
+/// Find YouTube videos that mention a given integration domain, with match
+/// quality (title mentions rank above description-only mentions)
+fn match_youtube_videos<'a>(domain: &str, youtube_data: &'a [YouTubeVideo]) -> Vec<(&'a YouTubeVideo, f64)> {
+    let domain_lower = domain.to_lowercase();
+
     youtube_data.iter()
         .filter_map(|video| {
             // Only process videos (not channels)
             video.id.video_id.as_ref()?;
 
-            let title_lower = video.snippet.title.to_lowercase();
-            let desc_lower = video.snippet.description.to_lowercase();
-            let domain_lower = domain.to_lowercase();
-
-            if title_lower.contains(&domain_lower) || desc_lower.contains(&domain_lower) {
-                video.id.video_id.clone()
+            if video.snippet.title.to_lowercase().contains(&domain_lower) {
+                Some((video, 1.0))
+            } else if video.snippet.description.to_lowercase().contains(&domain_lower) {
+                Some((video, 0.6))
             } else {
                 None
             }
@@ -252,3 +672,113 @@ fn find_youtube_videos(domain: &str, youtube_data: &[YouTubeVideo]) -> Vec<Strin
         .collect()
 }
 
+/// Repository evidence for a HACS integration
+fn repository_evidence(hacs_integration: &HacsIntegration, github_repo: Option<&GitHubRepo>) -> Evidence {
+    let mut engagement = BTreeMap::new();
+    if let Some(stars) = github_repo.map(|r| r.stargazers_count).or(hacs_integration.stargazers_count) {
+        engagement.insert("stars".to_string(), stars as u64);
+    }
+    if let Some(repo) = github_repo {
+        engagement.insert("forks".to_string(), repo.forks_count as u64);
+    }
+    if let Some(issues) = github_repo.map(|r| r.open_issues_count).or(hacs_integration.open_issues) {
+        engagement.insert("open_issues".to_string(), issues as u64);
+    }
+
+    Evidence {
+        source: "github".to_string(),
+        kind: EvidenceKind::Repository,
+        url: format!("https://github.com/{}", hacs_integration.full_name),
+        title: hacs_integration.full_name.clone(),
+        timestamp: parse_datetime(&hacs_integration.last_updated)
+            .or_else(|| github_repo.and_then(|r| parse_rfc3339(&r.updated_at))),
+        engagement,
+        match_quality: 1.0,
+    }
+}
+
+/// Evidence for a matched YouTube video
+fn video_evidence(video: &YouTubeVideo, quality: f64) -> Evidence {
+    Evidence {
+        source: "youtube".to_string(),
+        kind: EvidenceKind::Video,
+        url: format!("https://www.youtube.com/watch?v={}", video.id.video_id.as_deref().unwrap_or_default()),
+        title: video.snippet.title.clone(),
+        timestamp: parse_rfc3339(&video.snippet.published_at),
+        engagement: BTreeMap::new(),
+        match_quality: quality,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integration(name: &str, domain: &str) -> NormalizedIntegration {
+        NormalizedIntegration {
+            name: name.to_string(),
+            domain: Some(domain.to_string()),
+            in_hacs: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_core_coverage_and_install_counts() {
+        let mut integrations = vec![integration("Hue Sync Box", "huesyncbox"), integration("Tuya Local", "tuya_local")];
+        let manifests: Vec<CoreIntegrationManifest> = serde_json::from_value(serde_json::json!([
+            {"domain": "hue", "name": "Philips Hue"},
+            {"domain": "huesyncbox", "name": "Philips Hue Play HDMI Sync Box", "requirements": ["aiohuesyncbox==0.0.27"]},
+            {"domain": "light", "name": "Light", "integration_type": "entity"}
+        ]))
+        .unwrap();
+        apply_core_coverage(&mut integrations, &manifests);
+
+        assert_eq!(integrations[0].core_coverage.len(), 1);
+        assert_eq!(integrations[0].core_coverage[0].domain, "huesyncbox");
+        assert_eq!(integrations[0].requirements, vec!["aiohuesyncbox==0.0.27"]);
+        assert!(integrations[1].core_coverage.is_empty());
+
+        let snapshot: AnalyticsSnapshot = serde_json::from_value(serde_json::json!({
+            "integrations": {"huesyncbox": 4000},
+            "custom_integrations": {"huesyncbox": {"total": 250}, "tuya_local": {"total": 9000}}
+        }))
+        .unwrap();
+        apply_install_counts(&mut integrations, &snapshot);
+
+        assert_eq!(integrations[0].active_installs, Some(250));
+        assert_eq!(addressable_users(&integrations[0]), Some(4250));
+        assert_eq!(addressable_users(&integrations[1]), Some(9000));
+    }
+
+    #[test]
+    fn test_evidence_links() {
+        let hacs: HashMap<String, HacsIntegration> = serde_json::from_value(serde_json::json!({
+            "1": {"domain": "tado", "full_name": "user/tado", "stargazers_count": 40, "last_updated": "2025-01-10T00:00:00Z"}
+        }))
+        .unwrap();
+        let youtube: Vec<YouTubeVideo> = serde_json::from_value(serde_json::json!([
+            {"id": {"videoId": "v1"}, "snippet": {"title": "Setup guide", "channelTitle": "c", "publishedAt": "2025-01-02T00:00:00Z", "description": "Using tado with HA"}},
+            {"id": {"videoId": "v2"}, "snippet": {"title": "Tado review", "channelTitle": "c", "publishedAt": "2025-01-03T00:00:00Z", "description": ""}}
+        ]))
+        .unwrap();
+        let mut integrations = normalize_integrations(hacs, Vec::new(), youtube).unwrap();
+
+        let topics: Vec<DiscourseTopic> = serde_json::from_value(serde_json::json!([
+            {"id": 7, "title": "Tado X support", "slug": "tado-x-support", "vote_count": 12, "views": 300}
+        ]))
+        .unwrap();
+        apply_forum_signals(&mut integrations, &topics);
+
+        let evidence = &integrations[0].evidence;
+        assert_eq!(evidence.len(), 4);
+        assert_eq!(evidence[0].kind, EvidenceKind::Repository);
+        assert_eq!(evidence[0].url, "https://github.com/user/tado");
+        assert_eq!(evidence[0].engagement["stars"], 40);
+        assert_eq!(evidence[1].url, "https://www.youtube.com/watch?v=v1");
+        assert_eq!(evidence[1].match_quality, 0.6);
+        assert_eq!(evidence[2].match_quality, 1.0);
+        assert_eq!(evidence[3].url, "https://community.home-assistant.io/t/tado-x-support/7");
+        assert_eq!(evidence[3].engagement["votes"], 12);
+    }
+}
@@ -1,7 +1,13 @@
 //! Core data types for NicheFinder
 
+use crate::breakouts::Breakout;
+use crate::feature_gaps::FeatureGap;
+use crate::forecast::SignalForecast;
+use crate::lifecycle::LifecycleStage;
+use crate::scoring::ScoringData;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 /// Represents a potential integration opportunity
@@ -16,6 +22,10 @@ pub struct NicheOpportunity {
     /// Category (e.g., "smart_home_device", "cloud_service")
     pub category: String,
     
+    /// What kind of gap this opportunity represents
+    #[serde(default)]
+    pub kind: OpportunityKind,
+    
     /// Overall opportunity score (0.0 - 100.0)
     pub score: f64,
     
@@ -25,6 +35,34 @@ pub struct NicheOpportunity {
     /// Data sources used for analysis
     pub data_sources: Vec<DataSource>,
     
+    /// Verifiable items (repos, issues, videos, posts, threads) behind the opportunity
+    #[serde(default)]
+    pub evidence: Vec<Evidence>,
+    
+    /// Raw feature vector the score was computed from, for rescoring
+    #[serde(default)]
+    pub features: Option<ScoringData>,
+    
+    /// Result of every scorer when several ran as an ensemble (`score` is
+    /// then the ensemble composite)
+    #[serde(default)]
+    pub scorer_results: Vec<ScorerResult>,
+    
+    /// Projected stars, mentions and installs, when snapshot history was
+    /// available
+    #[serde(default)]
+    pub forecasts: Vec<SignalForecast>,
+    
+    /// Lifecycle stage of the niche (absent on opportunities stored before
+    /// stages were classified)
+    #[serde(default)]
+    pub lifecycle: Option<LifecycleStage>,
+    
+    /// Unmet features users request from the incumbent, most demanded first
+    /// ("differentiation opportunities" for a competing integration)
+    #[serde(default)]
+    pub differentiation: Vec<FeatureGap>,
+    
     /// When this opportunity was identified
     pub discovered_at: DateTime<Utc>,
     
@@ -32,6 +70,193 @@ pub struct NicheOpportunity {
     pub metadata: serde_json::Value,
 }
 
+/// A citable source item supporting an opportunity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Evidence {
+    /// Source key (e.g., "github", "youtube", "hackernews", "ha_community")
+    pub source: String,
+    
+    /// What the item is
+    pub kind: EvidenceKind,
+    
+    /// Link to the item
+    pub url: String,
+    
+    /// Title or name of the item
+    pub title: String,
+    
+    /// When the item was published or last active
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+    
+    /// Engagement metrics by name (stars, points, votes, views, comments...)
+    #[serde(default)]
+    pub engagement: BTreeMap<String, u64>,
+    
+    /// How well the item matched the opportunity (0.0 - 1.0)
+    pub match_quality: f64,
+}
+
+impl Evidence {
+    /// Markdown citation: link, source, date, engagement and match quality
+    pub fn markdown_citation(&self) -> String {
+        let mut details = vec![format!("{} {}", self.source, self.kind.as_str())];
+        if let Some(timestamp) = self.timestamp {
+            details.push(timestamp.format("%Y-%m-%d").to_string());
+        }
+        details.extend(self.engagement.iter().map(|(metric, value)| format!("{} {}", value, metric.replace('_', " "))));
+        details.push(format!("match {:.0}%", self.match_quality * 100.0));
+        format!("{} — {}", markdown_link(&self.title, &self.url), details.join(", "))
+    }
+}
+
+/// Markdown link with brackets in the text escaped and the characters that
+/// would end the link destination (whitespace, parentheses, angle brackets)
+/// percent-encoded
+pub(crate) fn markdown_link(text: &str, url: &str) -> String {
+    let text = text.replace('[', "\\[").replace(']', "\\]");
+    let mut destination = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            '(' | ')' | '<' | '>' => destination.push_str(&format!("%{:02X}", c as u32)),
+            c if c.is_whitespace() => destination.push_str(&format!("%{:02X}", c as u32)),
+            c => destination.push(c),
+        }
+    }
+    format!("[{}]({})", text, destination)
+}
+
+/// Kind of evidence item
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceKind {
+    /// Source code repository
+    Repository,
+    
+    /// Issue or pull request
+    Issue,
+    
+    /// Video
+    Video,
+    
+    /// Social or news post (Reddit, Hacker News)
+    Post,
+    
+    /// Forum thread
+    Thread,
+    
+    /// Package in another ecosystem
+    Package,
+}
+
+impl EvidenceKind {
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvidenceKind::Repository => "repository",
+            EvidenceKind::Issue => "issue",
+            EvidenceKind::Video => "video",
+            EvidenceKind::Post => "post",
+            EvidenceKind::Thread => "thread",
+            EvidenceKind::Package => "package",
+        }
+    }
+}
+
+/// Kind of integration gap an opportunity represents
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum OpportunityKind {
+    /// Niche around an existing HACS community integration
+    #[default]
+    CommunityIntegration,
+    
+    /// Device/vendor supported by Homebridge or openHAB but not Home Assistant
+    PortedElsewhere,
+    
+    /// Vendor whose devices are only reachable through generic Zigbee/Z-Wave/Matter support
+    ProtocolOnly,
+}
+
+impl OpportunityKind {
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpportunityKind::CommunityIntegration => "community_integration",
+            OpportunityKind::PortedElsewhere => "ported_elsewhere",
+            OpportunityKind::ProtocolOnly => "protocol_only",
+        }
+    }
+}
+
+impl NicheOpportunity {
+    /// Spread between the best and worst rank any scorer gave this
+    /// opportunity; `None` unless several scorers ran
+    pub fn rank_spread(&self) -> Option<f64> {
+        if self.scorer_results.len() < 2 {
+            return None;
+        }
+        let ranks = self.scorer_results.iter().map(|r| r.rank);
+        Some(ranks.clone().fold(f64::MIN, f64::max) - ranks.fold(f64::MAX, f64::min))
+    }
+}
+
+/// One scorer's result for an opportunity within an ensemble
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScorerResult {
+    /// Scorer name (`OpportunityScorer::name`)
+    pub scorer: String,
+    
+    /// Score the scorer produced
+    pub score: IntegrationScore,
+    
+    /// Rank among all candidates by this scorer's composite (1 = best);
+    /// tied candidates share their average rank
+    pub rank: f64,
+}
+
+/// How an ensemble combines its scorers' composites
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EnsembleMethod {
+    /// Mean of the composites
+    #[default]
+    Mean,
+    
+    /// Median of the composites
+    Median,
+    
+    /// Mean rank across scorers, scaled to 0-100 (robust to scorers on different scales)
+    RankAverage,
+}
+
+impl EnsembleMethod {
+    /// Stable identifier, matching the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnsembleMethod::Mean => "mean",
+            EnsembleMethod::Median => "median",
+            EnsembleMethod::RankAverage => "rank_average",
+        }
+    }
+}
+
+impl std::str::FromStr for EnsembleMethod {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.trim().replace('-', "_").as_str() {
+            "mean" => Ok(EnsembleMethod::Mean),
+            "median" => Ok(EnsembleMethod::Median),
+            "rank_average" => Ok(EnsembleMethod::RankAverage),
+            other => Err(crate::Error::Config(format!(
+                "Unknown ensemble method '{}' (expected mean, median or rank_average)",
+                other
+            ))),
+        }
+    }
+}
+
 /// Detailed scoring breakdown for an integration opportunity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntegrationScore {
@@ -50,8 +275,10 @@ pub struct IntegrationScore {
     /// Weighted composite score
     pub composite: f64,
     
-    /// Weights used for composite calculation
-    pub weights: ScoreWeights,
+    /// Weights used for composite calculation; `None` for an ensemble,
+    /// whose composite is not a weighted sum
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weights: Option<ScoreWeights>,
 }
 
 /// Weights for composite score calculation
@@ -63,6 +290,20 @@ pub struct ScoreWeights {
     pub trend: f64,
 }
 
+impl ScoreWeights {
+    /// Check that the weights are finite, non-negative and not all zero
+    pub fn validate(&self) -> crate::Result<()> {
+        let weights = [self.demand, self.feasibility, self.competition, self.trend];
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(crate::Error::Config("Score weights must be finite and non-negative".to_string()));
+        }
+        if weights.iter().sum::<f64>() <= 0.0 {
+            return Err(crate::Error::Config("Score weights must not all be zero".to_string()));
+        }
+        Ok(())
+    }
+}
+
 impl Default for ScoreWeights {
     fn default() -> Self {
         Self {
@@ -77,7 +318,12 @@ impl Default for ScoreWeights {
 /// Data source information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataSource {
-    /// Source name (e.g., "github", "reddit", "hacs")
+    /// Source key of the input, as in `AnalysisResult::provenance`
+    /// (e.g., "github", "reddit", "hacs")
+    #[serde(default)]
+    pub source: String,
+    
+    /// Display name (e.g., "GitHub", "Reddit", "HACS")
     pub name: String,
     
     /// Source type
@@ -89,10 +335,45 @@ pub struct DataSource {
     /// Number of data points collected
     pub data_points: usize,
     
+    /// Source file or artifact id the data was read from
+    #[serde(default)]
+    pub artifact: Option<String>,
+    
+    /// Search query or request used to collect the data
+    #[serde(default)]
+    pub query: Option<String>,
+    
+    /// Records in the whole source input (`data_points` are the matched ones)
+    #[serde(default)]
+    pub source_records: usize,
+    
     /// Source-specific metadata
     pub metadata: serde_json::Value,
 }
 
+impl DataSource {
+    /// Age of the data at `now`, in whole days
+    pub fn age_days(&self, now: DateTime<Utc>) -> i64 {
+        (now - self.collected_at).num_days()
+    }
+}
+
+/// Where an analysis input came from
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SourceProvenance {
+    /// When the data was collected
+    pub collected_at: Option<DateTime<Utc>>,
+    
+    /// Source file path or artifact id
+    pub artifact: Option<String>,
+    
+    /// Search query or request used to collect the data
+    pub query: Option<String>,
+    
+    /// Records in the source input
+    pub records: usize,
+}
+
 /// Type of data source
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -106,10 +387,57 @@ pub enum DataSourceType {
     /// Home Assistant Community Store
     Hacs,
     
+    /// Home Assistant core integration manifests
+    HaCore,
+    
+    /// Home Assistant analytics install counts
+    HaAnalytics,
+    
+    /// Homebridge plugins (npm registry)
+    Homebridge,
+    
+    /// openHAB add-ons
+    OpenHab,
+    
+    /// Protocol device catalogs (zigbee2mqtt, Z-Wave JS, Matter)
+    DeviceCatalog,
+    
+    /// Imported internal data (CSV/JSON Lines), by source name
+    Custom(String),
+    
+    /// Hacker News stories/comments (Algolia search)
+    HackerNews,
+    
+    /// Home Assistant Community forum (Discourse) topics
+    Forum,
+    
     /// Other source
     Other(String),
 }
 
+/// Source keys understood by `AnalysisConfig::enabled_sources` and
+/// `AnalysisConfig::disabled_sources`
+///
+/// Custom sources are enabled by their own name or by `custom`.
+pub const BUILTIN_SOURCES: &[&str] = &[
+    "hacs",
+    "github",
+    "youtube",
+    "hackernews",
+    "reddit",
+    "ha_community",
+    "ha_core",
+    "ha_analytics",
+    "pypi",
+    "github_maintenance",
+    "homebridge",
+    "openhab",
+    "zigbee2mqtt",
+    "zwave_js",
+    "matter",
+    "custom",
+];
+
 /// Configuration for niche analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisConfig {
@@ -122,11 +450,81 @@ pub struct AnalysisConfig {
     /// Score weights
     pub weights: ScoreWeights,
     
-    /// Data sources to use
+    /// Data sources to use (see `BUILTIN_SOURCES`); missing or empty uses every source
+    #[serde(default)]
     pub enabled_sources: Vec<String>,
     
-    /// Time range for analysis (days)
+    /// Data sources to skip, even when enabled
+    #[serde(default)]
+    pub disabled_sources: Vec<String>,
+    
+    /// Time range for analysis (days); timestamped signals older than this
+    /// are ignored, 0 disables the window
     pub time_range_days: u32,
+    
+    /// Scorer to use instead of the default weighted scorer, by name: a
+    /// weight profile or a script (`<scorer_dir>/<name>.rhai`)
+    #[serde(default)]
+    pub scorer: Option<String>,
+    
+    /// Directory holding scoring scripts
+    #[serde(default = "default_scorer_dir")]
+    pub scorer_dir: String,
+    
+    /// Scorers to run side by side as an ensemble, by name ("weighted", a
+    /// weight profile or a script name); empty runs the single `scorer`
+    #[serde(default)]
+    pub scorers: Vec<String>,
+    
+    /// Named score weights (e.g. learned from team labels), selectable as a
+    /// weighted scorer by their name
+    #[serde(default)]
+    pub weight_profiles: BTreeMap<String, ScoreWeights>,
+    
+    /// How ensemble composites are combined
+    #[serde(default)]
+    pub ensemble_method: EnsembleMethod,
+    
+    /// Days ahead that signals are forecast when snapshot history is given
+    #[serde(default = "default_forecast_horizon_days")]
+    pub forecast_horizon_days: u32,
+    
+    /// Lifecycle stages to report; empty reports every stage
+    #[serde(default)]
+    pub lifecycle_stages: Vec<LifecycleStage>,
+}
+
+fn default_scorer_dir() -> String {
+    "scorers".to_string()
+}
+
+fn default_forecast_horizon_days() -> u32 {
+    crate::forecast::DEFAULT_HORIZON_DAYS
+}
+
+impl AnalysisConfig {
+    /// Whether a source key is enabled (case-insensitive): listed in
+    /// `enabled_sources`, or that list is empty, and not disabled
+    pub fn source_enabled(&self, source: &str) -> bool {
+        let enabled = self.enabled_sources.is_empty()
+            || self.enabled_sources.iter().any(|s| s.eq_ignore_ascii_case(source));
+        enabled && !self.source_disabled(source)
+    }
+    
+    /// Whether a source key is listed in `disabled_sources` (case-insensitive)
+    pub fn source_disabled(&self, source: &str) -> bool {
+        self.disabled_sources.iter().any(|s| s.eq_ignore_ascii_case(source))
+    }
+    
+    /// Whether opportunities in a lifecycle stage are reported
+    pub fn stage_enabled(&self, stage: LifecycleStage) -> bool {
+        self.lifecycle_stages.is_empty() || self.lifecycle_stages.contains(&stage)
+    }
+    
+    /// Oldest timestamp a signal may have to be counted, relative to `now`
+    pub fn window_start(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        (self.time_range_days > 0).then(|| now - chrono::Duration::days(i64::from(self.time_range_days)))
+    }
 }
 
 impl Default for AnalysisConfig {
@@ -135,12 +533,16 @@ impl Default for AnalysisConfig {
             min_score: 50.0,
             max_results: 20,
             weights: ScoreWeights::default(),
-            enabled_sources: vec![
-                "github".to_string(),
-                "reddit".to_string(),
-                "hacs".to_string(),
-            ],
+            enabled_sources: Vec::new(),
+            disabled_sources: Vec::new(),
             time_range_days: 90,
+            scorer: None,
+            scorer_dir: default_scorer_dir(),
+            scorers: Vec::new(),
+            weight_profiles: BTreeMap::new(),
+            ensemble_method: EnsembleMethod::default(),
+            forecast_horizon_days: default_forecast_horizon_days(),
+            lifecycle_stages: Vec::new(),
         }
     }
 }
@@ -159,6 +561,28 @@ pub struct AnalysisResult {
     
     /// Analysis metadata
     pub metadata: AnalysisMetadata,
+    
+    /// Significant signal spikes per integration and vendor, when snapshot
+    /// history was available
+    #[serde(default)]
+    pub breakouts: Vec<Breakout>,
+    
+    /// Provenance of every loaded input, by source key; data sources refer
+    /// to it through `DataSource::source`
+    #[serde(default)]
+    pub provenance: BTreeMap<String, SourceProvenance>,
+}
+
+impl AnalysisResult {
+    /// Opportunities whose scorers disagree most, by rank spread
+    pub fn scorer_disagreements(&self, limit: usize) -> Vec<&NicheOpportunity> {
+        let mut disputed: Vec<(&NicheOpportunity, f64)> = self.opportunities
+            .iter()
+            .filter_map(|o| o.rank_spread().filter(|&spread| spread > 0.0).map(|spread| (o, spread)))
+            .collect();
+        disputed.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        disputed.into_iter().take(limit).map(|(o, _)| o).collect()
+    }
 }
 
 /// Metadata about the analysis run
@@ -175,5 +599,46 @@ pub struct AnalysisMetadata {
     
     /// Data sources used
     pub sources_used: Vec<String>,
+    
+    /// Per-source collection status, when collectors ran as part of the analysis
+    #[serde(default)]
+    pub source_status: Vec<SourceStatus>,
+}
+
+/// Outcome of collecting a single source
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceState {
+    /// Collected completely
+    Ok,
+    
+    /// Failed part-way; the records gathered before the error are used
+    Partial,
+    
+    /// Failed or timed out without usable records
+    Failed,
+}
+
+/// Collection status of a single source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceStatus {
+    /// Source name (`DataCollector::source_name`)
+    pub source: String,
+    
+    /// Outcome
+    pub state: SourceState,
+    
+    /// Records collected
+    pub records: usize,
+    
+    /// Search query or request the collector used
+    #[serde(default)]
+    pub query: Option<String>,
+    
+    /// Error message for partial and failed sources
+    pub error: Option<String>,
+    
+    /// Time spent collecting, in seconds
+    pub duration_secs: f64,
 }
 
@@ -0,0 +1,103 @@
+//! Integration tests for declarative REST sources
+
+use nichefinder_core::collectors::DataCollector;
+use nichefinder_core::declarative::{AuthScheme, DeclarativeCollector, Pagination, SourceDefinition};
+
+const DEFINITION: &str = r#"
+name: vendor_forum
+base_url: https://forum.example.com/api
+auth:
+  type: header
+  name: X-Api-Key
+  value_env: NICHEFINDER_TEST_VENDOR_KEY
+endpoints:
+  - path: /threads
+    data_type: thread
+    params: { tag: integrations }
+    pagination: { style: cursor, param: after, next: $.meta.next, max_pages: 3 }
+    records: $.data[*]
+    mapping:
+      id: $.id
+      observed_at: $.created
+      fields:
+        title: $.attributes.title
+        votes: $.attributes.votes
+"#;
+
+#[test]
+fn test_definition_parsing() {
+    let definition = SourceDefinition::from_yaml_str(DEFINITION).expect("Failed to parse YAML definition");
+    assert_eq!(definition.name, "vendor_forum");
+    assert_eq!(definition.timeout_seconds, 30);
+    assert!(matches!(definition.auth, AuthScheme::Header { .. }));
+    assert!(matches!(definition.endpoints[0].pagination, Pagination::Cursor { max_pages: 3, .. }));
+
+    let toml_definition = SourceDefinition::from_toml_str(
+        r#"
+        name = "catalog"
+        base_url = "https://catalog.example.com"
+
+        [[endpoints]]
+        path = "/devices"
+        data_type = "device"
+        records = "$[*]"
+        pagination = { style = "offset", param = "offset", limit_param = "limit", limit = 100, max_pages = 10 }
+        "#,
+    )
+    .expect("Failed to parse TOML definition");
+    assert!(matches!(toml_definition.auth, AuthScheme::None));
+    assert!(matches!(toml_definition.endpoints[0].pagination, Pagination::Offset { limit: 100, .. }));
+}
+
+#[tokio::test]
+async fn test_response_mapping() {
+    std::env::set_var("NICHEFINDER_TEST_VENDOR_KEY", "secret");
+    let definition = SourceDefinition::from_yaml_str(DEFINITION).unwrap();
+    let collector = DeclarativeCollector::new(definition).await.expect("Failed to create collector");
+    assert_eq!(collector.source_name(), "vendor_forum");
+
+    let response = serde_json::json!({
+        "data": [
+            {"id": 1, "created": "2025-03-01T12:00:00Z", "attributes": {"title": "Bosch dishwasher", "votes": 42}},
+            {"id": 2, "attributes": {"title": "Miele oven"}}
+        ],
+        "meta": {"next": "abc"}
+    });
+
+    let records = collector.map_response(0, &response).expect("Failed to map response");
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].source, "vendor_forum");
+    assert_eq!(records[0].data_type, "thread");
+    assert_eq!(records[0].raw_data["title"], "Bosch dishwasher");
+    assert_eq!(records[0].raw_data["votes"], 42);
+    assert_eq!(records[0].raw_data["id"], 1);
+    // The record's own time is kept apart from when it was fetched
+    assert_eq!(records[0].raw_data["observed_at"], "2025-03-01T12:00:00+00:00");
+    assert!(records[0].collected_at > chrono::Utc::now() - chrono::Duration::minutes(1));
+    assert!(records[1].raw_data.get("observed_at").is_none());
+    assert!(records[1].raw_data["votes"].is_null());
+}
+
+#[tokio::test]
+async fn test_missing_auth_env() {
+    let definition = SourceDefinition::from_yaml_str(
+        &DEFINITION.replace("NICHEFINDER_TEST_VENDOR_KEY", "NICHEFINDER_TEST_UNSET_KEY"),
+    )
+    .unwrap();
+    assert!(DeclarativeCollector::new(definition).await.is_err());
+}
+
+#[tokio::test]
+#[ignore] // Ignore by default since it requires network access
+async fn test_example_source_collect() {
+    let collector = DeclarativeCollector::from_file("../../sources/lemmy_homeassistant.yaml")
+        .await
+        .expect("Failed to create collector");
+
+    let collected_data = collector.collect().await.expect("Failed to collect data");
+    assert!(!collected_data.is_empty(), "Expected at least some collected data");
+    for item in collected_data.iter().take(3) {
+        assert_eq!(item.source, "lemmy_homeassistant");
+        assert!(item.raw_data.get("title").is_some());
+    }
+}
@@ -0,0 +1,62 @@
+//! Integration tests for the CSV / JSON Lines file collector
+
+use nichefinder_core::collectors::DataCollector;
+use nichefinder_core::custom_sources::{FileCollector, FileFormat, FileSourceConfig};
+use std::fs;
+
+#[tokio::test]
+async fn test_file_collector_csv_and_jsonl() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    fs::write(
+        root.join("tickets.csv"),
+        "product, aliases, tickets, week\n\
+         Bosch dishwasher,home connect;bosch,\"1,200\",2025-02-03\n\
+         ,orphan row,5,\n\
+         Miele oven,,3,2025-02-10T09:00:00Z\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("survey.yaml"),
+        "name: support_tickets\npath: tickets.csv\nweight: 0.5\ncolumns:\n  name: product\n  alias: aliases\n  value: tickets\n  timestamp: week\n",
+    )
+    .unwrap();
+
+    // Relative data paths resolve against the config file
+    let collector = FileCollector::from_config_file(root.join("survey.yaml")).expect("Failed to load config");
+    assert_eq!(collector.source_name(), "support_tickets");
+    assert_eq!(collector.config().file_format().unwrap(), FileFormat::Csv);
+
+    let signals = collector.read_signals().expect("Failed to read CSV");
+    assert_eq!(signals.len(), 2);
+    assert_eq!(signals[0].aliases, vec!["home connect", "bosch"]);
+    assert_eq!(signals[0].value, 1200.0);
+    assert!(signals[0].timestamp.is_some());
+    assert_eq!(signals[1].value, 3.0);
+
+    let collected_data = collector.collect().await.expect("Failed to collect data");
+    assert_eq!(collected_data.len(), 2);
+    assert_eq!(collected_data[0].data_type, "signal");
+    // The row's timestamp stays in the signal; collected_at is the read time
+    assert_eq!(collected_data[0].raw_data["timestamp"], "2025-02-03T00:00:00Z");
+    assert!(collected_data[0].collected_at > chrono::Utc::now() - chrono::Duration::minutes(1));
+
+    fs::write(
+        root.join("requests.jsonl"),
+        "{\"vendor\": \"Eufy\", \"names\": [\"eufy security\"], \"votes\": 7}\n\n{\"vendor\": \"Aqara\"}\n",
+    )
+    .unwrap();
+    let collector = FileCollector::new(FileSourceConfig {
+        name: "sales_requests".to_string(),
+        path: root.join("requests.jsonl"),
+        format: None,
+        columns: serde_json::from_value(serde_json::json!({"name": "vendor", "alias": "names", "value": "votes"})).unwrap(),
+        weight: 1.0,
+    });
+
+    let signals = collector.read_signals().expect("Failed to read JSON Lines");
+    assert_eq!(signals.len(), 2);
+    assert_eq!(signals[0].aliases, vec!["eufy security"]);
+    assert_eq!(signals[0].value, 7.0);
+    assert_eq!(signals[1].value, 1.0);
+}
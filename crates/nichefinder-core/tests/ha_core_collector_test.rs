@@ -0,0 +1,63 @@
+//! Integration tests for the Home Assistant core inventory collector
+
+use nichefinder_core::collectors::{CoreInventorySource, DataCollector, HaCoreCollector};
+use std::fs;
+
+#[tokio::test]
+async fn test_ha_core_collector_local_checkout() {
+    // Minimal fake checkout with one device integration and one entity platform
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    let components = root.join("homeassistant").join("components");
+    fs::create_dir_all(components.join("hue")).unwrap();
+    fs::create_dir_all(components.join("light")).unwrap();
+    fs::create_dir_all(components.join("broken")).unwrap();
+    fs::write(
+        components.join("hue").join("manifest.json"),
+        r#"{"domain": "hue", "name": "Philips Hue", "config_flow": true, "iot_class": "local_push",
+            "quality_scale": "platinum", "integration_type": "hub", "requirements": ["aiohue==4.7.3"]}"#,
+    )
+    .unwrap();
+    fs::write(
+        components.join("light").join("manifest.json"),
+        r#"{"domain": "light", "name": "Light", "integration_type": "entity"}"#,
+    )
+    .unwrap();
+    // A bad manifest is skipped, not fatal to the inventory
+    fs::write(components.join("broken").join("manifest.json"), "{ not json").unwrap();
+
+    let collector = HaCoreCollector::new(CoreInventorySource::LocalCheckout(root.to_path_buf()))
+        .await
+        .expect("Failed to create collector");
+    assert_eq!(collector.source_name(), "ha_core");
+
+    let manifests = collector.fetch_manifests().await.expect("Failed to read manifests");
+    assert_eq!(manifests.len(), 2);
+    assert_eq!(manifests[0].domain, "hue");
+    assert!(manifests[0].config_flow);
+    assert_eq!(manifests[0].requirements, vec!["aiohue==4.7.3"]);
+    assert!(manifests[0].is_device_or_service());
+    assert!(!manifests[1].is_device_or_service());
+
+    let collected_data = collector.collect().await.expect("Failed to collect data");
+    assert_eq!(collected_data.len(), 2);
+    assert_eq!(collected_data[0].source, "ha_core");
+    assert_eq!(collected_data[0].data_type, "manifest");
+}
+
+#[tokio::test]
+#[ignore] // Ignore by default since it requires network access
+async fn test_ha_core_collector_github() {
+    let token = std::env::var("GITHUB_TOKEN").ok();
+    let collector = HaCoreCollector::new(CoreInventorySource::GitHub {
+        git_ref: "dev".to_string(),
+        token,
+    })
+    .await
+    .expect("Failed to create collector");
+
+    let manifests = collector.fetch_manifests().await;
+    assert!(manifests.is_ok(), "Failed to fetch manifests: {:?}", manifests.err());
+    // The contents API listing stops at 1000 entries; the git tree does not
+    assert!(manifests.unwrap().len() > 1000, "Expected a full component inventory");
+}
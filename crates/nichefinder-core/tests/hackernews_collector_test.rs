@@ -0,0 +1,51 @@
+//! Integration tests for Hacker News data collector
+
+use nichefinder_core::collectors::{DataCollector, HackerNewsCollector};
+
+#[tokio::test]
+async fn test_hackernews_collector_creation() {
+    let collector = HackerNewsCollector::new(vec!["Philips Hue".to_string()]).await;
+    assert!(collector.is_ok(), "Failed to create Hacker News collector: {:?}", collector.err());
+
+    let collector = collector.unwrap();
+    assert_eq!(collector.source_name(), "hackernews");
+    assert_eq!(collector.queries(), &["home assistant", "home assistant Philips Hue"]);
+}
+
+#[tokio::test]
+#[ignore] // Ignore by default since it requires network access
+async fn test_hackernews_search() {
+    let collector = HackerNewsCollector::new(Vec::new()).await.expect("Failed to create collector");
+
+    let result = collector.search("home assistant", "story", 10).await;
+    assert!(result.is_ok(), "Failed to search Hacker News: {:?}", result.err());
+
+    let result = result.unwrap();
+    println!("Found {} stories", result.nb_hits);
+    for hit in result.hits.iter().take(5) {
+        println!("  - {} (points: {:?}, comments: {:?})",
+            hit.title.as_deref().unwrap_or("untitled"),
+            hit.points,
+            hit.num_comments
+        );
+    }
+
+    assert!(!result.hits.is_empty(), "Expected at least some stories");
+}
+
+#[tokio::test]
+#[ignore] // Ignore by default since it requires network access
+async fn test_hackernews_collector_trait() {
+    let collector = HackerNewsCollector::new(Vec::new()).await.expect("Failed to create collector");
+
+    let collected_data = collector.collect().await;
+    assert!(collected_data.is_ok(), "Failed to collect data: {:?}", collected_data.err());
+
+    let collected_data = collected_data.unwrap();
+    assert!(!collected_data.is_empty(), "Expected at least some collected data");
+
+    for item in collected_data.iter().take(3) {
+        assert_eq!(item.source, "hackernews");
+        assert!(item.data_type == "story" || item.data_type == "comment");
+    }
+}
@@ -34,7 +34,7 @@ async fn test_hacs_fetch_integrations() {
 async fn test_hacs_collector_trait() {
     let collector = HacsCollector::new().await.expect("Failed to create collector");
     
-    assert_eq!(collector.source_name(), "HACS");
+    assert_eq!(collector.source_name(), "hacs");
     
     let collected_data = collector.collect().await;
     assert!(collected_data.is_ok(), "Failed to collect data: {:?}", collected_data.err());
@@ -51,6 +51,10 @@ pub fn create_router(db_pool: SqlitePool) -> Router {
     Router::new()
         .route("/health", get(health_check))
         .route("/api/opportunities", get(get_opportunities))
+        .route("/api/opportunities/rescore", post(rescore_opportunities))
+        .route("/api/opportunities/{id}/labels", post(label_opportunity))
+        .route("/api/labels", get(get_labels))
+        .route("/api/labels/train", post(train_weights))
         .route("/api/analyze", post(trigger_analysis))
         // System endpoints
         .route("/api/system/status", get(get_system_status))
@@ -119,6 +123,9 @@ async fn health_check() -> impl IntoResponse {
 struct OpportunitiesQuery {
     #[serde(default = "default_limit")]
     limit: i64,
+    /// Only opportunities in this lifecycle stage
+    #[serde(default)]
+    stage: Option<nichefinder_core::lifecycle::LifecycleStage>,
 }
 
 fn default_limit() -> i64 {
@@ -130,7 +137,7 @@ async fn get_opportunities(
     State(state): State<Arc<AppState>>,
     Query(params): Query<OpportunitiesQuery>,
 ) -> Result<Json<OpportunitiesResponse>, AppError> {
-    let opportunities = crate::db::get_top_opportunities(&state.db_pool, params.limit).await?;
+    let opportunities = crate::db::get_top_opportunities(&state.db_pool, params.limit, params.stage).await?;
 
     Ok(Json(OpportunitiesResponse { opportunities }))
 }
@@ -141,6 +148,156 @@ struct OpportunitiesResponse {
     opportunities: Vec<nichefinder_core::NicheOpportunity>,
 }
 
+/// Request for a what-if rescoring of stored opportunities
+#[derive(Debug, Deserialize)]
+struct RescoreRequest {
+    /// Composite weights to score with (defaults when omitted)
+    #[serde(default)]
+    weights: Option<nichefinder_core::types::ScoreWeights>,
+    /// Weight profile or scoring script to use instead of the weighted scorer, by name
+    #[serde(default)]
+    scorer: Option<String>,
+    /// Scorers to run side by side ("weighted", weight profile or script names)
+    #[serde(default)]
+    scorers: Vec<String>,
+    /// How ensemble scores are combined
+    #[serde(default)]
+    ensemble_method: nichefinder_core::EnsembleMethod,
+    #[serde(default = "default_limit")]
+    limit: i64,
+}
+
+/// Response for rescore endpoint
+#[derive(Debug, Serialize)]
+struct RescoreResponse {
+    opportunities: Vec<nichefinder_core::NicheOpportunity>,
+    /// Stored opportunities left out because they have no feature vector
+    skipped: usize,
+}
+
+/// Rescore stored opportunities from their feature vectors
+///
+/// Only the newest row of each opportunity is rescored. Nothing is
+/// re-collected or re-normalized and stored scores are left unchanged; the
+/// re-ranked list is only returned.
+async fn rescore_opportunities(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<RescoreRequest>,
+) -> Result<Json<RescoreResponse>, AppError> {
+    let weights = request.weights.unwrap_or_default();
+    weights.validate().map_err(AppError::bad_request)?;
+
+    let config = nichefinder_core::AnalysisConfig {
+        weights,
+        scorer: request.scorer,
+        scorers: request.scorers,
+        weight_profiles: crate::db::get_weight_profiles(&state.db_pool).await?,
+        ensemble_method: request.ensemble_method,
+        ..Default::default()
+    };
+    let ensemble = nichefinder_core::Ensemble::from_config(&config).map_err(AppError::bad_request)?;
+
+    let mut opportunities = crate::db::get_latest_opportunities(&state.db_pool).await?;
+    let skipped = nichefinder_core::scoring::rescore(&mut opportunities, &ensemble)?;
+    tracing::info!("Rescored {} opportunities ({} without features skipped)", opportunities.len(), skipped);
+
+    opportunities.truncate(request.limit.max(0) as usize);
+    Ok(Json(RescoreResponse { opportunities, skipped }))
+}
+
+/// Request to label an opportunity
+#[derive(Debug, Deserialize)]
+struct LabelRequest {
+    /// "up", "down" or {"rating": 1-5}
+    value: nichefinder_core::training::LabelValue,
+    #[serde(default)]
+    labeled_by: Option<String>,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+/// Record a team label for an opportunity
+async fn label_opportunity(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<uuid::Uuid>,
+    Json(request): Json<LabelRequest>,
+) -> Result<Json<nichefinder_core::training::OpportunityLabel>, AppError> {
+    if request.value.target().is_none() {
+        return Err(AppError::bad_request(anyhow::anyhow!("Ratings must be between 1 and 5")));
+    }
+    if !crate::db::opportunity_exists(&state.db_pool, id).await? {
+        return Err(AppError::not_found(anyhow::anyhow!("Opportunity {} not found", id)));
+    }
+
+    let label = nichefinder_core::training::OpportunityLabel {
+        opportunity_id: id,
+        value: request.value,
+        labeled_by: request.labeled_by,
+        note: request.note,
+        labeled_at: chrono::Utc::now(),
+    };
+    crate::db::save_label(&state.db_pool, &label).await?;
+
+    Ok(Json(label))
+}
+
+/// Response for labels endpoint
+#[derive(Debug, Serialize)]
+struct LabelsResponse {
+    labels: Vec<nichefinder_core::training::OpportunityLabel>,
+}
+
+/// Get every team label
+async fn get_labels(State(state): State<Arc<AppState>>) -> Result<Json<LabelsResponse>, AppError> {
+    let labels = crate::db::get_labels(&state.db_pool).await?;
+
+    Ok(Json(LabelsResponse { labels }))
+}
+
+/// Request to learn weights from the team labels
+#[derive(Debug, Deserialize)]
+struct TrainRequest {
+    /// Name of the learned scoring profile
+    #[serde(default = "default_profile_name")]
+    name: String,
+    /// Weights the learned profile is compared against (defaults when omitted)
+    #[serde(default)]
+    baseline: Option<nichefinder_core::types::ScoreWeights>,
+}
+
+fn default_profile_name() -> String {
+    "learned".to_string()
+}
+
+/// Fit score weights to the team labels over the persisted feature vectors
+///
+/// Labels of opportunities stored by several runs use the newest copy's
+/// features. Stores the learned profile under its name, so rescoring can
+/// select it as a scorer, and returns it with its fit report; too few labels
+/// or a reserved name are rejected as a bad request.
+async fn train_weights(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<TrainRequest>,
+) -> Result<Json<nichefinder_core::training::TrainedProfile>, AppError> {
+    let opportunities = crate::db::get_all_opportunities(&state.db_pool).await?;
+    let labels = crate::db::get_labels(&state.db_pool).await?;
+    let examples = nichefinder_core::training::labeled_examples(&opportunities, &labels);
+
+    let profile = nichefinder_core::training::WeightTrainer::new()
+        .with_baseline(request.baseline.unwrap_or_default())
+        .fit(&request.name, &examples)
+        .map_err(AppError::bad_request)?;
+    crate::db::save_weight_profile(&state.db_pool, &profile).await?;
+    tracing::info!(
+        "Trained weights '{}' from {} labels (accuracy {:.2})",
+        profile.name,
+        profile.report.examples,
+        profile.report.accuracy
+    );
+
+    Ok(Json(profile))
+}
+
 /// Request for analysis trigger
 #[derive(Debug, Deserialize)]
 struct AnalysisRequest {
@@ -232,16 +389,92 @@ async fn trigger_analysis(
     download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &github_artifact.id, &github_path).await?;
     download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &youtube_artifact.id, &youtube_path).await?;
 
+    let mut inputs = nichefinder_core::AnalysisInputs::new(
+        hacs_path.to_str().unwrap(),
+        github_path.to_str().unwrap(),
+        youtube_path.to_str().unwrap(),
+    );
+
+    // Optional sources - only used when the workflow produced them
+    let hackernews_path = temp_dir.join(format!("hackernews_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "search_hackernews") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &hackernews_path).await?;
+        inputs.hackernews_path = Some(hackernews_path.to_str().unwrap().to_string());
+    }
+    let core_inventory_path = temp_dir.join(format!("ha_core_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_ha_core_integrations") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &core_inventory_path).await?;
+        inputs.core_inventory_path = Some(core_inventory_path.to_str().unwrap().to_string());
+    }
+    let analytics_path = temp_dir.join(format!("ha_analytics_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_ha_analytics") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &analytics_path).await?;
+        inputs.analytics_path = Some(analytics_path.to_str().unwrap().to_string());
+    }
+    let forum_path = temp_dir.join(format!("forum_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_ha_community_requests") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &forum_path).await?;
+        inputs.forum_path = Some(forum_path.to_str().unwrap().to_string());
+    }
+    let homebridge_path = temp_dir.join(format!("homebridge_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_homebridge_plugins") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &homebridge_path).await?;
+        inputs.homebridge_path = Some(homebridge_path.to_str().unwrap().to_string());
+    }
+    let openhab_path = temp_dir.join(format!("openhab_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_openhab_addons") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &openhab_path).await?;
+        inputs.openhab_path = Some(openhab_path.to_str().unwrap().to_string());
+    }
+    let zigbee_path = temp_dir.join(format!("zigbee2mqtt_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_zigbee2mqtt_devices") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &zigbee_path).await?;
+        inputs.zigbee_path = Some(zigbee_path.to_str().unwrap().to_string());
+    }
+    let zwave_path = temp_dir.join(format!("zwave_js_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_zwave_js_devices") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &zwave_path).await?;
+        inputs.zwave_path = Some(zwave_path.to_str().unwrap().to_string());
+    }
+    let matter_path = temp_dir.join(format!("matter_{}.json", request.execution_id));
+    if let Some(artifact) = artifacts.iter().find(|a| a.step_id == "fetch_matter_products") {
+        download_artifact(&state, &client, &peg_engine_url, &request.execution_id, &artifact.id, &matter_path).await?;
+        inputs.matter_path = Some(matter_path.to_str().unwrap().to_string());
+    }
+
     tracing::info!("Downloaded all artifacts to temp directory");
 
+    // Artifact ids and creation times are the provenance of each input
+    for (step_id, source) in [
+        ("fetch_hacs_integrations", "hacs"),
+        ("search_github_repos", "github"),
+        ("search_youtube_videos", "youtube"),
+        ("search_hackernews", "hackernews"),
+        ("fetch_ha_core_integrations", "ha_core"),
+        ("fetch_ha_analytics", "ha_analytics"),
+        ("fetch_ha_community_requests", "ha_community"),
+        ("fetch_homebridge_plugins", "homebridge"),
+        ("fetch_openhab_addons", "openhab"),
+        ("fetch_zigbee2mqtt_devices", "zigbee2mqtt"),
+        ("fetch_zwave_js_devices", "zwave_js"),
+        ("fetch_matter_products", "matter"),
+    ] {
+        if let Some(artifact) = artifacts.iter().find(|a| a.step_id == step_id) {
+            inputs.provenance.insert(
+                source.to_string(),
+                nichefinder_core::types::SourceProvenance {
+                    collected_at: artifact.created_at,
+                    artifact: Some(format!("{}/{}", request.execution_id, artifact.id)),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
     // Run analysis
     let analyzer = nichefinder_core::IntegrationAnalyzer::new();
     let result = analyzer
-        .analyze_from_files(
-            hacs_path.to_str().unwrap(),
-            github_path.to_str().unwrap(),
-            youtube_path.to_str().unwrap(),
-        )
+        .analyze_from_inputs(&inputs)
         .map_err(|e| anyhow::anyhow!("Analysis failed: {}", e))?;
 
     tracing::info!(
@@ -261,6 +494,15 @@ async fn trigger_analysis(
     let _ = tokio::fs::remove_file(&hacs_path).await;
     let _ = tokio::fs::remove_file(&github_path).await;
     let _ = tokio::fs::remove_file(&youtube_path).await;
+    let _ = tokio::fs::remove_file(&hackernews_path).await;
+    let _ = tokio::fs::remove_file(&forum_path).await;
+    let _ = tokio::fs::remove_file(&core_inventory_path).await;
+    let _ = tokio::fs::remove_file(&analytics_path).await;
+    let _ = tokio::fs::remove_file(&homebridge_path).await;
+    let _ = tokio::fs::remove_file(&openhab_path).await;
+    let _ = tokio::fs::remove_file(&zigbee_path).await;
+    let _ = tokio::fs::remove_file(&zwave_path).await;
+    let _ = tokio::fs::remove_file(&matter_path).await;
 
     Ok(Json(AnalysisResponse {
         status: "completed".to_string(),
@@ -343,6 +585,8 @@ struct ArtifactMetadata {
     id: String,
     step_id: String,
     name: String,
+    #[serde(default)]
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// Response for analysis trigger
@@ -356,14 +600,29 @@ struct AnalysisResponse {
 
 /// API error type
 #[derive(Debug)]
-struct AppError(anyhow::Error);
+struct AppError {
+    status: StatusCode,
+    error: anyhow::Error,
+}
+
+impl AppError {
+    /// The request itself is invalid
+    fn bad_request(err: impl Into<anyhow::Error>) -> Self {
+        Self { status: StatusCode::BAD_REQUEST, error: err.into() }
+    }
+
+    /// The requested resource does not exist
+    fn not_found(err: impl Into<anyhow::Error>) -> Self {
+        Self { status: StatusCode::NOT_FOUND, error: err.into() }
+    }
+}
 
 impl IntoResponse for AppError {
     fn into_response(self) -> axum::response::Response {
         (
-            StatusCode::INTERNAL_SERVER_ERROR,
+            self.status,
             Json(serde_json::json!({
-                "error": self.0.to_string()
+                "error": self.error.to_string()
             })),
         )
             .into_response()
@@ -375,7 +634,7 @@ where
     E: Into<anyhow::Error>,
 {
     fn from(err: E) -> Self {
-        Self(err.into())
+        Self { status: StatusCode::INTERNAL_SERVER_ERROR, error: err.into() }
     }
 }
 
@@ -657,12 +916,12 @@ async fn get_workflows(
         .get(&url)
         .send()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to fetch workflows from peg-engine: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to fetch workflows from peg-engine: {}", e)))?;
 
     let status = response.status();
     if !status.is_success() {
         let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(AppError(anyhow::anyhow!(
+        return Err(AppError::from(anyhow::anyhow!(
             "Failed to fetch workflows: HTTP {} - {}",
             status.as_u16(),
             error_text
@@ -672,7 +931,7 @@ async fn get_workflows(
     let workflows_data: Vec<serde_json::Value> = response
         .json()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to parse workflows response: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to parse workflows response: {}", e)))?;
 
     // Map to simplified workflow definitions
     let workflows: Vec<WorkflowDefinition> = workflows_data
@@ -735,7 +994,7 @@ async fn execute_workflow(
         }))
         .send()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to execute workflow in peg-engine: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to execute workflow in peg-engine: {}", e)))?;
 
     let status = response.status().as_u16();
     let duration_ms = start.elapsed().as_millis() as u64;
@@ -756,7 +1015,7 @@ async fn execute_workflow(
             "Service call failed: Execute workflow"
         );
 
-        return Err(AppError(anyhow::anyhow!(
+        return Err(AppError::from(anyhow::anyhow!(
             "Failed to execute workflow: HTTP {} - {}",
             status,
             error_text
@@ -766,7 +1025,7 @@ async fn execute_workflow(
     let execution_data: serde_json::Value = response
         .json()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to parse execution response: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to parse execution response: {}", e)))?;
 
     // Extract execution ID from response
     let execution_id = execution_data["id"]
@@ -826,7 +1085,7 @@ async fn get_workflow_definition(
 
     let definition = tokio::fs::read_to_string(workflow_path)
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to read workflow file: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to read workflow file: {}", e)))?;
 
     tracing::info!(
         "Serving static workflow definition from file: {}",
@@ -870,7 +1129,7 @@ async fn get_executions(
         .get(&url)
         .send()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to fetch executions from peg-engine: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to fetch executions from peg-engine: {}", e)))?;
 
     let status = response.status().as_u16();
     let duration_ms = start.elapsed().as_millis();
@@ -888,13 +1147,13 @@ async fn get_executions(
 
     if !response.status().is_success() {
         let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(AppError(anyhow::anyhow!("peg-engine returned error {}: {}", status, error_text)));
+        return Err(AppError::from(anyhow::anyhow!("peg-engine returned error {}: {}", status, error_text)));
     }
 
     let executions_data = response
         .json::<serde_json::Value>()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to parse executions response: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to parse executions response: {}", e)))?;
 
     Ok(Json(executions_data))
 }
@@ -977,7 +1236,7 @@ async fn get_execution_trace(
         .get(&url)
         .send()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to fetch trace from peg-engine: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to fetch trace from peg-engine: {}", e)))?;
 
     let status = response.status().as_u16();
     let duration_ms = start.elapsed().as_millis();
@@ -996,13 +1255,13 @@ async fn get_execution_trace(
 
     if !response.status().is_success() {
         let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(AppError(anyhow::anyhow!("peg-engine returned error {}: {}", status, error_text)));
+        return Err(AppError::from(anyhow::anyhow!("peg-engine returned error {}: {}", status, error_text)));
     }
 
     let trace_data = response
         .json::<serde_json::Value>()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to parse trace response: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to parse trace response: {}", e)))?;
 
     Ok(Json(trace_data))
 }
@@ -1016,7 +1275,7 @@ async fn get_service_calls(
     let calls = state
         .service_calls
         .read()
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to read service calls: {}", e)))?
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to read service calls: {}", e)))?
         .iter()
         .filter(|call| {
             call.execution_id.as_ref().map(|id| id == &execution_id).unwrap_or(false)
@@ -1127,20 +1386,20 @@ async fn get_artifacts(
         .get(&executions_url)
         .send()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to fetch executions: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to fetch executions: {}", e)))?;
 
     if !executions_response.status().is_success() {
-        return Err(AppError(anyhow::anyhow!("Failed to fetch executions from peg-engine")));
+        return Err(AppError::from(anyhow::anyhow!("Failed to fetch executions from peg-engine")));
     }
 
     let executions_data: serde_json::Value = executions_response
         .json()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to parse executions: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to parse executions: {}", e)))?;
 
     let executions = executions_data["executions"]
         .as_array()
-        .ok_or_else(|| AppError(anyhow::anyhow!("Invalid executions response")))?;
+        .ok_or_else(|| AppError::from(anyhow::anyhow!("Invalid executions response")))?;
 
     if executions.is_empty() {
         return Ok(Json(ArtifactsResponse {
@@ -1151,7 +1410,7 @@ async fn get_artifacts(
     // Get the latest execution ID
     let latest_execution_id = executions[0]["id"]
         .as_str()
-        .ok_or_else(|| AppError(anyhow::anyhow!("Invalid execution ID")))?;
+        .ok_or_else(|| AppError::from(anyhow::anyhow!("Invalid execution ID")))?;
 
     // Fetch artifacts for this execution
     let artifacts_url = format!("{}/api/v1/executions/{}/artifacts", peg_engine_url, latest_execution_id);
@@ -1161,7 +1420,7 @@ async fn get_artifacts(
         .get(&artifacts_url)
         .send()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to fetch artifacts: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to fetch artifacts: {}", e)))?;
 
     let status = artifacts_response.status().as_u16();
     let duration_ms = start.elapsed().as_millis();
@@ -1178,13 +1437,13 @@ async fn get_artifacts(
     );
 
     if !artifacts_response.status().is_success() {
-        return Err(AppError(anyhow::anyhow!("Failed to fetch artifacts from peg-engine")));
+        return Err(AppError::from(anyhow::anyhow!("Failed to fetch artifacts from peg-engine")));
     }
 
     let artifacts_data: Vec<serde_json::Value> = artifacts_response
         .json()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to parse artifacts: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to parse artifacts: {}", e)))?;
 
     // Transform peg-engine artifacts to our format
     let artifacts: Vec<ArtifactResponse> = artifacts_data
@@ -1243,7 +1502,7 @@ async fn get_artifact(
         .get(&download_url)
         .send()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to download artifact: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to download artifact: {}", e)))?;
 
     let status = response.status().as_u16();
     let duration_ms = start.elapsed().as_millis();
@@ -1261,14 +1520,14 @@ async fn get_artifact(
     );
 
     if !response.status().is_success() {
-        return Err(AppError(anyhow::anyhow!("Failed to download artifact from peg-engine")));
+        return Err(AppError::from(anyhow::anyhow!("Failed to download artifact from peg-engine")));
     }
 
     // Get content as bytes first
     let bytes = response
         .bytes()
         .await
-        .map_err(|e| AppError(anyhow::anyhow!("Failed to read artifact content: {}", e)))?;
+        .map_err(|e| AppError::from(anyhow::anyhow!("Failed to read artifact content: {}", e)))?;
 
     // Try to parse as JSON
     let content: serde_json::Value = serde_json::from_slice(&bytes)
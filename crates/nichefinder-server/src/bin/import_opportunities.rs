@@ -54,20 +54,32 @@ async fn main() -> anyhow::Result<()> {
     for opportunity in &analysis_result.opportunities {
         let scoring_details_json = serde_json::to_string(&opportunity.scoring_details)?;
         let data_sources_json = serde_json::to_string(&opportunity.data_sources)?;
+        let evidence_json = serde_json::to_string(&opportunity.evidence)?;
+        let features_json = serde_json::to_string(&opportunity.features)?;
+        let scorer_results_json = serde_json::to_string(&opportunity.scorer_results)?;
+        let forecasts_json = serde_json::to_string(&opportunity.forecasts)?;
+        let differentiation_json = serde_json::to_string(&opportunity.differentiation)?;
         let metadata_json = serde_json::to_string(&opportunity.metadata)?;
         
         sqlx::query(
             r#"
-            INSERT INTO opportunities (id, name, category, score, scoring_details, data_sources, discovered_at, metadata)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO opportunities (id, name, category, kind, score, scoring_details, data_sources, evidence, features, scorer_results, forecasts, lifecycle, differentiation, discovered_at, metadata)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(opportunity.id.to_string())
         .bind(&opportunity.name)
         .bind(&opportunity.category)
+        .bind(opportunity.kind.as_str())
         .bind(opportunity.score)
         .bind(scoring_details_json)
         .bind(data_sources_json)
+        .bind(evidence_json)
+        .bind(features_json)
+        .bind(scorer_results_json)
+        .bind(forecasts_json)
+        .bind(opportunity.lifecycle.map(|stage| stage.as_str()))
+        .bind(differentiation_json)
         .bind(opportunity.discovered_at.to_rfc3339())
         .bind(metadata_json)
         .execute(&pool)
@@ -53,6 +53,37 @@ async fn run_migrations(pool: &SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // Create labels table (team feedback on opportunities)
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS labels (
+            id TEXT PRIMARY KEY,
+            opportunity_id TEXT NOT NULL,
+            value TEXT NOT NULL,
+            labeled_by TEXT,
+            note TEXT,
+            labeled_at TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create weight_profiles table (score weights learned from labels)
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS weight_profiles (
+            name TEXT PRIMARY KEY,
+            weights TEXT NOT NULL,
+            report TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
     // Create index on score for faster queries
     sqlx::query(
         r#"
@@ -63,10 +94,42 @@ async fn run_migrations(pool: &SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // Columns added after the initial schema
+    add_column_if_missing(pool, "opportunities", "kind", "TEXT NOT NULL DEFAULT 'community_integration'").await?;
+    add_column_if_missing(pool, "opportunities", "evidence", "TEXT NOT NULL DEFAULT '[]'").await?;
+    add_column_if_missing(pool, "opportunities", "features", "TEXT NOT NULL DEFAULT 'null'").await?;
+    add_column_if_missing(pool, "opportunities", "scorer_results", "TEXT NOT NULL DEFAULT '[]'").await?;
+    add_column_if_missing(pool, "opportunities", "forecasts", "TEXT NOT NULL DEFAULT '[]'").await?;
+    add_column_if_missing(pool, "opportunities", "lifecycle", "TEXT").await?;
+    add_column_if_missing(pool, "opportunities", "differentiation", "TEXT NOT NULL DEFAULT '[]'").await?;
+
     tracing::info!("Database migrations completed");
     Ok(())
 }
 
+/// Add a column to an existing table unless it is already there
+async fn add_column_if_missing(
+    pool: &SqlitePool,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<()> {
+    let columns = sqlx::query(&format!("PRAGMA table_info({})", table))
+        .fetch_all(pool)
+        .await?;
+
+    let exists = columns
+        .iter()
+        .any(|row| row.get::<String, _>("name") == column);
+    if !exists {
+        sqlx::query(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition))
+            .execute(pool)
+            .await?;
+    }
+
+    Ok(())
+}
+
 /// Save an opportunity to the database
 pub async fn save_opportunity(
     pool: &SqlitePool,
@@ -75,23 +138,37 @@ pub async fn save_opportunity(
     sqlx::query(
         r#"
         INSERT INTO opportunities (
-            id, name, category, score, scoring_details, 
-            data_sources, discovered_at, metadata
+            id, name, category, kind, score, scoring_details, 
+            data_sources, evidence, features, scorer_results, forecasts, lifecycle, differentiation, discovered_at, metadata
         )
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         ON CONFLICT(id) DO UPDATE SET
+            kind = excluded.kind,
             score = excluded.score,
             scoring_details = excluded.scoring_details,
             data_sources = excluded.data_sources,
+            evidence = excluded.evidence,
+            features = excluded.features,
+            scorer_results = excluded.scorer_results,
+            forecasts = excluded.forecasts,
+            lifecycle = excluded.lifecycle,
+            differentiation = excluded.differentiation,
             metadata = excluded.metadata
         "#,
     )
     .bind(opportunity.id.to_string())
     .bind(&opportunity.name)
     .bind(&opportunity.category)
+    .bind(opportunity.kind.as_str())
     .bind(opportunity.score)
     .bind(serde_json::to_string(&opportunity.scoring_details)?)
     .bind(serde_json::to_string(&opportunity.data_sources)?)
+    .bind(serde_json::to_string(&opportunity.evidence)?)
+    .bind(serde_json::to_string(&opportunity.features)?)
+    .bind(serde_json::to_string(&opportunity.scorer_results)?)
+    .bind(serde_json::to_string(&opportunity.forecasts)?)
+    .bind(opportunity.lifecycle.map(|stage| stage.as_str()))
+    .bind(serde_json::to_string(&opportunity.differentiation)?)
     .bind(opportunity.discovered_at.to_rfc3339())
     .bind(serde_json::to_string(&opportunity.metadata)?)
     .execute(pool)
@@ -100,39 +177,181 @@ pub async fn save_opportunity(
     Ok(())
 }
 
-/// Get top opportunities from the database
+/// Save an analysis run (config and metadata, including per-source status)
+pub async fn save_analysis_run(
+    pool: &SqlitePool,
+    result: &nichefinder_core::AnalysisResult,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO analysis_runs (id, analyzed_at, config, metadata)
+        VALUES (?, ?, ?, ?)
+        "#,
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(result.analyzed_at.to_rfc3339())
+    .bind(serde_json::to_string(&result.config)?)
+    .bind(serde_json::to_string(&result.metadata)?)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Get top opportunities from the database, optionally in one lifecycle stage
 pub async fn get_top_opportunities(
     pool: &SqlitePool,
     limit: i64,
+    stage: Option<nichefinder_core::lifecycle::LifecycleStage>,
 ) -> Result<Vec<nichefinder_core::NicheOpportunity>> {
+    let stage = stage.map(|stage| stage.as_str());
     let rows = sqlx::query(
         r#"
-        SELECT id, name, category, score, scoring_details, 
-               data_sources, discovered_at, metadata
+        SELECT id, name, category, kind, score, scoring_details, 
+               data_sources, evidence, features, scorer_results, forecasts, lifecycle, differentiation, discovered_at, metadata
         FROM opportunities
+        WHERE ? IS NULL OR lifecycle = ?
         ORDER BY score DESC
         LIMIT ?
         "#,
     )
+    .bind(stage)
+    .bind(stage)
     .bind(limit)
     .fetch_all(pool)
     .await?;
 
-    let mut opportunities = Vec::new();
-    for row in rows {
-        let opportunity = nichefinder_core::NicheOpportunity {
-            id: row.get::<String, _>("id").parse()?,
-            name: row.get("name"),
-            category: row.get("category"),
-            score: row.get("score"),
-            scoring_details: serde_json::from_str(&row.get::<String, _>("scoring_details"))?,
-            data_sources: serde_json::from_str(&row.get::<String, _>("data_sources"))?,
-            discovered_at: row.get::<String, _>("discovered_at").parse()?,
-            metadata: serde_json::from_str(&row.get::<String, _>("metadata"))?,
-        };
-        opportunities.push(opportunity);
-    }
+    rows.iter().map(opportunity_from_row).collect()
+}
+
+/// Get every stored opportunity, across all analysis runs
+pub async fn get_all_opportunities(pool: &SqlitePool) -> Result<Vec<nichefinder_core::NicheOpportunity>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT id, name, category, kind, score, scoring_details, 
+               data_sources, evidence, features, scorer_results, forecasts, lifecycle, differentiation, discovered_at, metadata
+        FROM opportunities
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter().map(opportunity_from_row).collect()
+}
 
+/// Get the newest stored row of each opportunity (by kind and name)
+///
+/// Every analysis run saves its opportunities again, so the full table holds
+/// one row per run an opportunity appeared in.
+pub async fn get_latest_opportunities(pool: &SqlitePool) -> Result<Vec<nichefinder_core::NicheOpportunity>> {
+    let mut opportunities = get_all_opportunities(pool).await?;
+    opportunities.sort_by_key(|o| std::cmp::Reverse(o.discovered_at));
+    let mut seen = std::collections::HashSet::new();
+    opportunities.retain(|o| seen.insert((o.kind, o.name.clone())));
     Ok(opportunities)
 }
 
+/// Save a team label for an opportunity
+pub async fn save_label(pool: &SqlitePool, label: &nichefinder_core::training::OpportunityLabel) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO labels (id, opportunity_id, value, labeled_by, note, labeled_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(label.opportunity_id.to_string())
+    .bind(serde_json::to_string(&label.value)?)
+    .bind(&label.labeled_by)
+    .bind(&label.note)
+    .bind(label.labeled_at.to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Whether an opportunity is stored
+pub async fn opportunity_exists(pool: &SqlitePool, id: uuid::Uuid) -> Result<bool> {
+    let row = sqlx::query("SELECT 1 FROM opportunities WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.is_some())
+}
+
+/// Get every team label, oldest first
+pub async fn get_labels(pool: &SqlitePool) -> Result<Vec<nichefinder_core::training::OpportunityLabel>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT opportunity_id, value, labeled_by, note, labeled_at
+        FROM labels
+        ORDER BY labeled_at
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter()
+        .map(|row| {
+            Ok(nichefinder_core::training::OpportunityLabel {
+                opportunity_id: row.get::<String, _>("opportunity_id").parse()?,
+                value: serde_json::from_str(&row.get::<String, _>("value"))?,
+                labeled_by: row.get("labeled_by"),
+                note: row.get("note"),
+                labeled_at: row.get::<String, _>("labeled_at").parse()?,
+            })
+        })
+        .collect()
+}
+
+/// Save a learned weight profile, replacing one of the same name
+pub async fn save_weight_profile(pool: &SqlitePool, profile: &nichefinder_core::training::TrainedProfile) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT OR REPLACE INTO weight_profiles (name, weights, report)
+        VALUES (?, ?, ?)
+        "#,
+    )
+    .bind(&profile.name)
+    .bind(serde_json::to_string(&profile.weights)?)
+    .bind(serde_json::to_string(&profile.report)?)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Get every learned weight profile, by name
+pub async fn get_weight_profiles(
+    pool: &SqlitePool,
+) -> Result<std::collections::BTreeMap<String, nichefinder_core::types::ScoreWeights>> {
+    let rows = sqlx::query("SELECT name, weights FROM weight_profiles")
+        .fetch_all(pool)
+        .await?;
+
+    rows.iter()
+        .map(|row| Ok((row.get("name"), serde_json::from_str(&row.get::<String, _>("weights"))?)))
+        .collect()
+}
+
+fn opportunity_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<nichefinder_core::NicheOpportunity> {
+    Ok(nichefinder_core::NicheOpportunity {
+        id: row.get::<String, _>("id").parse()?,
+        name: row.get("name"),
+        category: row.get("category"),
+        kind: serde_json::from_value(serde_json::Value::String(row.get("kind")))?,
+        score: row.get("score"),
+        scoring_details: serde_json::from_str(&row.get::<String, _>("scoring_details"))?,
+        data_sources: serde_json::from_str(&row.get::<String, _>("data_sources"))?,
+        evidence: serde_json::from_str(&row.get::<String, _>("evidence"))?,
+        features: serde_json::from_str(&row.get::<String, _>("features"))?,
+        scorer_results: serde_json::from_str(&row.get::<String, _>("scorer_results"))?,
+        forecasts: serde_json::from_str(&row.get::<String, _>("forecasts"))?,
+        lifecycle: row.get::<Option<String>, _>("lifecycle").map(|stage| stage.parse()).transpose()?,
+        differentiation: serde_json::from_str(&row.get::<String, _>("differentiation"))?,
+        discovered_at: row.get::<String, _>("discovered_at").parse()?,
+        metadata: serde_json::from_str(&row.get::<String, _>("metadata"))?,
+    })
+}
@@ -1,8 +1,19 @@
 //! Scheduled analysis jobs
 
 use anyhow::Result;
+use nichefinder_core::collectors::{
+    CoreInventorySource, DataCollector, DiscourseCollector, GitHubCollector, GitHubMaintenanceCollector,
+    HaAnalyticsCollector, HaCoreCollector, HackerNewsCollector, HacsCollector,
+};
+use nichefinder_core::data_lake::{DataLake, RetentionPolicy};
+use nichefinder_core::{AnalysisConfig, CollectionOrchestrator, CollectionRun, IntegrationAnalyzer};
 use sqlx::SqlitePool;
+use std::time::Duration;
 use tokio_cron_scheduler::{Job, JobScheduler};
+use uuid::Uuid;
+
+/// HACS repositories that get maintenance activity collected
+const MAINTENANCE_REPOS: usize = 50;
 
 /// Scheduler handle for managing scheduled jobs
 pub struct SchedulerHandle {
@@ -41,14 +52,153 @@ pub async fn start_scheduler(db_pool: SqlitePool) -> Result<SchedulerHandle> {
 }
 
 /// Run a full analysis
+///
+/// Collects every enabled source concurrently, analyzes whatever succeeded
+/// and saves the opportunities plus the run (including per-source status).
+/// A collector that cannot be created is skipped like a failed source.
 async fn run_analysis(pool: &SqlitePool) -> Result<()> {
-    // TODO: Implement actual analysis logic
-    // This will:
-    // 1. Fetch data from GitHub, Reddit, HACS using UDM connectors
-    // 2. Score opportunities using nichefinder-core
-    // 3. Save results to database
-    
-    tracing::info!("Analysis placeholder - implementation pending");
+    let config = analysis_config(pool).await?;
+    let github_token = std::env::var("GITHUB_TOKEN").ok();
+
+    let orchestrator = || {
+        CollectionOrchestrator::new()
+            .with_max_concurrency(4)
+            .with_default_timeout(Duration::from_secs(120))
+            // The core inventory fetches one manifest per component, the
+            // maintenance collector four pages per repository
+            .with_timeout("ha_core", Duration::from_secs(900))
+            .with_timeout("github_maintenance", Duration::from_secs(900))
+    };
+    let mut collection = orchestrator();
+    add_collector(&mut collection, &config, "hacs", HacsCollector::new().await);
+    add_collector(&mut collection, &config, "github", GitHubCollector::new(github_token.clone()).await);
+    add_collector(&mut collection, &config, "hackernews", HackerNewsCollector::new(Vec::new()).await);
+    add_collector(&mut collection, &config, "ha_community", DiscourseCollector::home_assistant(5).await);
+    add_collector(&mut collection, &config, "ha_analytics", HaAnalyticsCollector::new().await);
+    let core = HaCoreCollector::new(CoreInventorySource::GitHub {
+        git_ref: "dev".to_string(),
+        token: github_token.clone(),
+    })
+    .await;
+    add_collector(&mut collection, &config, "ha_core", core);
+    let mut run = collection.run().await;
+
+    // Maintenance activity of the incumbents found in this run's HACS records
+    let mut maintenance = orchestrator();
+    let repos = incumbent_repos(&run);
+    if repos.is_empty() {
+        tracing::warn!("No HACS integrations collected; skipping maintenance collection");
+    } else {
+        let collector = GitHubMaintenanceCollector::new(github_token, repos).await;
+        add_collector(&mut maintenance, &config, "github_maintenance", collector);
+    }
+    if !maintenance.is_empty() {
+        let maintained = maintenance.run().await;
+        run.statuses.extend(maintained.statuses);
+        run.data.extend(maintained.data);
+    }
+
+    for status in &run.statuses {
+        tracing::info!(
+            "Source {}: {:?} ({} records in {:.1}s){}",
+            status.source,
+            status.state,
+            status.records,
+            status.duration_secs,
+            status.error.as_ref().map(|e| format!(" - {}", e)).unwrap_or_default(),
+        );
+    }
+
+    // Keep the raw run so the analysis can be reproduced later
+    let lake = DataLake::new(std::env::var("DATA_LAKE_DIR").unwrap_or_else(|_| "data/lake".to_string()));
+    match lake.store(&run) {
+        Ok(manifest) => tracing::info!("Stored collection run {} in {}", manifest.run_id, lake.root().display()),
+        Err(e) => tracing::warn!("Failed to store collection run: {}", e),
+    }
+    let retention = RetentionPolicy {
+        max_age_days: Some(90),
+        max_runs: None,
+    };
+    match lake.apply_retention(&retention) {
+        Ok(removed) if !removed.is_empty() => tracing::info!("Removed {} expired collection runs", removed.len()),
+        Ok(_) => {}
+        Err(e) => tracing::warn!("Failed to apply data lake retention: {}", e),
+    }
+
+    // Write collected records as analyzer input files
+    let temp_dir = std::env::temp_dir().join(format!("nichefinder-scheduled-{}", Uuid::new_v4()));
+    tokio::fs::create_dir_all(&temp_dir).await?;
+    let analysis = run
+        .write_inputs(&temp_dir)
+        .and_then(|inputs| IntegrationAnalyzer::from_config(config)?.analyze_from_inputs(&inputs));
+    let _ = tokio::fs::remove_dir_all(&temp_dir).await;
+
+    let mut result = analysis.map_err(|e| anyhow::anyhow!("Analysis failed: {}", e))?;
+    result.metadata.source_status = run.statuses;
+
+    for opportunity in &result.opportunities {
+        crate::db::save_opportunity(pool, opportunity).await?;
+    }
+    crate::db::save_analysis_run(pool, &result).await?;
+
+    tracing::info!(
+        "Scheduled analysis saved {} opportunities from {} candidates",
+        result.opportunities.len(),
+        result.metadata.total_candidates
+    );
     Ok(())
 }
 
+/// Analysis configuration for scheduled runs
+///
+/// Read from the JSON file in `ANALYSIS_CONFIG` when set (defaults otherwise),
+/// with the learned weight profiles from the database added.
+async fn analysis_config(pool: &SqlitePool) -> Result<AnalysisConfig> {
+    let mut config = match std::env::var("ANALYSIS_CONFIG") {
+        Ok(path) => {
+            let content = tokio::fs::read_to_string(&path).await?;
+            serde_json::from_str(&content).map_err(|e| anyhow::anyhow!("Invalid analysis config {}: {}", path, e))?
+        }
+        Err(_) => AnalysisConfig::default(),
+    };
+    for (name, weights) in crate::db::get_weight_profiles(pool).await? {
+        config.weight_profiles.entry(name).or_insert(weights);
+    }
+    Ok(config)
+}
+
+/// Register an enabled source's collector, logging and skipping it when it
+/// could not be created
+fn add_collector<C: DataCollector + 'static>(
+    orchestrator: &mut CollectionOrchestrator,
+    config: &AnalysisConfig,
+    source: &str,
+    collector: nichefinder_core::Result<C>,
+) {
+    if !config.source_enabled(source) {
+        return;
+    }
+    match collector {
+        Ok(collector) => orchestrator.add(collector),
+        Err(e) => tracing::error!("Skipping {} collection; collector could not be created: {}", source, e),
+    }
+}
+
+/// "owner/repo" of the most-starred HACS integrations in a collection run,
+/// so the incumbents users rely on get a maintenance health without crawling
+/// every repository
+///
+/// Chosen by stars rather than stored score, so the selection doesn't depend
+/// on the previous analysis and a fresh database still gets maintenance data.
+fn incumbent_repos(run: &CollectionRun) -> Vec<String> {
+    let mut by_stars: Vec<(u64, String)> = run
+        .records("hacs")
+        .filter_map(|d| {
+            let full_name = d.raw_data.get("full_name")?.as_str()?;
+            let stars = d.raw_data.get("stargazers_count").and_then(|s| s.as_u64()).unwrap_or(0);
+            Some((stars, full_name.to_string()))
+        })
+        .collect();
+    by_stars.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    by_stars.into_iter().take(MAINTENANCE_REPOS).map(|(_, name)| name).collect()
+}
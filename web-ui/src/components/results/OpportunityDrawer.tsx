@@ -22,7 +22,7 @@ interface ScoringDetails {
   competition: number;
   trend: number;
   composite: number;
-  weights: {
+  weights?: {
     demand: number;
     feasibility: number;
     competition: number;
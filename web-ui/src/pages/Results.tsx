@@ -20,7 +20,7 @@ interface IntegrationScore {
   competition: number;
   trend: number;
   composite: number;
-  weights: {
+  weights?: {
     demand: number;
     feasibility: number;
     competition: number;